@@ -1,9 +1,11 @@
 
 use crate::domain::Column;
 use crate::domain::Row;
+use greyjack::domain::DomainEntity;
 
-#[derive(Clone)]
+#[derive(Clone, DomainEntity)]
 pub struct Queen {
+    #[solved_value(column = "row_id")]
     pub row: Row,
     pub column: Column
 }
@@ -1,3 +1,6 @@
+use greyjack::domain::FromAnyValue;
+use greyjack::errors::GreyjackError;
+use polars::datatypes::AnyValue;
 
 
 #[derive(Clone, Copy)]
@@ -11,4 +14,10 @@ impl Row {
             row_id: i
         }
     }
-}
\ No newline at end of file
+}
+
+impl FromAnyValue for Row {
+    fn from_any_value(value: &AnyValue) -> Result<Self, GreyjackError> {
+        return Ok(Row::new(u64::from_any_value(value)?));
+    }
+}
@@ -8,7 +8,7 @@ mod domain;
 use greyjack::score_calculation::scores::SimpleScore;
 use greyjack::domain::DomainBuilderTrait;
 use greyjack::cotwin::{CotwinBuilderTrait};
-use greyjack::solver::{ObserverTrait, Solver, SolverLoggingLevels};
+use greyjack::solver::{MigrationTopologies, ObserverTrait, Phase, Solver, SolverConfig, SolverLoggingLevels};
 use greyjack::agents::{GeneticAlgorithm, LateAcceptance, SimulatedAnnealing, TabuSearch};
 use greyjack::agents::AgentBuildersVariants::*;
 use greyjack::agents::termination_strategies::*;
@@ -35,17 +35,25 @@ fn main() {
     //let agent_builder = LA(LateAcceptance::new(32, 0.2, None, Some(vec![0.0, 1.0, 0.0, 0.0, 0.0, 0.0]), 10000, termination_strategy));
     //let agent_builder = SA(SimulatedAnnealing::new(vec![1.0], Some(0.9999), 0.0, None, Some(vec![0.0, 1.0, 0.0, 0.0, 0.0, 0.0]), 10, termination_strategy));
 
-    // example of optional observers
-    //let mut observers: Vec<Box<dyn ObserverTrait + Send>> = Vec::new();
-    //observers.push(Box::new(NQueensObserver::new(0)));
-
     // change logging_level to SolverLoggingLevels::Silent for max performance
-    let solution = Solver::solve(
-        domain_builder.clone(), cotwin_builder, agent_builder, 
-        10, None, SolverLoggingLevels::FreshOnly, None, None,
-    );
+    let solver_config = SolverConfig::new(agent_builder)
+                        .with_n_jobs(10)
+                        .with_logging_level(SolverLoggingLevels::FreshOnly);
+    // solver-wide termination stops all agents at once, e.g. when the global best hasn't improved for 30 s
+    //let solver_config = solver_config.with_termination_strategy(SNI(ScoreNoImprovement::new(30*1000)));
+    // fixed seed with n_jobs = 1 makes a run repeatable
+    //let solver_config = solver_config.with_random_seed(45);
+    // agents of other metaheuristics can join the migration ring, e.g. 2 x LateAcceptance next to TabuSearch agents
+    //let solver_config = solver_config.with_agent_team(LA(LateAcceptance::new(32, 0.2, None, Some(vec![0.0, 1.0, 0.0, 0.0, 0.0, 0.0]), 100, ScL(ScoreLimit::new(SimpleScore::new(0.0))))), 2);
+    // denser migration topology spreads good solutions faster between many agents
+    //let solver_config = solver_config.with_migration_topology(MigrationTopologies::BidirectionalRing);
+    // the next phase continues from the global best of the previous one, e.g. polishing by LateAcceptance
+    //let solver_config = solver_config.with_phase(Phase::new(LA(LateAcceptance::new(32, 0.2, None, Some(vec![0.0, 1.0, 0.0, 0.0, 0.0, 0.0]), 10000, StL(StepsLimit::new(1000))))).with_n_jobs(2));
+    // example of optional observers
+    //let solver_config = solver_config.with_observer(Box::new(NQueensObserver::new(0)));
+    let solution = Solver::solve(domain_builder.clone(), cotwin_builder, solver_config).expect("Solving failed");
 
-    let domain = domain_builder.build_from_solution(&solution, None);
+    let domain = domain_builder.build_from_solution(&solution.get_raw_solution(), None);
     //println!("{}", domain);
 
     println!("done");
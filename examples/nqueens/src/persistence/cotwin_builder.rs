@@ -7,19 +7,19 @@ use greyjack::cotwin::CotwinEntityTrait;
 use greyjack::score_calculation::scores::SimpleScore;
 use greyjack::score_calculation::score_calculators::score_calculator_variants::ScoreCalculatorVariants;
 use greyjack::variables::GJInteger;
+use greyjack::errors::GreyjackError;
 use crate::cotwin::CotQueen;
 use crate::score::NQueensIncrementalScoreCalculator;
 use crate::score::NQueensPlainScoreCalculator;
 use crate::domain::ChessBoard;
-use polars::datatypes::AnyValue;
 use std::collections::HashMap;
 
 
-pub enum EntityVariants<'a> {
-    CotQueen(CotQueen<'a>)
+pub enum EntityVariants {
+    CotQueen(CotQueen)
 }
 
-impl<'a> CotwinEntityTrait for EntityVariants<'a> {
+impl CotwinEntityTrait for EntityVariants {
     fn to_vec(&self) -> Vec<(String, CotwinValueTypes)> {
         match self {
             EntityVariants::CotQueen(x) => return x.to_vec()
@@ -42,33 +42,28 @@ impl CotwinBuilder {
     }
 }
 
-impl<'a> CotwinBuilderTrait<ChessBoard, EntityVariants<'a>, UtilityObjectVariants, SimpleScore> for CotwinBuilder
+impl CotwinBuilderTrait<ChessBoard, EntityVariants, UtilityObjectVariants, SimpleScore> for CotwinBuilder
  {
 
-    fn build_cotwin(&self, domain_model: ChessBoard, is_already_initialized: bool) -> Cotwin<EntityVariants<'a>, UtilityObjectVariants, SimpleScore> {
+    fn build_cotwin(&self, domain_model: ChessBoard, is_already_initialized: bool) -> Result<Cotwin<EntityVariants, UtilityObjectVariants, SimpleScore>, GreyjackError> {
 
         let n = domain_model.n;
         let queens = &domain_model.queens;
         let mut cot_queens: Vec<EntityVariants> = Vec::new();
 
         if is_already_initialized {
-            panic!("Building cotwin for existing domain isn't already implemented for NQueens problem")
+            return Err(GreyjackError::InvalidCotwin("Building cotwin for existing domain isn't already implemented for NQueens problem".to_string()));
         }
 
         for i in 0..n {
-            let queen_id  = CotwinValueTypes::PAV(AnyValue::UInt64(i));
-            let column_id = CotwinValueTypes::PAV(AnyValue::UInt64(i));
             // initial_value: Some(queens[i as usize].row.row_id as i64)
-            let planning_row_id = CotwinValueTypes::GJI(
-                GJInteger::new( Some(queens[i as usize].row.row_id as i64), 
-                0, (n-1) as i64, false, None)
-            );
+            let planning_row_id = GJInteger::new( Some(queens[i as usize].row.row_id as i64), 
+                0, (n-1) as i64, false, None);
 
             let cot_queen = CotQueen {
-                queen_id: queen_id,
+                queen_id: i,
                 row_id: planning_row_id,
-                column_id: column_id,
-
+                column_id: i,
             };
             let cot_queen = EntityVariants::CotQueen(cot_queen);
             cot_queens.push(cot_queen);
@@ -85,7 +80,7 @@ impl<'a> CotwinBuilderTrait<ChessBoard, EntityVariants<'a>, UtilityObjectVariant
             nqueens_cotwin.add_score_calculator(ScoreCalculatorVariants::PSC(score_calculator));
         }
 
-        return nqueens_cotwin;
+        return Ok(nqueens_cotwin);
     }
 }
 
@@ -1,5 +1,5 @@
 
-use greyjack::domain::DomainBuilderTrait;
+use greyjack::domain::{DomainBuilderTrait, SolutionView};
 use greyjack::score_calculation::scores::SimpleScore;
 use crate::domain::ChessBoard;
 use crate::domain::Queen;
@@ -47,24 +47,8 @@ impl DomainBuilderTrait<ChessBoard> for DomainBuilder {
 
     fn build_from_solution(&self, solution: &Value, initial_domain: Option<ChessBoard>) -> ChessBoard {
         let mut domain = self.build_domain_from_scratch();
-        let solution: (Vec<(String, AnyValue)>, SimpleScore)  = from_value(solution.clone()).unwrap();
-        let solution = solution.0;
-
-        (0..solution.len()).for_each(|i| {
-            let queen_id = &solution[i].0;
-            let queen_id: Vec<&str> = queen_id.split(" ").collect();
-            let queen_id: Vec<&str> = queen_id[1].split("-->").collect();
-            let queen_id: usize = queen_id[0].parse().unwrap();
-
-            let parsed_row_id = &solution[i].1;
-            let row_id;
-            match parsed_row_id {
-                AnyValue::Int64(x) => row_id = *x as u64,
-                _ => panic!("Invalid datatype. Expecting AnyValue::Int64")
-            }
-
-            domain.queens[queen_id].row.row_id = row_id;
-        });
+        let solution_view = SolutionView::from_raw_solution(solution).expect("Invalid NQueens solution");
+        solution_view.set_solved_values("queens", &mut domain.queens).expect("Failed to set solved row_ids");
 
         return domain;
     }
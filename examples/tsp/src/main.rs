@@ -11,7 +11,7 @@ use greyjack::domain::DomainBuilderTrait;
 use greyjack::cotwin::CotwinBuilderTrait;
 use greyjack::score_calculation::scores::HardSoftScore;
 use persistence::{CotwinBuilder, DomainBuilder};
-use greyjack::solver::{ObserverTrait, Solver, SolverLoggingLevels};
+use greyjack::solver::{ObserverTrait, Solver, SolverConfig, SolverLoggingLevels};
 use greyjack::agents::*;
 use greyjack::agents::AgentBuildersVariants::*;
 use greyjack::agents::termination_strategies::*;
@@ -51,12 +51,13 @@ fn main() {
 
     // to make possible to build huge round-robin (use n_jobs >= cpus count) of communicating agents
     //rayon::ThreadPoolBuilder::new().num_threads(100).build_global().unwrap();
-    let solution = Solver::solve(
-        domain_builder.clone(), cotwin_builder, agent_builder, 
-        10, Some(vec![3, 3]), SolverLoggingLevels::FreshOnly, None, None,
-    );
+    let solver_config = SolverConfig::new(agent_builder)
+                        .with_n_jobs(10)
+                        .with_score_precision(vec![3, 3])
+                        .with_logging_level(SolverLoggingLevels::FreshOnly);
+    let solution = Solver::solve(domain_builder.clone(), cotwin_builder, solver_config).expect("Solving failed");
 
-    let domain = domain_builder.build_from_solution(&solution, None);
+    let domain = domain_builder.build_from_solution(&solution.get_raw_solution(), None);
     domain.print_metrics();
     domain.print_path();
 
@@ -7,6 +7,7 @@ use greyjack::cotwin::{Cotwin, CotwinEntityTrait, CotwinValueTypes, CotwinBuilde
 use greyjack::score_calculation::score_calculators::score_calculator_variants::ScoreCalculatorVariants;
 use greyjack::score_calculation::scores::HardSoftScore;
 use greyjack::variables::GJInteger;
+use greyjack::errors::GreyjackError;
 use std::collections::{HashMap, HashSet};
 use polars::datatypes::AnyValue;
 
@@ -119,7 +120,7 @@ impl CotwinBuilder {
 
 impl<'a> CotwinBuilderTrait<TravelSchedule, EntityVariants<'a>, UtilityObjectVariants, HardSoftScore> for CotwinBuilder {
 
-    fn build_cotwin(&self, domain: TravelSchedule, is_already_initialized: bool) -> Cotwin<EntityVariants<'a>, UtilityObjectVariants, HardSoftScore> {
+    fn build_cotwin(&self, domain: TravelSchedule, is_already_initialized: bool) -> Result<Cotwin<EntityVariants<'a>, UtilityObjectVariants, HardSoftScore>, GreyjackError> {
         
         let mut cotwin = Cotwin::new();
         cotwin.add_planning_entities("path_stops".to_string(), self.build_planning_stops(&domain, is_already_initialized));
@@ -134,7 +135,7 @@ impl<'a> CotwinBuilderTrait<TravelSchedule, EntityVariants<'a>, UtilityObjectVar
             cotwin.add_score_calculator(ScoreCalculatorVariants::PSC(score_calculator));
         }
 
-        return cotwin;
+        return Ok(cotwin);
     }
 
 }
\ No newline at end of file
@@ -9,7 +9,7 @@ use std::path::{PathBuf, Path};
 use greyjack::domain::DomainBuilderTrait;
 use greyjack::cotwin::CotwinBuilderTrait;
 use persistence::{CotwinBuilder, DomainBuilder};
-use greyjack::solver::{InitialSolutionVariants, ObserverTrait, Solver, SolverLoggingLevels};
+use greyjack::solver::{InitialSolutionVariants, ObserverTrait, Solver, SolverConfig, SolverLoggingLevels};
 use greyjack::agents::*;
 use greyjack::agents::AgentBuildersVariants::*;
 use greyjack::agents::termination_strategies::*;
@@ -48,6 +48,7 @@ fn main() {
     //let termination_strategy = StL(StepsLimit::new(100));
     //let termination_strategy = TSL(TimeSpentLimit::new(60*1000));
     let termination_strategy = SNI(ScoreNoImprovement::new(60*1000));
+    //let termination_strategy = AnyOf(vec![TSL(TimeSpentLimit::new(60*1000)), SNI(ScoreNoImprovement::new(10*1000))]);
     let agent_builder = TS(TabuSearch::new(128, 0.8, true, None, Some(vec![0.5, 0.5, 0.0, 0.0, 0.0, 0.0]), 10, termination_strategy));
     //let agent_builder = LA(LateAcceptance::new(32, 0.8, None, Some(vec![0.5, 0.5, 0.0, 0.0, 0.0, 0.0]), 10000, termination_strategy));
     //let agent_builder = GA(GeneticAlgorithm::new(128, 0.5, 0.2, 0.05, Some(1.0), None, 0.00001, 10, termination_strategy)); 
@@ -61,14 +62,25 @@ fn main() {
 
     // to make possible to build huge round-robin (use n_jobs >= cpus count) of communicating agents
     //rayon::ThreadPoolBuilder::new().num_threads(100).build_global().unwrap();
-    let solution = Solver::solve(domain_builder.clone(), cotwin_builder.clone(), agent_builder, 
-    10, Some(vec![0, 0, 3]), SolverLoggingLevels::FreshOnly, 
-    None, None);
+    let solver_config = SolverConfig::new(agent_builder)
+                        .with_n_jobs(10)
+                        .with_score_precision(vec![0, 0, 3])
+                        .with_logging_level(SolverLoggingLevels::FreshOnly);
+    let solution = Solver::solve(domain_builder.clone(), cotwin_builder.clone(), solver_config).expect("Solving failed");
 
-    let domain = domain_builder.build_from_solution(&solution, None);
+    let domain = domain_builder.build_from_solution(&solution.get_raw_solution(), None);
     domain.print_metrics();
     domain.print_trip_paths();
 
+    // which constraints dominate the step time (summed over all agents)
+    //for timing_stats in &solution.constraint_timing_stats {
+    //    println!("{}: {} calls, {:.3} ms mean", timing_stats.name, timing_stats.calls_count, timing_stats.get_mean_milliseconds());
+    //}
+
+    // per-constraint breakdown of the found solution
+    //let score_explanation = Solver::explain_score(domain_builder.clone(), cotwin_builder.clone(), &solution.get_raw_solution(), &HashMap::new()).expect("Score explanation failed");
+    //println!("{}", serde_json::to_string_pretty(&score_explanation.to_json().expect("Score explanation serialization failed")).unwrap());
+
     println!("done");
 }
 
@@ -113,13 +125,12 @@ fn main() {
     //let agent_builder = TS(TabuSearch::new(128, 0.2, true, None, None, 10, termination_strategy));
     //let agent_builder = LA(LateAcceptance::new(20, 0.2, None, Some(vec![0.5, 0.5, 0.0, 0.0, 0.0, 0.0]), 10000, termination_strategy));
     //let agent_builder = GA(GeneticAlgorithm::new(128, 0.5, 0.2, 0.05, Some(1.0), None, 0.00001, 10, termination_strategy)); 
-    let solution = Solver::solve(domain_builder.clone(), cotwin_builder.clone(), agent_builder, 
-    10, Some(vec![0, 0, 3]), SolverLoggingLevels::FreshOnly, 
-    None, None);
+    let solver_config = SolverConfig::new(agent_builder).with_n_jobs(10).with_score_precision(vec![0, 0, 3]).with_logging_level(SolverLoggingLevels::FreshOnly);
+    let solution = Solver::solve(domain_builder.clone(), cotwin_builder.clone(), solver_config).expect("Solving failed");
 
     //////////////////////////////////////////////////////////////////////////////////////////////////////////////
     // to try replanning and frozening (pinning entities) scenarios and using existing domain as initial solution
-    let mut interim_domain = domain_builder.build_from_solution(&solution, None);
+    let mut interim_domain = domain_builder.build_from_solution(&solution.get_raw_solution(), None);
     println!();
     interim_domain.print_metrics();
     interim_domain.print_trip_paths();
@@ -133,10 +144,10 @@ fn main() {
     let termination_strategy = SNI(ScoreNoImprovement::new(2*1000)); 
     let agent_builder = TS(TabuSearch::new(128, 0.2, true, None, Some(vec![0.5, 0.5, 0.0, 0.0, 0.0, 0.0]), 10, termination_strategy));
     //let agent_builder = LA(LateAcceptance::new(2, 0.2, true, None, None, 1000, termination_strategy));
-    let solution = Solver::solve(domain_builder.clone(), cotwin_builder.clone(), agent_builder, 
-                    10, Some(vec![0, 0, 3]), SolverLoggingLevels::FreshOnly, 
-                    None, Some(InitialSolutionVariants::DomainObject(interim_domain.clone())));
-    let domain = domain_builder.build_from_solution(&solution, Some(interim_domain));
+    let solver_config = SolverConfig::new(agent_builder).with_n_jobs(10).with_score_precision(vec![0, 0, 3]).with_logging_level(SolverLoggingLevels::FreshOnly)
+                        .with_initial_solution(InitialSolutionVariants::DomainObject(interim_domain.clone()));
+    let solution = Solver::solve(domain_builder.clone(), cotwin_builder.clone(), solver_config).expect("Solving failed");
+    let domain = domain_builder.build_from_solution(&solution.get_raw_solution(), Some(interim_domain));
     domain.print_metrics();
     domain.print_trip_paths();
 
@@ -148,19 +159,19 @@ fn main() {
     let agent_builder = TS(TabuSearch::new(6000, 0.2, true, Some(1.0), None, 10, termination_strategy));
     //let agent_builder = LA(LateAcceptance::new(128, 0.2, None, Some(vec![0.5, 0.5, 0.0, 0.0, 0.0, 0.0]), 10000, termination_strategy));
     //let agent_builder = GA(GeneticAlgorithm::new(128, 0.5, 0.2, 0.05, Some(1.0), None, 0.00001, 10, termination_strategy)); 
-    let solution = Solver::solve(domain_builder.clone(), cotwin_builder.clone(), agent_builder, 
-        10, Some(vec![0, 0, 3]), SolverLoggingLevels::FreshOnly,
-        None, Some(InitialSolutionVariants::CotwinValuesVector(solution)));
+    let solver_config = SolverConfig::new(agent_builder).with_n_jobs(10).with_score_precision(vec![0, 0, 3]).with_logging_level(SolverLoggingLevels::FreshOnly)
+                        .with_initial_solution(InitialSolutionVariants::CotwinValuesVector(solution.get_raw_solution()));
+    let solution = Solver::solve(domain_builder.clone(), cotwin_builder.clone(), solver_config).expect("Solving failed");
 
     // 3-rd stage (just to try increase quality and reuse solution from 2-nd stage)
     /*let termination_strategy = SNI(ScoreNoImprovement::new(30*1000));
     let agent_builder = TS(TabuSearch::new(512, 0.2, true, None, None, 10, termination_strategy));    
     //let agent_builder = LA(LateAcceptance::new(200, 0.2, None, None, 10000, termination_strategy));
-    let solution = Solver::solve(domain_builder.clone(), cotwin_builder.clone(), agent_builder, 
-    10, Some(vec![0, 0, 3]), SolverLoggingLevels::FreshOnly, 
-    None, Some(InitialSolutionVariants::CotwinValuesVector(solution)));*/
+    let solver_config = SolverConfig::new(agent_builder).with_n_jobs(10).with_score_precision(vec![0, 0, 3]).with_logging_level(SolverLoggingLevels::FreshOnly)
+                        .with_initial_solution(InitialSolutionVariants::CotwinValuesVector(solution.get_raw_solution()));
+    let solution = Solver::solve(domain_builder.clone(), cotwin_builder.clone(), solver_config).expect("Solving failed");*/
 
-    let domain = domain_builder.build_from_solution(&solution, None);
+    let domain = domain_builder.build_from_solution(&solution.get_raw_solution(), None);
     domain.print_metrics();
     domain.print_trip_paths();*/
 
@@ -7,6 +7,7 @@ use greyjack::cotwin::{Cotwin, CotwinEntityTrait, CotwinValueTypes, CotwinBuilde
 use greyjack::score_calculation::score_calculators::score_calculator_variants::ScoreCalculatorVariants;
 use greyjack::score_calculation::scores::HardMediumSoftScore;
 use greyjack::variables::GJInteger;
+use greyjack::errors::GreyjackError;
 use polars::frame::DataFrame;
 use std::collections::{HashMap, HashSet};
 use polars::datatypes::AnyValue;
@@ -127,6 +128,10 @@ impl CotwinBuilder {
                 vehicle_id: CotwinValueTypes::GJI(GJInteger::new(
                     initial_vehicle_ids[i - n_depots], 0, (k_vehicles-1) as i64, is_frozen[i - n_depots], 
                     Some(vec!["vehicle_assignment".to_string(), "common".to_string()]))),
+                    // if only some vehicles can serve a customer (skills, capacity classes), restrict them:
+                    // Some(...)).with_allowed_values(allowed_vehicle_ids[i - n_depots].clone())),
+                    // if the fleet can't serve everyone, let customers stay unserved (null vehicle_id) and penalize nulls softly:
+                    // Some(...)).with_allow_unassigned(0.05)),
                 // init: Some(i as i64)
                 customer_id: CotwinValueTypes::GJI(GJInteger::new(
                     initial_customer_ids[i - n_depots], n_depots as i64, (n_locations-1) as i64, is_frozen[i - n_depots], 
@@ -276,12 +281,16 @@ impl CotwinBuilder {
 
 impl<'a> CotwinBuilderTrait<VehicleRoutingPlan, EntityVariants<'a>, UtilityObjectVariants, HardMediumSoftScore> for CotwinBuilder {
 
-    fn build_cotwin(&self, domain: VehicleRoutingPlan, is_already_initialized: bool) -> Cotwin<EntityVariants<'a>, UtilityObjectVariants, HardMediumSoftScore> {
+    fn build_cotwin(&self, domain: VehicleRoutingPlan, is_already_initialized: bool) -> Result<Cotwin<EntityVariants<'a>, UtilityObjectVariants, HardMediumSoftScore>, GreyjackError> {
         
         let mut cotwin = Cotwin::new();
         cotwin.add_problem_facts("vehicles".to_string(), self.build_problem_fact_vehicles(&domain));
         cotwin.add_problem_facts("customers".to_string(), self.build_problem_fact_customers(&domain));
         cotwin.add_planning_entities("planning_stops".to_string(), self.build_planning_stops(&domain, is_already_initialized));
+        // derived columns (arrival times, vehicle loads) can be kept in planning_stops by a shadow variables listener,
+        // in incremental mode only stops of the changed vehicles are recomputed:
+        //cotwin.add_shadow_variables_listener("planning_stops".to_string(), ShadowVariablesListener::new(
+        //    vec!["arrival_time".to_string()], Some("vehicle_id".to_string()), Box::new(compute_arrival_times)));
 
         if self.use_incremental_score_calculation {
             let mut score_calculator = VRPIncrementalScoreCalculator::new();
@@ -303,7 +312,7 @@ impl<'a> CotwinBuilderTrait<VehicleRoutingPlan, EntityVariants<'a>, UtilityObjec
             cotwin.add_score_calculator(ScoreCalculatorVariants::PSC(score_calculator));   
         }
 
-        return cotwin;
+        return Ok(cotwin);
     }
 
 }
\ No newline at end of file
@@ -19,10 +19,13 @@ impl VRPPlainScoreCalculator {
 
         score_calculator.add_prescoring_function("build_common_df".to_string(), Box::new(Self::build_common_df));
 
-        score_calculator.add_constraint("no_duplicating_stops_constraint".to_string(), Box::new(Self::no_duplicating_stops_constraint));
-        score_calculator.add_constraint("capacity_constraint".to_string(), Box::new(Self::capacity_constraint));
-        score_calculator.add_constraint("minimize_distance".to_string(), Box::new(Self::minimize_distance));
-        score_calculator.add_constraint("late_arrival_penalty".to_string(), Box::new(Self::late_arrival_penalty));
+        // constraints only read common_df, so they can be evaluated concurrently
+        score_calculator.add_parallel_constraint("no_duplicating_stops_constraint".to_string(), Box::new(Self::no_duplicating_stops_constraint));
+        score_calculator.add_parallel_constraint("capacity_constraint".to_string(), Box::new(Self::capacity_constraint));
+        score_calculator.add_parallel_constraint("minimize_distance".to_string(), Box::new(Self::minimize_distance));
+        score_calculator.add_parallel_constraint("late_arrival_penalty".to_string(), Box::new(Self::late_arrival_penalty));
+        // worth enabling when n_jobs is smaller than the count of CPUs
+        //score_calculator.set_parallel_constraints(true);
 
         return score_calculator;
     }
@@ -51,7 +54,7 @@ impl VRPPlainScoreCalculator {
     fn no_duplicating_stops_constraint(
         planning_entity_dfs: &HashMap<String, DataFrame>, 
         problem_fact_dfs: &HashMap<String, DataFrame>,
-        utility_objects: &mut HashMap<String, UtilityObjectVariants>,
+        utility_objects: &HashMap<String, UtilityObjectVariants>,
     ) -> Vec<HardMediumSoftScore> {
 
         let planning_stops_df = planning_entity_dfs["planning_stops"].clone();
@@ -82,7 +85,7 @@ impl VRPPlainScoreCalculator {
     fn capacity_constraint(
         planning_entity_dfs: &HashMap<String, DataFrame>, 
         problem_fact_dfs: &HashMap<String, DataFrame>,
-        utility_objects: &mut HashMap<String, UtilityObjectVariants>,
+        utility_objects: &HashMap<String, UtilityObjectVariants>,
     ) -> Vec<HardMediumSoftScore> {
 
         let vehicle_df = problem_fact_dfs["vehicles"].clone().lazy();
@@ -124,7 +127,7 @@ impl VRPPlainScoreCalculator {
     fn minimize_distance(
         planning_entity_dfs: &HashMap<String, DataFrame>, 
         problem_fact_dfs: &HashMap<String, DataFrame>,
-        utility_objects: &mut HashMap<String, UtilityObjectVariants>,
+        utility_objects: &HashMap<String, UtilityObjectVariants>,
     ) -> Vec<HardMediumSoftScore> {
 
         let common_df: DataFrame;
@@ -173,7 +176,7 @@ impl VRPPlainScoreCalculator {
     fn late_arrival_penalty(
         planning_entity_dfs: &HashMap<String, DataFrame>, 
         problem_fact_dfs: &HashMap<String, DataFrame>,
-        utility_objects: &mut HashMap<String, UtilityObjectVariants>,
+        utility_objects: &HashMap<String, UtilityObjectVariants>,
     ) -> Vec<HardMediumSoftScore> {
 
         let common_df: DataFrame;
@@ -5,12 +5,14 @@ mod persistence;
 mod observers;
 
 use std::default;
-use std::path::{PathBuf, Path};
 use greyjack::domain::DomainBuilderTrait;
 use greyjack::cotwin::CotwinBuilderTrait;
 use lapin::{Channel, Consumer, ExchangeKind};
 use persistence::{CotwinBuilder, DomainBuilder};
-use greyjack::solver::{InitialSolutionVariants, ObserverTrait, Solver, SolverLoggingLevels};
+use domain::VehicleRoutingPlan;
+use greyjack::errors::GreyjackError;
+use greyjack::score_calculation::scores::HardMediumSoftScore;
+use greyjack::solver::{InitialSolutionVariants, ObserverTrait, Solver, SolverConfig, SolverLoggingLevels};
 use greyjack::agents::{GeneticAlgorithm, LateAcceptance, TabuSearch};
 use greyjack::agents::AgentBuildersVariants::*;
 use greyjack::agents::termination_strategies::*;
@@ -26,9 +28,15 @@ use lapin::{
     ConnectionProperties, Result, message::Delivery,
 };
 
+// whole solver setup (agent, termination strategy, n_jobs, etc) is read from config file next to Cargo.toml
+const SOLVER_CONFIG_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/solver_config.toml");
+
 #[tokio::main]
 async fn main() {
 
+    // config is read and validated once, so a broken config stops the service at start instead of failing every task
+    let solver_config_string = load_solver_config_string().expect("Invalid solver config");
+
     // RabbitMQ instance deployed inside Docker on Ubuntu virtual machine by the official guide
     let rabbitmq_address = "amqp://192.168.0.189:5672/%2f";
     let connection = Connection::connect(&rabbitmq_address, ConnectionProperties::default())
@@ -42,16 +50,23 @@ async fn main() {
         match incoming_message {
             Ok(incoming_message) => {
                 //println!("{:?}", incoming_message);
-                if let Err(err) = solve_vrp(&incoming_message, &mut solution_sender_channel, tokio_runtime.clone()).await {
-                    incoming_message
-                        .nack(Default::default())
-                        .await
-                        .expect("Failed to send nack");
-                } else {
-                    incoming_message
-                        .ack(Default::default())
-                        .await
-                        .expect("Failed to send ack");
+                let reply_message;
+                match solve_vrp(&incoming_message, &mut solution_sender_channel, tokio_runtime.clone(), &solver_config_string).await {
+                    Ok(_) => {
+                        incoming_message
+                            .ack(Default::default())
+                            .await
+                            .expect("Failed to send ack");
+                        reply_message = "Solving finished".to_string();
+                    }
+                    Err(task_error) => {
+                        println!("Task rejected: {}", task_error);
+                        incoming_message
+                            .nack(Default::default())
+                            .await
+                            .expect("Failed to send nack");
+                        reply_message = format!("Task rejected: {}", task_error);
+                    }
                 }
 
                 let channel = solution_sender_channel.clone();
@@ -61,7 +76,7 @@ async fn main() {
                                 "vrp_solutions_exchange",
                                 "vrp_out",
                                 BasicPublishOptions::default(),
-                                "Solving finished".to_string().as_bytes(),
+                                reply_message.as_bytes(),
                                 BasicProperties::default(),
                             )
                             .await;
@@ -77,29 +92,31 @@ async fn main() {
     println!("done");
 }
 
-async fn solve_vrp(delivery: &Delivery, solution_sender_channel: &mut Channel, tokio_runtime: Handle) -> Result<()> {
+fn load_solver_config_string() -> std::result::Result<String, GreyjackError> {
+
+    let config_string = std::fs::read_to_string(SOLVER_CONFIG_PATH)
+        .map_err(|read_error| GreyjackError::InvalidConfig(format!("Failed to read {}: {}", SOLVER_CONFIG_PATH, read_error)))?;
+    SolverConfig::<VehicleRoutingPlan, HardMediumSoftScore>::from_toml_str(&config_string)?;
+
+    return Ok(config_string);
+}
+
+async fn solve_vrp(delivery: &Delivery, solution_sender_channel: &mut Channel, tokio_runtime: Handle, solver_config_string: &str) -> std::result::Result<(), GreyjackError> {
 
-    let vrp_json_string = match std::str::from_utf8(&delivery.data) {
-        Ok(v) => v.to_string(),
-        Err(e) => panic!("Invalid UTF-8 sequence: {}", e),
-    };
-    let vrp_json: Value = serde_json::from_str(&vrp_json_string).unwrap();
+    let vrp_json_string = std::str::from_utf8(&delivery.data)
+        .map_err(|utf8_error| GreyjackError::Serialization(format!("Invalid UTF-8 sequence: {}", utf8_error)))?;
+    let vrp_json: Value = serde_json::from_str(vrp_json_string)?;
 
 
     let domain_builder = DomainBuilder::new(&vrp_json);
     let cotwin_builder = CotwinBuilder::new(true, true);
 
-    let termination_strategy = SNI(ScoreNoImprovement::new(5*1000));
-    let agent_builder = TS(TabuSearch::new(1024, 0.2, true, None, Some(vec![0.5, 0.5, 0.0, 0.0, 0.0, 0.0]), 10, termination_strategy));
+    // SolverConfig owns observers and isn't cloneable, so each task parses its own copy of the validated config
+    let solver_config = SolverConfig::from_toml_str(solver_config_string)?;
 
     let rabbitmq_observer = RabbitMQObserver::new(domain_builder.clone(), solution_sender_channel.clone(), tokio_runtime.clone());
-    let mut observers: Vec<Box<dyn ObserverTrait + Send>> = Vec::new();
-    observers.push(Box::new(rabbitmq_observer));
-    Solver::solve(
-        domain_builder.clone(), cotwin_builder, agent_builder, 
-        10, Some(vec![0, 0, 3]), SolverLoggingLevels::FreshOnly, 
-        Some(observers), None,
-    );
+    let solver_config = solver_config.with_observer(Box::new(rabbitmq_observer));
+    Solver::solve(domain_builder.clone(), cotwin_builder, solver_config)?;
 
     return Ok(());
 }
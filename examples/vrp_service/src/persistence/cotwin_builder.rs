@@ -7,6 +7,7 @@ use greyjack::cotwin::{Cotwin, CotwinEntityTrait, CotwinValueTypes, CotwinBuilde
 use greyjack::score_calculation::score_calculators::score_calculator_variants::ScoreCalculatorVariants;
 use greyjack::score_calculation::scores::HardMediumSoftScore;
 use greyjack::variables::GJInteger;
+use greyjack::errors::GreyjackError;
 use polars::frame::DataFrame;
 use std::collections::{HashMap, HashSet};
 use polars::datatypes::AnyValue;
@@ -275,7 +276,7 @@ impl CotwinBuilder {
 
 impl<'a> CotwinBuilderTrait<VehicleRoutingPlan, EntityVariants<'a>, UtilityObjectVariants, HardMediumSoftScore> for CotwinBuilder {
 
-    fn build_cotwin(&self, domain: VehicleRoutingPlan, is_already_initialized: bool) -> Cotwin<EntityVariants<'a>, UtilityObjectVariants, HardMediumSoftScore> {
+    fn build_cotwin(&self, domain: VehicleRoutingPlan, is_already_initialized: bool) -> Result<Cotwin<EntityVariants<'a>, UtilityObjectVariants, HardMediumSoftScore>, GreyjackError> {
         
         let mut cotwin = Cotwin::new();
         cotwin.add_problem_facts("vehicles".to_string(), self.build_problem_fact_vehicles(&domain));
@@ -302,7 +303,7 @@ impl<'a> CotwinBuilderTrait<VehicleRoutingPlan, EntityVariants<'a>, UtilityObjec
             cotwin.add_score_calculator(ScoreCalculatorVariants::PSC(score_calculator));   
         }
 
-        return cotwin;
+        return Ok(cotwin);
     }
 
 }
\ No newline at end of file
@@ -5,7 +5,7 @@ use std::collections::HashMap;
 use std::fs::File;
 use std::io::{prelude::*, BufReader, Lines};
 use regex::Regex;
-use greyjack::domain::DomainBuilderTrait;
+use greyjack::domain::{DomainBuilderTrait, FromAnyValue, SolutionView};
 use greyjack::utils::math_utils::round;
 use greyjack::score_calculation::scores::HardMediumSoftScore;
 use serde_json::*;
@@ -113,30 +113,13 @@ impl DomainBuilderTrait<VehicleRoutingPlan> for DomainBuilder {
     fn build_from_solution(&self, solution: &Value, initial_domain: Option<VehicleRoutingPlan>) -> VehicleRoutingPlan {
 
         let mut domain = self.build_domain_from_scratch();
-        let solution: (Vec<(String, AnyValue)>, HardMediumSoftScore) = from_value(solution.clone()).unwrap();
-        let solution = solution.0;
-
-        for i in (0..solution.len()).step_by(2) {
-            
-            // TODO: think about how to preserve order in cotwin entity representations
-            let (encoded_vehicle_id, encoded_customer_id);
-            if solution[i].0.contains("vehicle") {
-                (encoded_vehicle_id, encoded_customer_id) = (i, i+1);
-            } else {
-                (encoded_vehicle_id, encoded_customer_id) = (i+1, i);
-            }
-
-            let vehicle_id: usize;
-            match solution[encoded_vehicle_id].1 {
-                AnyValue::Int64(v_i) => vehicle_id = v_i as usize,
-                _ => panic!("Dragons")
-            }
+        let solution_view = SolutionView::from_raw_solution(solution).expect("Invalid VRP solution");
+        let planning_stops = solution_view.get_entities("planning_stops").expect("VRP solution doesn't contain planning_stops");
 
-            let customer_id;
-            match solution[encoded_customer_id].1 {
-                AnyValue::Int64(c_i) => customer_id = c_i as usize,
-                _ => panic!("Dragons")
-            }
+        // stops go by entity index, so customers are pushed in the order of stops in cotwin
+        for stop_values in planning_stops.values() {
+            let vehicle_id = usize::from_any_value(&stop_values["vehicle_id"]).expect("Invalid vehicle_id");
+            let customer_id = usize::from_any_value(&stop_values["customer_id"]).expect("Invalid customer_id");
 
             let current_customer = domain.customers_vec[customer_id].clone();
             domain.vehicles[vehicle_id].customers.push( current_customer );
@@ -0,0 +1,126 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr};
+
+/*
+    #[derive(CotwinEntity)] implements CotwinEntityTrait for a struct with named fields.
+    Fields marked #[planning_variable] (GJFloat, GJInteger, GJBool, GJCategorical, GJList)
+    become planning variables, other fields become problem values (PAV) through ToAnyValue.
+    Column names are field names, columns go in the order of fields declaration.
+*/
+#[proc_macro_derive(CotwinEntity, attributes(planning_variable))]
+pub fn derive_cotwin_entity(input: TokenStream) -> TokenStream {
+
+    let input = parse_macro_input!(input as DeriveInput);
+    let struct_name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields;
+    match &input.data {
+        Data::Struct(data_struct) => {
+            match &data_struct.fields {
+                Fields::Named(named_fields) => fields = &named_fields.named,
+                _ => return syn::Error::new_spanned(struct_name, "CotwinEntity can be derived only for structs with named fields").to_compile_error().into(),
+            }
+        },
+        _ => return syn::Error::new_spanned(struct_name, "CotwinEntity can be derived only for structs").to_compile_error().into(),
+    }
+
+    let mut field_pushes = Vec::new();
+    for field in fields {
+        let field_name = field.ident.as_ref().unwrap();
+        let column_name = field_name.to_string();
+        let is_planning_variable = field.attrs.iter().any(|attribute| attribute.path().is_ident("planning_variable"));
+
+        if is_planning_variable {
+            field_pushes.push(quote! {
+                entity_vec.push((#column_name.to_string(), ::greyjack::cotwin::PlanningVariableTrait::to_cotwin_value(&self.#field_name)));
+            });
+        } else {
+            field_pushes.push(quote! {
+                entity_vec.push((#column_name.to_string(), ::greyjack::cotwin::CotwinValueTypes::PAV(::greyjack::cotwin::ToAnyValue::to_any_value(&self.#field_name))));
+            });
+        }
+    }
+
+    let expanded = quote! {
+        impl #impl_generics ::greyjack::cotwin::CotwinEntityTrait for #struct_name #type_generics #where_clause {
+            fn to_vec(&self) -> Vec<(String, ::greyjack::cotwin::CotwinValueTypes)> {
+                let mut entity_vec: Vec<(String, ::greyjack::cotwin::CotwinValueTypes)> = Vec::new();
+                #(#field_pushes)*
+                return entity_vec;
+            }
+        }
+    };
+
+    return expanded.into();
+}
+
+/*
+    #[derive(DomainEntity)] implements DomainEntityTrait for a domain struct with named fields.
+    Fields marked #[solved_value] are set from the solved value of the cotwin column with the same name,
+    #[solved_value(column = "row_id")] takes another column. Values are converted by FromAnyValue.
+    Fields without the attribute are left untouched.
+*/
+#[proc_macro_derive(DomainEntity, attributes(solved_value))]
+pub fn derive_domain_entity(input: TokenStream) -> TokenStream {
+
+    let input = parse_macro_input!(input as DeriveInput);
+    let struct_name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields;
+    match &input.data {
+        Data::Struct(data_struct) => {
+            match &data_struct.fields {
+                Fields::Named(named_fields) => fields = &named_fields.named,
+                _ => return syn::Error::new_spanned(struct_name, "DomainEntity can be derived only for structs with named fields").to_compile_error().into(),
+            }
+        },
+        _ => return syn::Error::new_spanned(struct_name, "DomainEntity can be derived only for structs").to_compile_error().into(),
+    }
+
+    let mut field_assignments = Vec::new();
+    for field in fields {
+        let field_name = field.ident.as_ref().unwrap();
+        for attribute in field.attrs.iter().filter(|attribute| attribute.path().is_ident("solved_value")) {
+
+            let mut column_name = field_name.to_string();
+            if let syn::Meta::List(_) = &attribute.meta {
+                let parse_result = attribute.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("column") {
+                        column_name = meta.value()?.parse::<LitStr>()?.value();
+                        return Ok(());
+                    }
+                    return Err(meta.error("expected `column = \"...\"`"));
+                });
+                if let Err(parse_error) = parse_result {
+                    return parse_error.to_compile_error().into();
+                }
+            }
+
+            field_assignments.push(quote! {
+                match solved_values.get(#column_name) {
+                    Some(solved_value) => self.#field_name = ::greyjack::domain::FromAnyValue::from_any_value(solved_value)?,
+                    None => return Err(::greyjack::errors::GreyjackError::Serialization(
+                        format!("solved values of {} don't contain column \"{}\"", stringify!(#struct_name), #column_name)
+                    )),
+                }
+            });
+        }
+    }
+
+    let expanded = quote! {
+        impl #impl_generics ::greyjack::domain::DomainEntityTrait for #struct_name #type_generics #where_clause {
+            fn set_solved_values(
+                &mut self,
+                solved_values: &::greyjack::domain::SolvedValues
+            ) -> Result<(), ::greyjack::errors::GreyjackError> {
+                #(#field_assignments)*
+                return Ok(());
+            }
+        }
+    };
+
+    return expanded.into();
+}
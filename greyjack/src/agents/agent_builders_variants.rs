@@ -1,12 +1,17 @@
 
 
 use super::{GeneticAlgorithm, LateAcceptance, TabuSearch, SimulatedAnnealing, LSHADE};
+use super::base::Agent;
+use crate::cotwin::CotwinEntityTrait;
+use crate::score_calculation::score_requesters::OOPScoreRequester;
 use crate::score_calculation::scores::ScoreTrait;
 use std::ops::{AddAssign, Sub};
 use std::fmt::{Debug, Display};
-use serde::Serialize;
+use serde::{Serialize, Deserialize};
+use crate::utils::math_utils;
+use crate::errors::GreyjackError;
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum AgentBuildersVariants<ScoreType>
 where
     ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
@@ -15,4 +20,75 @@ where
     TS(TabuSearch<ScoreType>),
     SA(SimulatedAnnealing<ScoreType>),
     LSH(LSHADE<ScoreType>),
-}
\ No newline at end of file
+}
+impl<ScoreType> AgentBuildersVariants<ScoreType>
+where
+    ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
+
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+        match self {
+            AgentBuildersVariants::GA(ga_builder) => ga_builder.validate(),
+            AgentBuildersVariants::LA(la_builder) => la_builder.validate(),
+            AgentBuildersVariants::TS(ts_builder) => ts_builder.validate(),
+            AgentBuildersVariants::SA(sa_builder) => sa_builder.validate(),
+            AgentBuildersVariants::LSH(lsh_builder) => lsh_builder.validate(),
+        }
+    }
+
+    // score requester can be already used by an agent of the previous phase, so its cotwin isn't rebuilt
+    pub fn build_agent<EntityVariants, UtilityObjectVariants>(
+        &self,
+        score_requester: OOPScoreRequester<EntityVariants, UtilityObjectVariants, ScoreType>
+    ) -> Result<Agent<EntityVariants, UtilityObjectVariants, ScoreType>, GreyjackError>
+    where
+        EntityVariants: CotwinEntityTrait {
+
+        match self {
+            AgentBuildersVariants::GA(ga_builder) => ga_builder.build_agent(score_requester),
+            AgentBuildersVariants::LA(la_builder) => la_builder.build_agent(score_requester),
+            AgentBuildersVariants::TS(ts_builder) => ts_builder.build_agent(score_requester),
+            AgentBuildersVariants::SA(sa_builder) => sa_builder.build_agent(score_requester),
+            AgentBuildersVariants::LSH(lsh_builder) => lsh_builder.build_agent(score_requester),
+        }
+    }
+}
+
+// checks of parameters, that all agent builders pass to Mover
+pub fn validate_mover_params(
+    agent_name: &str,
+    tabu_entity_rate: f64,
+    mutation_rate_multiplier: Option<f64>,
+    move_probas: &Option<Vec<f64>>,
+    migration_frequency: usize,
+) -> Result<(), GreyjackError> {
+
+    if !(0.0..=1.0).contains(&tabu_entity_rate) {
+        return Err(GreyjackError::InvalidConfig(format!("{}: tabu_entity_rate must be in [0.0, 1.0], got {}", agent_name, tabu_entity_rate)));
+    }
+    match mutation_rate_multiplier {
+        Some(multiplier) if multiplier < 0.0 => {
+            return Err(GreyjackError::InvalidConfig(format!("{}: mutation_rate_multiplier must be non-negative, got {}", agent_name, multiplier)));
+        }
+        _ => ()
+    }
+    match move_probas {
+        Some(probas) => {
+            let moves_count = 6;
+            if probas.len() != moves_count {
+                return Err(GreyjackError::InvalidConfig(format!("{}: move_probas must contain {} values (one per move), got {}", agent_name, moves_count, probas.len())));
+            }
+            if probas.iter().any(|proba| *proba < 0.0) {
+                return Err(GreyjackError::InvalidConfig(format!("{}: move_probas must be non-negative", agent_name)));
+            }
+            if math_utils::round(probas.iter().sum(), 1) != 1.0 {
+                return Err(GreyjackError::InvalidConfig(format!("{}: move_probas sum must be equal to 1.0", agent_name)));
+            }
+        }
+        None => ()
+    }
+    if migration_frequency == 0 {
+        return Err(GreyjackError::InvalidConfig(format!("{}: migration_frequency must be greater than 0", agent_name)));
+    }
+
+    return Ok(());
+}
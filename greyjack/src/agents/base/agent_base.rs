@@ -16,9 +16,12 @@ use crate::solver::observable_trait::ObservableTrait;
 use crate::solver::observer_trait::ObserverTrait;
 use super::AgentToAgentUpdate;
 use super::AgentStatuses;
+use super::ParetoArchive;
+use crate::errors::GreyjackError;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::fmt::{Debug, Display};
 use std::ops::{AddAssign, Sub};
 use crossbeam_channel::*;
@@ -44,6 +47,12 @@ where
     pub global_top_individual: Arc<Mutex<Individual<ScoreType>>>,
     pub global_top_json: Arc<Mutex<Value>>,
     pub is_global_top_updated: bool,
+    pub termination_flag: Arc<AtomicBool>,
+    pub solver_termination_strategy: Arc<Mutex<Option<TerminationStrategiesVariants<ScoreType>>>>,
+    pub phase_termination_flag: Arc<AtomicBool>,
+    pub phase_termination_strategy: Arc<Mutex<Option<TerminationStrategiesVariants<ScoreType>>>>,
+    pub pareto_archive: Option<ParetoArchive<ScoreType>>,
+    pub initial_individual: Option<Individual<ScoreType>>,
     
     pub score_requester: OOPScoreRequester<EntityVariants, UtilityObjectVariants, ScoreType>,
     pub score_precision: Option<Vec<u64>>,
@@ -51,11 +60,11 @@ where
     
     pub steps_to_send_updates: usize,
     pub agent_status: AgentStatuses,
-    pub round_robin_status_vec: Vec<AgentStatuses>,
+    pub agents_statuses: Arc<Mutex<Vec<AgentStatuses>>>,
     pub alive_agents_count: usize,
     pub comparisons_to_global_count: usize,
 
-    pub updates_to_agent_sender: Option<Sender<AgentToAgentUpdate<ScoreType>>>,
+    pub updates_to_agents_senders: Vec<Sender<AgentToAgentUpdate<ScoreType>>>,
     pub updates_for_agent_receiver: Option<Receiver<AgentToAgentUpdate<ScoreType>>>,
     pub received_fresh_candidate: bool,
     pub solving_start: i64,
@@ -97,6 +106,12 @@ where
             global_top_individual: global_top_individual,
             global_top_json: Arc::new(Mutex::new(Value::Null)),
             is_global_top_updated: false,
+            termination_flag: Arc::new(AtomicBool::new(false)), // setups by Solver
+            solver_termination_strategy: Arc::new(Mutex::new(None)), // setups by Solver
+            phase_termination_flag: Arc::new(AtomicBool::new(false)), // setups by Solver
+            phase_termination_strategy: Arc::new(Mutex::new(None)), // setups by Solver
+            pareto_archive: None, // setups by Solver
+            initial_individual: None, // setups by Solver (construction heuristic)
             
             
             score_requester: score_requester,
@@ -105,8 +120,8 @@ where
             
             steps_to_send_updates: migration_frequency,
             agent_status: AgentStatuses::Alive,
-            round_robin_status_vec: Vec::new(), // setups by Solver
-            updates_to_agent_sender: None, // setups by Solver
+            agents_statuses: Arc::new(Mutex::new(Vec::new())), // setups by Solver
+            updates_to_agents_senders: Vec::new(), // setups by Solver
             updates_for_agent_receiver: None, // setups by Solver
             received_fresh_candidate: true,
             alive_agents_count: 1, // setups by Solver
@@ -121,9 +136,16 @@ where
         }
     }
 
-    pub fn solve(&mut self) {
+    pub fn set_random_seed(&mut self, random_seed: u64) {
+        self.score_requester.variables_manager.set_random_seed(random_seed);
+        self.metaheuristic_base.as_trait().set_random_seed(random_seed);
+    }
+
+    pub fn solve(&mut self) -> Result<(), GreyjackError> {
 
-        self.init_population();
+        self.init_population()?;
+        let initial_population = self.population.clone();
+        self.update_pareto_archive(initial_population);
         self.population.sort();
         self.update_top_individual();
         self.update_termination_strategy();
@@ -134,17 +156,23 @@ where
 
         loop {
             self.set_agent_step_dependent_params();
+            let is_step_done;
             match self.agent_status {
                 AgentStatuses::Alive => {
                     match &self.score_requester.cotwin.score_calculator {
-                        ScoreCalculatorVariants::PSC(psc) => self.step_plain(),
-                        ScoreCalculatorVariants::ISC(isc) => self.step_incremental(),
-                        ScoreCalculatorVariants::None => panic!("Score calculator is not existing. Check your cotwin builder.")
+                        ScoreCalculatorVariants::PSC(psc) => self.step_plain()?,
+                        ScoreCalculatorVariants::ISC(isc) => self.step_incremental()?,
+                        ScoreCalculatorVariants::None => return Err(GreyjackError::InvalidCotwin("Score calculator is not existing. Check your cotwin builder.".to_string()))
                     }
+                    self.step_id += 1;
+                    is_step_done = true;
+                },
+                // dead agent only keeps exchanging migrants, until all agents are dead
+                AgentStatuses::Dead => {
+                    self.receive_updates();
+                    is_step_done = false;
                 },
-                AgentStatuses::Dead => (),
             }
-            self.step_id += 1;
             
             if self.population_size > 1 {
                 self.population.sort();
@@ -160,34 +188,29 @@ where
             
             self.steps_to_send_updates -= 1;
             if self.steps_to_send_updates <= 0 {
-                if self.agent_id % 2 == 0 {
-                    match self.send_updates() {
-                        Err(x) => return,
-                        _ => ()
-                    }
-                    match self.receive_updates() {
-                        Err(x) => return,
-                        _ => ()
-                    }
-                } else {
-                    match self.receive_updates() {
-                        Err(x) => return,
-                        _ => ()
-                    }
-                    match self.send_updates() {
-                        Err(x) => return,
-                        _ => ()
-                    }
+                self.send_updates();
+                // dead agent has already received updates at the start of the iteration
+                match self.agent_status {
+                    AgentStatuses::Alive => self.receive_updates(),
+                    AgentStatuses::Dead => (),
                 }
                 self.steps_to_send_updates = self.migration_frequency;
             }
             
             self.update_global_top();
+            // shared strategies count steps (StepsLimit), so loops of dead agents mustn't update them
+            if is_step_done {
+                self.update_solver_termination_strategy();
+            }
         }
 
+        // the last step (or the step interrupted by early termination) can contain a new agent's best
+        self.update_global_top();
+
+        return Ok(());
     }
 
-    fn init_population(&mut self) {
+    fn init_population(&mut self) -> Result<(), GreyjackError> {
 
 
         match &self.score_requester.cotwin.score_calculator {
@@ -197,7 +220,12 @@ where
                     let mut generated_sample = self.score_requester.variables_manager.sample_variables();
                     samples.push(generated_sample);
                 }
-                let scores = self.score_requester.request_score_plain(&samples);
+                // the constructed solution replaces the first random one
+                match &self.initial_individual {
+                    Some(initial_individual) => samples[0] = initial_individual.variable_values.clone(),
+                    None => (),
+                }
+                let scores = self.score_requester.request_score_plain(&samples)?;
 
                 for i in 0..self.population_size {
                     self.population.push(Individual::new(samples[i].clone(), scores[i].clone()));
@@ -205,16 +233,28 @@ where
             },
 
             ScoreCalculatorVariants::ISC(isc) => {
-                let generated_sample = self.score_requester.variables_manager.sample_variables();
+                let generated_sample;
+                match &self.initial_individual {
+                    Some(initial_individual) => generated_sample = initial_individual.variable_values.clone(),
+                    None => generated_sample = self.score_requester.variables_manager.sample_variables(),
+                }
                 let mut deltas: Vec<Vec<(usize, f64)>> = Vec::new();
                 deltas.push(generated_sample.iter().enumerate().map(|i_val| (i_val.0, i_val.1.clone())).collect());
-                let scores = self.score_requester.request_score_incremental(&generated_sample, &deltas);
+                let scores = self.score_requester.request_score_incremental(&generated_sample, &deltas)?;
                 self.population.push(Individual::new(generated_sample, scores[0].clone()));
             },
 
-            ScoreCalculatorVariants::None => panic!("Score calculator is not existing. Check your cotwin builder.")
+            ScoreCalculatorVariants::None => return Err(GreyjackError::InvalidCotwin("Score calculator is not existing. Check your cotwin builder.".to_string()))
         }
 
+        return Ok(());
+    }
+
+    fn update_pareto_archive(&mut self, individuals: Vec<Individual<ScoreType>>) {
+        match &mut self.pareto_archive {
+            Some(pareto_archive) => individuals.into_iter().for_each(|individual| { pareto_archive.add(individual); }),
+            None => ()
+        }
     }
 
     fn update_top_individual(&mut self) {
@@ -225,33 +265,23 @@ where
 
     fn update_termination_strategy(&mut self) {
         
-        match &mut self.termination_strategy {
-            StL(steps_limit) => steps_limit.update(),
-            SNI(no_improvement) => no_improvement.update(&self.agent_top_individual),
-            TSL(time_spent_limit) => time_spent_limit.update(),
-            ScL(score_limit) => score_limit.update(&self.agent_top_individual)
-        }
+        self.termination_strategy.update(&self.agent_top_individual);
     }
 
     fn update_agent_status(&mut self) {
 
-        let is_accomplish;
-        match &self.termination_strategy {
-            StL(steps_limit) => is_accomplish = steps_limit.is_accomplish(),
-            SNI(no_improvement) => is_accomplish = no_improvement.is_accomplish(),
-            TSL(time_spent_limit) => is_accomplish = time_spent_limit.is_accomplish(),
-            ScL(score_limit) => is_accomplish = score_limit.is_accomplish()
-        }
+        let is_accomplish = self.termination_strategy.is_accomplish();
 
         if is_accomplish {
             self.agent_status = AgentStatuses::Dead;
-            self.round_robin_status_vec[self.agent_id] = self.agent_status;
+            self.agents_statuses.lock().unwrap()[self.agent_id] = self.agent_status;
             
             if self.end_work_message_printed == false {
                 match self.logging_level {
                     SolverLoggingLevels::Silent => (),
                     _ => {
-                        let end_work_message = format!("Agent {} has successfully terminated work. Now it's just transmitting updates between its neighbours until at least one agent is alive.", self.agent_id);
+                        let fired_strategies = self.termination_strategy.get_fired_strategies().join(", ");
+                        let end_work_message = format!("Agent {} has successfully terminated work ({}). Now it's just transmitting updates between its neighbours until at least one agent is alive.", self.agent_id, fired_strategies);
                         println!("{}", end_work_message);
                     }
                 }
@@ -262,7 +292,17 @@ where
     }
 
     fn update_alive_agents_count(&mut self) {
-        self.alive_agents_count = self.round_robin_status_vec.iter().filter(|x| {
+
+        // statuses are shared by all agents of the phase, so they don't depend on the migration topology
+        let mut agents_statuses = self.agents_statuses.lock().unwrap();
+
+        // early termination stops all agents at once
+        if self.termination_flag.load(Ordering::SeqCst) || self.phase_termination_flag.load(Ordering::SeqCst) {
+            self.agent_status = AgentStatuses::Dead;
+            agents_statuses.iter_mut().for_each(|status| *status = AgentStatuses::Dead);
+        }
+
+        self.alive_agents_count = agents_statuses.iter().filter(|x| {
             match x {
                 AgentStatuses::Alive => true,
                 AgentStatuses::Dead => false,
@@ -270,17 +310,17 @@ where
         }).count();
     }
 
-    fn step_plain(&mut self) {
+    fn step_plain(&mut self) -> Result<(), GreyjackError> {
 
         let me_base = self.metaheuristic_base.as_trait();
         let mut new_population: Vec<Individual<ScoreType>> = Vec::new();
             
         //let start_time = chrono::Utc::now().timestamp_millis();
-        let samples: Vec<Vec<f64>> = me_base.sample_candidates_plain(&mut self.population, &self.agent_top_individual, &mut self.score_requester.variables_manager);
+        let samples: Vec<Vec<f64>> = me_base.sample_candidates_plain(&mut self.population, &self.agent_top_individual, &mut self.score_requester.variables_manager)?;
         //println!("Sampling time: {}", chrono::Utc::now().timestamp_millis() - start_time );
         
         //let start_time = chrono::Utc::now().timestamp_millis();
-        let mut scores = self.score_requester.request_score_plain(&samples);
+        let mut scores = self.score_requester.request_score_plain(&samples)?;
         match &self.score_precision {
             Some(precision) => scores.iter_mut().for_each(|score| score.round(&precision)),
             None => ()
@@ -291,37 +331,57 @@ where
         }
         //println!("Scoring time: {}", chrono::Utc::now().timestamp_millis() - start_time );
 
+        match &mut self.pareto_archive {
+            Some(pareto_archive) => candidates.iter().for_each(|candidate| { pareto_archive.add(candidate.clone()); }),
+            None => ()
+        }
         new_population = me_base.build_updated_population(&self.population, &mut candidates);
 
         self.population = new_population;
 
+        return Ok(());
     }
 
-    fn step_incremental(&mut self) {
+    fn step_incremental(&mut self) -> Result<(), GreyjackError> {
 
         let me_base = self.metaheuristic_base.as_trait();
         let mut new_population: Vec<Individual<ScoreType>> = Vec::new();
             
         //let start_time = chrono::Utc::now().timestamp_millis();
-        let (mut sample, deltas) = me_base.sample_candidates_incremental(&mut self.population, &self.agent_top_individual, &mut self.score_requester.variables_manager);
+        let (mut sample, deltas) = me_base.sample_candidates_incremental(&mut self.population, &self.agent_top_individual, &mut self.score_requester.variables_manager)?;
         //println!("Sampling time: {}", chrono::Utc::now().timestamp_millis() - start_time );
 
         //let start_time = chrono::Utc::now().timestamp_millis();
-        let mut scores = self.score_requester.request_score_incremental(&sample, &deltas);
+        let mut scores = self.score_requester.request_score_incremental(&sample, &deltas)?;
         match &self.score_precision {
             Some(precision) => scores.iter_mut().for_each(|score| score.round(&precision)),
             None => ()
         }
         //println!("Scoring time: {}", chrono::Utc::now().timestamp_millis() - start_time );
 
+        match &mut self.pareto_archive {
+            Some(pareto_archive) => {
+                for (candidate_deltas, score) in deltas.iter().zip(scores.iter()) {
+                    // candidate's values are built only for scores, which can get into the archive
+                    if pareto_archive.is_acceptable(score) == false {
+                        continue;
+                    }
+                    let mut candidate_values = sample.clone();
+                    candidate_deltas.iter().for_each(|(var_id, new_value)| candidate_values[*var_id] = *new_value);
+                    pareto_archive.add(Individual::new(candidate_values, score.clone()));
+                }
+            }
+            None => ()
+        }
         new_population = me_base.build_updated_population_incremental(&self.population, &mut sample, deltas, scores);
 
         self.population = new_population;
+
+        return Ok(());
     }
 
-    fn send_updates(&mut self) -> Result<(), String> {
+    fn send_updates(&mut self) {
 
-        let round_robin_status_vec = self.round_robin_status_vec.clone();
         let migrants:Vec<Individual<ScoreType>>;
         match &mut self.metaheuristic_base {
             MetaheuristicsBasesVariants::None => panic!("Metaheuristic base is not initialized"),
@@ -347,50 +407,43 @@ where
 
         }
 
-        let agent_update = AgentToAgentUpdate::new(self.agent_id, migrants, round_robin_status_vec);
-        let send_result = self.updates_to_agent_sender.as_mut().unwrap().send(agent_update);
-        match send_result {
-            Err(e) => {
-                match self.logging_level {
-                    SolverLoggingLevels::Silent => (),
-                    _ => {
-                        let error_message = format!("Warning! Failed to send updates by Agent {} due to {e}", self.agent_id);
-                        println!("{}", error_message);
-                        return Err(error_message);
-                    }
-                }
-            },
-            _ => ()
+        /*
+            Sending never blocks, so agents don't wait for each other in any topology.
+            Full inbox means, that the neighbour hasn't taken the previous migrants yet, so new ones are skipped.
+            Disconnected inbox means, that the neighbour has already finished the phase.
+        */
+        for updates_sender in &self.updates_to_agents_senders {
+            updates_sender.try_send(AgentToAgentUpdate::new(self.agent_id, migrants.clone())).ok();
         }
-
-        Ok(())
     }
 
-    fn receive_updates(&mut self) -> Result<usize, usize> {
+    fn receive_updates(&mut self) {
 
-        // assume that the agent's population is already sorted
-
-        let received_updates;
-        let received_updates_result = self.updates_for_agent_receiver.as_mut().unwrap().recv();
-        match received_updates_result {
-            Err(e) => {
-                match self.logging_level {
-                    SolverLoggingLevels::Silent => (),
-                    _ => {
-                        let error_message = format!("Warning! Failed to receive updates by Agent {} due to {e}", self.agent_id);
-                        println!("{}", error_message)
-                    },
+        let mut received_updates: Vec<AgentToAgentUpdate<ScoreType>> = Vec::new();
+        let updates_receiver = self.updates_for_agent_receiver.as_ref().unwrap();
+        // dead agent calls it on every iteration and only transmits migrants, so it waits for them a bit instead of spinning idle
+        match self.agent_status {
+            AgentStatuses::Alive => (),
+            AgentStatuses::Dead => {
+                match updates_receiver.recv_timeout(std::time::Duration::from_millis(10)) {
+                    Ok(agent_update) => received_updates.push(agent_update),
+                    Err(_) => (),
                 }
-                return Err(1);
-            },
-            Ok(updates) => received_updates = updates
+            }
         }
+        received_updates.extend(updates_receiver.try_iter());
 
-        (0..self.round_robin_status_vec.len()).for_each(|i| {
-            if i != self.agent_id {
-                self.round_robin_status_vec[i] = received_updates.round_robin_status_vec[i];
+        for agent_update in received_updates {
+            self.accept_migrants(agent_update.migrants);
+            if self.population_size > 1 {
+                self.population.sort();
             }
-        });
+        }
+    }
+
+    fn accept_migrants(&mut self, migrants: Vec<Individual<ScoreType>>) {
+
+        // assume that the agent's population is already sorted
 
         let current_agent_kind: MetaheuristicKind;
         match &self.metaheuristic_base {
@@ -405,7 +458,8 @@ where
         let comparison_ids:Vec<usize>;
         match current_agent_kind {
             MetaheuristicKind::Population => {
-                let migrants_count = received_updates.migrants.len();
+                // neighbour of other metaheuristic can send more migrants than this agent's population has
+                let migrants_count = migrants.len().min(self.population_size);
                 comparison_ids = ((self.population_size - migrants_count)..self.population_size).collect();
             },
             MetaheuristicKind::LocalSearch => comparison_ids = vec![0; 1]
@@ -414,8 +468,13 @@ where
         match &mut self.metaheuristic_base {
             MetaheuristicsBasesVariants::None => panic!("Metaheuristic base is not initialized"),
             MetaheuristicsBasesVariants::LAB(la) => {
-                let migrant = &received_updates.migrants[0];
-                if (migrant.score <= la.late_scores.back().unwrap().clone()) || (migrant.score <= self.population[0].score) {
+                let migrant = &migrants[0];
+                // late scores are empty, if migrant comes before the first accepted step
+                let beats_late_score = match la.late_scores.back() {
+                    Some(late_score) => migrant.score <= *late_score,
+                    None => false,
+                };
+                if beats_late_score || (migrant.score <= self.population[0].score) {
                     la.late_scores.push_front(migrant.score.clone());
                     if la.late_scores.len() > la.late_acceptance_size {
                         la.late_scores.pop_back();
@@ -427,20 +486,49 @@ where
                 }
             },
             MetaheuristicsBasesVariants::TSB(tsb) => {
-                let migrant = &received_updates.migrants[0];
+                let migrant = &migrants[0];
                 if migrant.score <= self.population[0].score {
                     self.population[0] = migrant.clone();
                 }
             }
-            _ => (0..received_updates.migrants.len()).for_each(|i| {
-                if received_updates.migrants[i] <= self.population[comparison_ids[i]] {
-                    self.population[comparison_ids[i]] = received_updates.migrants[i].clone();
+            _ => (0..comparison_ids.len()).for_each(|i| {
+                if migrants[i] <= self.population[comparison_ids[i]] {
+                    self.population[comparison_ids[i]] = migrants[i].clone();
                 }
             })
         }
+    }
 
-        Ok(0)
+    fn update_solver_termination_strategy(&mut self) {
+
+        /*
+            Solver-wide strategy is shared by all agents and observes the global top individual.
+            When it's accomplished, termination_flag stops all agents at once (see update_alive_agents_count()).
+        */
+        let mut solver_termination_strategy = self.solver_termination_strategy.lock().unwrap();
+        match &mut *solver_termination_strategy {
+            None => (),
+            Some(termination_strategy) => {
+                let global_top_individual = self.global_top_individual.lock().unwrap().clone();
+                termination_strategy.update(&global_top_individual);
+                if termination_strategy.is_accomplish() {
+                    self.termination_flag.store(true, Ordering::SeqCst);
+                }
+            }
+        }
 
+        // phase-wide strategy works the same way, but stops only agents of the current phase
+        let mut phase_termination_strategy = self.phase_termination_strategy.lock().unwrap();
+        match &mut *phase_termination_strategy {
+            None => (),
+            Some(termination_strategy) => {
+                let global_top_individual = self.global_top_individual.lock().unwrap().clone();
+                termination_strategy.update(&global_top_individual);
+                if termination_strategy.is_accomplish() {
+                    self.phase_termination_flag.store(true, Ordering::SeqCst);
+                }
+            }
+        }
     }
 
     fn update_global_top(&mut self) {
@@ -522,14 +610,7 @@ where
 
     pub fn convert_to_json(&self, individual: Individual<ScoreType>) -> Value {
 
-        let inverse_transformed_variables = self.score_requester.variables_manager.inverse_transform_variables(&individual.variable_values);
-        let variables_names = self.score_requester.variables_manager.get_variables_names_vec();
-        let inverse_transformed_variables: Vec<(String, AnyValue)> = 
-        inverse_transformed_variables.iter()
-        .zip(variables_names.iter())
-        .map(|(x, name)| {
-            (name.clone(), x.clone())
-        }).collect();
+        let inverse_transformed_variables: Vec<(String, AnyValue)> = self.score_requester.variables_manager.get_named_variables(&individual.variable_values);
         let individual_json = json!((inverse_transformed_variables, individual.score));
         return individual_json;
     }
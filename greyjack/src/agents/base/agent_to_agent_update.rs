@@ -1,8 +1,7 @@
 
-use super::{AgentStatuses, Individual};
+use super::Individual;
 use crate::score_calculation::scores::ScoreTrait;
-use std::collections::HashMap;
-use std::ops::{AddAssign, Sub};
+use std::ops::AddAssign;
 use std::fmt::Debug;
 
 
@@ -14,7 +13,6 @@ where
 
     pub agent_id: usize,
     pub migrants: Vec<Individual<ScoreType>>,
-    pub round_robin_status_vec: Vec<AgentStatuses>
 
 }
 
@@ -24,15 +22,13 @@ where
     
     pub fn new(
         agent_id: usize, 
-        migrants: Vec<Individual<ScoreType>>, 
-        round_robin_status_vec: Vec<AgentStatuses>)
+        migrants: Vec<Individual<ScoreType>>)
         -> Self {
 
             Self {
                 agent_id: agent_id,
                 migrants: migrants,
-                round_robin_status_vec,
             }
 
         }
-}
\ No newline at end of file
+}
@@ -3,8 +3,9 @@ use std::fmt::Debug;
 use std::ops::{AddAssign, Sub};
 use std::cmp::Ordering;
 use crate::score_calculation::scores::ScoreTrait;
+use serde::{Serialize, Deserialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Individual<ScoreType>
 where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {
     pub variable_values: Vec<f64>,
@@ -4,8 +4,10 @@ pub mod agent_base;
 pub mod individual;
 pub mod agent_to_agent_update;
 pub mod agent_statuses;
+pub mod pareto_archive;
 
 pub use agent_base::Agent;
 pub use agent_statuses::AgentStatuses;
 pub use individual::Individual;
-pub use agent_to_agent_update::AgentToAgentUpdate;
\ No newline at end of file
+pub use agent_to_agent_update::AgentToAgentUpdate;
+pub use pareto_archive::ParetoArchive;
\ No newline at end of file
@@ -0,0 +1,123 @@
+use std::fmt::Debug;
+use std::ops::AddAssign;
+use crate::score_calculation::scores::ScoreTrait;
+use super::Individual;
+
+/*
+    Non-dominated individuals, found by an agent (or merged from all agents by Solver).
+    When the archive is full, the individual from the most crowded region of the front is dropped
+    (crowding distance from NSGA-II, boundary individuals are always kept).
+*/
+#[derive(Debug, Clone)]
+pub struct ParetoArchive<ScoreType>
+where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {
+    pub max_size: usize,
+    pub individuals: Vec<Individual<ScoreType>>,
+}
+
+impl<ScoreType> ParetoArchive<ScoreType>
+where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {
+
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            max_size: max_size,
+            individuals: Vec::new(),
+        }
+    }
+
+    // cheap check before building candidate's variable values
+    pub fn is_acceptable(&self, score: &ScoreType) -> bool {
+        self.individuals.iter().all(|x| x.score.dominates(score) == false && x.score != *score)
+    }
+
+    pub fn add(&mut self, individual: Individual<ScoreType>) -> bool {
+
+        if self.is_acceptable(&individual.score) == false {
+            return false;
+        }
+
+        self.individuals.retain(|x| individual.score.dominates(&x.score) == false);
+        self.individuals.push(individual);
+        if self.individuals.len() > self.max_size {
+            self.remove_most_crowded();
+        }
+
+        return true;
+    }
+
+    pub fn merge(&mut self, other: ParetoArchive<ScoreType>) {
+        for individual in other.individuals {
+            self.add(individual);
+        }
+    }
+
+    pub fn get_front(&self) -> Vec<Individual<ScoreType>> {
+        let mut front = self.individuals.clone();
+        front.sort();
+        return front;
+    }
+
+    fn remove_most_crowded(&mut self) {
+
+        let individuals_count = self.individuals.len();
+        let score_vecs: Vec<Vec<f64>> = self.individuals.iter().map(|x| x.score.as_vec()).collect();
+        let components_count = score_vecs.iter().map(|x| x.len()).max().unwrap();
+        let mut crowding_distances = vec![0.0; individuals_count];
+
+        for component_id in 0..components_count {
+            let component_value = |individual_id: usize| *score_vecs[individual_id].get(component_id).unwrap_or(&0.0);
+            let mut sorted_ids: Vec<usize> = (0..individuals_count).collect();
+            sorted_ids.sort_by(|a, b| component_value(*a).total_cmp(&component_value(*b)));
+
+            let min_value = component_value(sorted_ids[0]);
+            let max_value = component_value(sorted_ids[individuals_count - 1]);
+            crowding_distances[sorted_ids[0]] = f64::INFINITY;
+            crowding_distances[sorted_ids[individuals_count - 1]] = f64::INFINITY;
+            if max_value - min_value <= 0.0 {
+                continue;
+            }
+            for i in 1..(individuals_count - 1) {
+                let neighbours_gap = component_value(sorted_ids[i + 1]) - component_value(sorted_ids[i - 1]);
+                crowding_distances[sorted_ids[i]] += neighbours_gap / (max_value - min_value);
+            }
+        }
+
+        let mut most_crowded_id = 0;
+        for i in 1..individuals_count {
+            if crowding_distances[i] < crowding_distances[most_crowded_id] {
+                most_crowded_id = i;
+            }
+        }
+        self.individuals.remove(most_crowded_id);
+    }
+}
+
+unsafe impl<ScoreType> Send for ParetoArchive<ScoreType>
+where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::score_calculation::scores::MultiObjectiveScore;
+
+    #[test]
+    fn test_pareto_archive() {
+
+        let mut pareto_archive: ParetoArchive<MultiObjectiveScore> = ParetoArchive::new(3);
+        assert_eq!(pareto_archive.add(Individual::new(vec![0.0], MultiObjectiveScore::new(0.0, vec![5.0, 5.0]))), true);
+        assert_eq!(pareto_archive.add(Individual::new(vec![1.0], MultiObjectiveScore::new(0.0, vec![6.0, 6.0]))), false);
+        assert_eq!(pareto_archive.add(Individual::new(vec![2.0], MultiObjectiveScore::new(0.0, vec![1.0, 9.0]))), true);
+        assert_eq!(pareto_archive.add(Individual::new(vec![3.0], MultiObjectiveScore::new(0.0, vec![9.0, 1.0]))), true);
+        assert_eq!(pareto_archive.add(Individual::new(vec![4.0], MultiObjectiveScore::new(0.0, vec![5.0, 5.0]))), false);
+        assert_eq!(pareto_archive.add(Individual::new(vec![4.0], MultiObjectiveScore::new(0.0, vec![5.0, 5.0, 0.0]))), false);
+        assert_eq!(pareto_archive.individuals.len(), 3);
+
+        // dominates [5, 5] and is the most crowded after that
+        assert_eq!(pareto_archive.add(Individual::new(vec![5.0], MultiObjectiveScore::new(0.0, vec![4.0, 4.0]))), true);
+        assert_eq!(pareto_archive.individuals.len(), 3);
+        pareto_archive.add(Individual::new(vec![6.0], MultiObjectiveScore::new(0.0, vec![2.0, 7.0])));
+        let front_values: Vec<f64> = pareto_archive.get_front().iter().map(|x| x.variable_values[0]).collect();
+        assert_eq!(front_values.len(), 3);
+        assert_eq!(front_values.contains(&2.0) && front_values.contains(&3.0), true);
+    }
+}
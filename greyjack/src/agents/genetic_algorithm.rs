@@ -3,16 +3,17 @@ use super::base::agent_base::Agent;
 use super::metaheuristic_bases::GeneticAlgorithmBase;
 use super::metaheuristic_bases::MetaheuristicsBasesVariants;
 use crate::agents::termination_strategies::TerminationStrategiesVariants;
+use super::agent_builders_variants::validate_mover_params;
 use crate::score_calculation::score_requesters::OOPScoreRequester;
 use crate::score_calculation::scores::ScoreTrait;
 use crate::cotwin::CotwinEntityTrait;
-use crate::cotwin::Cotwin;
+use crate::errors::GreyjackError;
 use std::ops::{AddAssign, Sub};
 use std::fmt::{Debug, Display};
-use serde::Serialize;
+use serde::{Serialize, Deserialize};
 
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GeneticAlgorithm<ScoreType>
 where
     ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize{
@@ -56,20 +57,39 @@ where
         }
     }
 
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+
+        if self.population_size < 2 {
+            return Err(GreyjackError::InvalidConfig(format!("GeneticAlgorithm: population_size must be at least 2, got {}", self.population_size)));
+        }
+        if !(0.0..=1.0).contains(&self.crossover_probability) {
+            return Err(GreyjackError::InvalidConfig(format!("GeneticAlgorithm: crossover_probability must be in [0.0, 1.0], got {}", self.crossover_probability)));
+        }
+        if !(0.0..=1.0).contains(&self.p_best_rate) {
+            return Err(GreyjackError::InvalidConfig(format!("GeneticAlgorithm: p_best_rate must be in [0.0, 1.0], got {}", self.p_best_rate)));
+        }
+        if !(0.0..=1.0).contains(&self.migration_rate) {
+            return Err(GreyjackError::InvalidConfig(format!("GeneticAlgorithm: migration_rate must be in [0.0, 1.0], got {}", self.migration_rate)));
+        }
+        validate_mover_params("GeneticAlgorithm", self.tabu_entity_rate, self.mutation_rate_multiplier, &self.move_probas, self.migration_frequency)?;
+        self.termination_strategy.validate()?;
+
+        return Ok(());
+    }
+
     pub fn build_agent<EntityVariants, UtilityObjectVariants>(
         &self, 
-        cotwin: Cotwin<EntityVariants, UtilityObjectVariants, ScoreType>
-    )  -> Agent<EntityVariants, UtilityObjectVariants, ScoreType>
+        score_requester: OOPScoreRequester<EntityVariants, UtilityObjectVariants, ScoreType>
+    )  -> Result<Agent<EntityVariants, UtilityObjectVariants, ScoreType>, GreyjackError>
     where 
         EntityVariants: CotwinEntityTrait {
 
-        let score_requester = OOPScoreRequester::new(cotwin);
         let semantic_groups_dict = score_requester.variables_manager.semantic_groups_map.clone();
         let discrete_ids = score_requester.variables_manager.discrete_ids.clone();
 
         let metaheuristic_base = GeneticAlgorithmBase::new(self.population_size, self.crossover_probability, self.p_best_rate, self.tabu_entity_rate, 
                                                                                  self.mutation_rate_multiplier, self.move_probas.clone(),
-                                                                                 semantic_groups_dict, discrete_ids);
+                                                                                 semantic_groups_dict, discrete_ids)?;
         let metaheuristic_base = MetaheuristicsBasesVariants::GAB(metaheuristic_base);
         
         let agent: Agent<EntityVariants, UtilityObjectVariants, ScoreType> = Agent::new(self.migration_rate, 
@@ -77,7 +97,7 @@ where
                                                                                         self.population_size, score_requester, 
                                                                                         metaheuristic_base);
         
-        return agent;
+        return Ok(agent);
 
     }
 
@@ -3,16 +3,17 @@ use super::base::agent_base::Agent;
 use super::metaheuristic_bases::LateAcceptanceBase;
 use super::metaheuristic_bases::MetaheuristicsBasesVariants;
 use crate::agents::termination_strategies::TerminationStrategiesVariants;
+use super::agent_builders_variants::validate_mover_params;
 use crate::score_calculation::score_requesters::OOPScoreRequester;
 use crate::score_calculation::scores::ScoreTrait;
 use crate::cotwin::CotwinEntityTrait;
-use crate::cotwin::Cotwin;
+use crate::errors::GreyjackError;
 use std::ops::{AddAssign, Sub};
 use std::fmt::{Debug, Display};
-use serde::Serialize;
+use serde::{Serialize, Deserialize};
 
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LateAcceptance<ScoreType>
 where
     ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
@@ -47,20 +48,30 @@ where
         }
     }
 
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+
+        if self.late_acceptance_size == 0 {
+            return Err(GreyjackError::InvalidConfig("LateAcceptance: late_acceptance_size must be greater than 0".to_string()));
+        }
+        validate_mover_params("LateAcceptance", self.tabu_entity_rate, self.mutation_rate_multiplier, &self.move_probas, self.migration_frequency)?;
+        self.termination_strategy.validate()?;
+
+        return Ok(());
+    }
+
     pub fn build_agent<EntityVariants, UtilityObjectVariants>(
         &self, 
-        cotwin: Cotwin<EntityVariants, UtilityObjectVariants, ScoreType>
-    )  -> Agent<EntityVariants, UtilityObjectVariants, ScoreType>
+        score_requester: OOPScoreRequester<EntityVariants, UtilityObjectVariants, ScoreType>
+    )  -> Result<Agent<EntityVariants, UtilityObjectVariants, ScoreType>, GreyjackError>
     where 
         EntityVariants: CotwinEntityTrait {
 
-        let score_requester = OOPScoreRequester::new(cotwin);
         let semantic_groups_dict = score_requester.variables_manager.semantic_groups_map.clone();
         let discrete_ids = score_requester.variables_manager.discrete_ids.clone();
 
         let metaheuristic_base = LateAcceptanceBase::new(self.late_acceptance_size, self.tabu_entity_rate,
                                                                                  self.mutation_rate_multiplier, self.move_probas.clone(),
-                                                                                 semantic_groups_dict, discrete_ids);
+                                                                                 semantic_groups_dict, discrete_ids)?;
         let metaheuristic_base = MetaheuristicsBasesVariants::LAB(metaheuristic_base);
         
         let agent: Agent<EntityVariants, UtilityObjectVariants, ScoreType> = Agent::new(1.0, 
@@ -68,7 +79,7 @@ where
                                                                                         1, score_requester, 
                                                                                         metaheuristic_base);
         
-        return agent;
+        return Ok(agent);
 
     }
 
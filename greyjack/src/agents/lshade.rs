@@ -3,16 +3,17 @@ use super::base::agent_base::Agent;
 use super::metaheuristic_bases::LSHADEBase;
 use super::metaheuristic_bases::MetaheuristicsBasesVariants;
 use crate::agents::termination_strategies::TerminationStrategiesVariants;
+use super::agent_builders_variants::validate_mover_params;
 use crate::score_calculation::score_requesters::OOPScoreRequester;
 use crate::score_calculation::scores::ScoreTrait;
 use crate::cotwin::CotwinEntityTrait;
-use crate::cotwin::Cotwin;
+use crate::errors::GreyjackError;
 use std::ops::{AddAssign, Sub};
 use std::fmt::{Debug, Display};
-use serde::Serialize;
+use serde::{Serialize, Deserialize};
 
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LSHADE<ScoreType>
 where
     ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
@@ -71,14 +72,34 @@ where
         }
     }
 
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+
+        if self.population_size < 2 {
+            return Err(GreyjackError::InvalidConfig(format!("LSHADE: population_size must be at least 2, got {}", self.population_size)));
+        }
+        if self.history_archive_size == 0 {
+            return Err(GreyjackError::InvalidConfig("LSHADE: history_archive_size must be greater than 0".to_string()));
+        }
+        for (param_name, param_value) in [("p_best_rate", self.p_best_rate), ("memory_pruning_rate", self.memory_pruning_rate), 
+                                          ("initial_f", self.initial_f), ("initial_cr", self.initial_cr),
+                                          ("initial_mutation_proba", self.initial_mutation_proba), ("migration_rate", self.migration_rate)] {
+            if !(0.0..=1.0).contains(&param_value) {
+                return Err(GreyjackError::InvalidConfig(format!("LSHADE: {} must be in [0.0, 1.0], got {}", param_name, param_value)));
+            }
+        }
+        validate_mover_params("LSHADE", self.tabu_entity_rate, self.mutation_rate_multiplier, &self.move_probas, self.migration_frequency)?;
+        self.termination_strategy.validate()?;
+
+        return Ok(());
+    }
+
     pub fn build_agent<EntityVariants, UtilityObjectVariants>(
         &self, 
-        cotwin: Cotwin<EntityVariants, UtilityObjectVariants, ScoreType>
-    )  -> Agent<EntityVariants, UtilityObjectVariants, ScoreType>
+        score_requester: OOPScoreRequester<EntityVariants, UtilityObjectVariants, ScoreType>
+    )  -> Result<Agent<EntityVariants, UtilityObjectVariants, ScoreType>, GreyjackError>
     where 
         EntityVariants: CotwinEntityTrait {
 
-        let score_requester = OOPScoreRequester::new(cotwin);
         let semantic_groups_dict = score_requester.variables_manager.semantic_groups_map.clone();
         let discrete_ids = score_requester.variables_manager.discrete_ids.clone();
 
@@ -94,7 +115,7 @@ where
                                                                         self.mutation_rate_multiplier, 
                                                                         self.move_probas.clone(),
                                                                         semantic_groups_dict, 
-                                                                        discrete_ids);
+                                                                        discrete_ids)?;
         let metaheuristic_base = MetaheuristicsBasesVariants::LSH(metaheuristic_base);
         
         let agent: Agent<EntityVariants, UtilityObjectVariants, ScoreType> = Agent::new(self.migration_rate, 
@@ -102,7 +123,7 @@ where
                                                                                         self.population_size, score_requester, 
                                                                                         metaheuristic_base);
         
-        return agent;
+        return Ok(agent);
 
     }
 
@@ -9,13 +9,14 @@ use crate::agents::base::Individual;
 use std::ops::{AddAssign, Sub};
 use std::fmt::Debug;
 
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
 use rand::rngs::StdRng;
 use rand_distr::{Distribution, Uniform};
 
 use super::Mover;
 use super::metaheuristic_kinds_and_names::{MetaheuristicKind, MetaheuristicNames};
 use crate::utils::math_utils;
+use crate::errors::GreyjackError;
 use std::collections::VecDeque;
 use std::collections::HashSet;
 use std::cmp::max;
@@ -35,6 +36,7 @@ pub struct GeneticAlgorithmBase {
     pub group_mutation_rates_map: HashMap<String, f64>,
     pub discrete_ids: Option<Vec<usize>>,
     pub mover: Mover,
+    pub random_generator: StdRng,
 }
 
 impl GeneticAlgorithmBase {
@@ -48,7 +50,7 @@ impl GeneticAlgorithmBase {
         move_probas: Option<Vec<f64>>,
         semantic_groups_dict: HashMap<String, Vec<usize>>,
         discrete_ids: Option<Vec<usize>>,
-    ) -> Self {
+    ) -> Result<Self, GreyjackError> {
 
         let half_population_size = (0.5 * (population_size as f64)).ceil() as usize;
         let current_mutation_rate_multiplier;
@@ -63,7 +65,7 @@ impl GeneticAlgorithmBase {
             group_mutation_rates_map.insert(group_name.clone(), current_group_mutation_rate);
         }
 
-        Self {
+        Ok(Self {
             population_size: population_size,
             half_population_size: half_population_size,
             crossover_probability: crossover_probability,
@@ -76,16 +78,17 @@ impl GeneticAlgorithmBase {
 
             group_mutation_rates_map: group_mutation_rates_map.clone(),
             discrete_ids: discrete_ids.clone(),
-            mover: Mover::new(tabu_entity_rate, HashMap::new(), HashMap::new(), HashMap::new(), group_mutation_rates_map.clone(), move_probas),
-        }
+            mover: Mover::new(tabu_entity_rate, HashMap::new(), HashMap::new(), HashMap::new(), group_mutation_rates_map.clone(), move_probas)?,
+            random_generator: StdRng::from_entropy(),
+        })
     }
 
     fn select_p_best<ScoreType>(&mut self, population: &Vec<Individual<ScoreType>>) -> Individual<ScoreType>
     where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {
 
-        let p_best_proba = Uniform::new(0.000001, self.p_best_rate).sample(&mut StdRng::from_entropy());
+        let p_best_proba = Uniform::new(0.000001, self.p_best_rate).sample(&mut self.random_generator);
         let last_top_id = (p_best_proba * (self.population_size as f64)).ceil() as usize;
-        let chosen_id:usize = Uniform::new(0, last_top_id).sample(&mut StdRng::from_entropy());
+        let chosen_id:usize = Uniform::new(0, last_top_id).sample(&mut self.random_generator);
         let p_best = population[chosen_id].clone();
 
         return p_best;
@@ -94,9 +97,9 @@ impl GeneticAlgorithmBase {
     fn select_p_worst<ScoreType>(&mut self, population: &Vec<Individual<ScoreType>>) -> Individual<ScoreType>
     where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {
 
-        let p_best_proba = Uniform::new(0.000001, self.p_best_rate).sample(&mut StdRng::from_entropy());
+        let p_best_proba = Uniform::new(0.000001, self.p_best_rate).sample(&mut self.random_generator);
         let last_top_id = (p_best_proba * (self.population_size as f64)).ceil() as usize;
-        let chosen_id: usize = Uniform::new(self.population_size - last_top_id, self.population_size).sample(&mut StdRng::from_entropy());
+        let chosen_id: usize = Uniform::new(self.population_size - last_top_id, self.population_size).sample(&mut self.random_generator);
         let p_worst = population[chosen_id].clone();
 
         return p_worst;
@@ -105,7 +108,7 @@ impl GeneticAlgorithmBase {
     fn cross(&mut self, candidate_1: Vec<f64>, candidate_2: Vec<f64>) -> (Vec<f64>, Vec<f64>) {
 
         let variables_count = candidate_1.len();
-        let mut weights = vec![Uniform::new_inclusive(0.0, 1.0).sample(&mut StdRng::from_entropy()); variables_count];
+        let mut weights = vec![Uniform::new_inclusive(0.0, 1.0).sample(&mut self.random_generator); variables_count];
 
         match &self.discrete_ids {
             None => (),
@@ -143,7 +146,7 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
             population: &mut Vec<Individual<ScoreType>>, 
             current_top_individual: &Individual<ScoreType>,
             variables_manager: &VariablesManager
-        ) -> Vec<Vec<f64>> {
+        ) -> Result<Vec<Vec<f64>>, GreyjackError> {
 
         if self.mover.tabu_entity_size_map.len() == 0 {
             let semantic_groups_map = variables_manager.semantic_groups_map.clone();
@@ -161,15 +164,15 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
             let mut candidate_1 = self.select_p_best(population).variable_values;
             let mut candidate_2 = self.select_p_best(population).variable_values;
 
-            if Uniform::new_inclusive(0.0, 1.0).sample(&mut StdRng::from_entropy()) <= self.crossover_probability {
+            if Uniform::new_inclusive(0.0, 1.0).sample(&mut self.random_generator) <= self.crossover_probability {
                 (candidate_1, candidate_2) = self.cross(candidate_1, candidate_2);
             }
             
-            let (mut changed_candidate_1, changed_columns_1, candidate_deltas_1) = self.mover.do_move(&mut candidate_1, variables_manager, false);
-            let (mut changed_candidate_2, changed_columns_2, candidate_deltas_2) = self.mover.do_move(&mut candidate_2, variables_manager, false);
+            let (mut changed_candidate_1, changed_columns_1, candidate_deltas_1) = self.mover.do_move(&mut candidate_1, variables_manager, false)?;
+            let (mut changed_candidate_2, changed_columns_2, candidate_deltas_2) = self.mover.do_move(&mut candidate_2, variables_manager, false)?;
 
-            candidate_1 = changed_candidate_1.unwrap();
-            candidate_2 = changed_candidate_2.unwrap();
+            candidate_1 = Mover::take_moved(changed_candidate_1)?;
+            candidate_2 = Mover::take_moved(changed_candidate_2)?;
 
 
             // for crossover with rint() one doesn't need for fixing the whole candidate vector
@@ -183,7 +186,7 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
             candidates.push(candidate_2);
         }
         
-        return candidates;
+        return Ok(candidates);
     }
 
     fn sample_candidates_incremental(
@@ -191,8 +194,8 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         population: &mut Vec<Individual<ScoreType>>, 
         current_top_individual: &Individual<ScoreType>,
         variables_manager: &VariablesManager
-    ) -> (Vec<f64>, Vec<Vec<(usize, f64)>>) {
-        panic!("Incremental candidates sampling is available only for local search approaches (TabuSearch, LateAcceptance, etc).")
+    ) -> Result<(Vec<f64>, Vec<Vec<(usize, f64)>>), GreyjackError> {
+        return Err(GreyjackError::InvalidConfig("Incremental candidates sampling is available only for local search approaches (TabuSearch, LateAcceptance, etc). Use plain score calculator.".to_string()));
     }
 
     fn build_updated_population(
@@ -223,6 +226,12 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         panic!("Incremental candidates sampling is available only for local search approaches (TabuSearch, LateAcceptance, etc).")
     }
 
+    fn set_random_seed(&mut self, random_seed: u64) {
+        self.random_generator = StdRng::seed_from_u64(random_seed);
+        let mover_seed: u64 = self.random_generator.gen();
+        self.mover.set_random_seed(mover_seed);
+    }
+
     fn get_metaheuristic_kind(&self) -> MetaheuristicKind {
         self.metaheuristic_kind.clone()
     }
@@ -18,6 +18,7 @@ use rand_distr::{Distribution, Uniform};
 use super::Mover;
 use super::metaheuristic_kinds_and_names::{MetaheuristicKind, MetaheuristicNames};
 use crate::utils::math_utils;
+use crate::errors::GreyjackError;
 use std::collections::VecDeque;
 use std::collections::HashSet;
 use std::cmp::max;
@@ -52,7 +53,7 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq +  PartialOrd + Ord
         move_probas: Option<Vec<f64>>,
         semantic_groups_dict: HashMap<String, Vec<usize>>,
         discrete_ids: Option<Vec<usize>>,
-    ) -> Self {
+    ) -> Result<Self, GreyjackError> {
 
         let current_mutation_rate_multiplier;
         match mutation_rate_multiplier {
@@ -66,7 +67,7 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq +  PartialOrd + Ord
             group_mutation_rates_map.insert(group_name.clone(), current_group_mutation_rate);
         }
 
-        Self {
+        Ok(Self {
             late_acceptance_size: late_acceptance_size,
             tabu_entity_rate: tabu_entity_rate,
             //late_scores: Vec::new(),
@@ -78,8 +79,8 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq +  PartialOrd + Ord
 
             group_mutation_rates_map: group_mutation_rates_map.clone(),
             discrete_ids: discrete_ids.clone(),
-            mover: Mover::new(tabu_entity_rate, HashMap::new(), HashMap::new(), HashMap::new(), group_mutation_rates_map.clone(), move_probas),
-        }
+            mover: Mover::new(tabu_entity_rate, HashMap::new(), HashMap::new(), HashMap::new(), group_mutation_rates_map.clone(), move_probas)?,
+        })
     }
 
 }
@@ -92,7 +93,7 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
             population: &mut Vec<Individual<ScoreType>>, 
             current_top_individual: &Individual<ScoreType>,
             variables_manager: &VariablesManager
-        ) -> Vec<Vec<f64>> {
+        ) -> Result<Vec<Vec<f64>>, GreyjackError> {
 
         if self.mover.tabu_entity_size_map.len() == 0 {
             let semantic_groups_map = variables_manager.semantic_groups_map.clone();
@@ -104,12 +105,12 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         }
 
         let mut candidate = population[0].variable_values.clone();
-        let (changed_candidate, changed_columns, candidate_deltas) = self.mover.do_move(&mut candidate, variables_manager, false);
-        candidate = changed_candidate.unwrap();
+        let (changed_candidate, changed_columns, candidate_deltas) = self.mover.do_move(&mut candidate, variables_manager, false)?;
+        candidate = Mover::take_moved(changed_candidate)?;
         variables_manager.fix_variables(&mut candidate, changed_columns);
         let candidate = vec![candidate; 1];
 
-        return candidate;
+        return Ok(candidate);
 
     }
 
@@ -118,7 +119,7 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         population: &mut Vec<Individual<ScoreType>>, 
         current_top_individual: &Individual<ScoreType>,
         variables_manager: &VariablesManager
-    ) -> (Vec<f64>, Vec<Vec<(usize, f64)>>) {
+    ) -> Result<(Vec<f64>, Vec<Vec<(usize, f64)>>), GreyjackError> {
 
         if self.mover.tabu_entity_size_map.len() == 0 {
             let semantic_groups_map = variables_manager.semantic_groups_map.clone();
@@ -130,14 +131,14 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         }
 
         let mut candidate = population[0].variable_values.clone();
-        let (_, changed_columns, candidate_deltas) = self.mover.do_move(&mut candidate, variables_manager, true);
-        let mut candidate_deltas = candidate_deltas.unwrap();
+        let (_, changed_columns, candidate_deltas) = self.mover.do_move(&mut candidate, variables_manager, true)?;
+        let mut candidate_deltas = Mover::take_moved(candidate_deltas)?;
         variables_manager.fix_deltas(&mut candidate_deltas, changed_columns.clone());
-        let changed_columns = changed_columns.unwrap();
+        let changed_columns = Mover::take_moved(changed_columns)?;
         let candidate_deltas: Vec<(usize, f64)> = changed_columns.iter().zip(candidate_deltas.iter()).map(|(col_id, delta_value)| (*col_id, *delta_value)).collect();
         let deltas = vec![candidate_deltas; 1];
 
-        return (candidate, deltas);
+        return Ok((candidate, deltas));
     }
 
     fn build_updated_population(
@@ -240,6 +241,10 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         return new_population;
     }
 
+    fn set_random_seed(&mut self, random_seed: u64) {
+        self.mover.set_random_seed(random_seed);
+    }
+
     fn get_metaheuristic_kind(&self) -> MetaheuristicKind {
         self.metaheuristic_kind.clone()
     }
@@ -15,6 +15,7 @@ use rand_distr::{Distribution, Normal};
 
 use super::Mover;
 use super::metaheuristic_kinds_and_names::{MetaheuristicKind, MetaheuristicNames};
+use crate::errors::GreyjackError;
 use std::collections::VecDeque;
 use std::collections::HashSet;
 use std::cmp::max;
@@ -90,7 +91,7 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         move_probas: Option<Vec<f64>>,
         semantic_groups_dict: HashMap<String, Vec<usize>>,
         discrete_ids: Option<Vec<usize>>,
-    ) -> Self {
+    ) -> Result<Self, GreyjackError> {
 
         let current_mutation_rate_multiplier;
         match mutation_rate_multiplier {
@@ -104,7 +105,7 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
             group_mutation_rates_map.insert(group_name.clone(), current_group_mutation_rate);
         }
 
-        Self {
+        Ok(Self {
             population_size: population_size,
             history_archive_size: history_archive_size,
             initial_f: initial_f,
@@ -138,9 +139,9 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
 
             group_mutation_rates_map: group_mutation_rates_map.clone(),
             discrete_ids: discrete_ids.clone(),
-            mover: Mover::new(tabu_entity_rate, HashMap::new(), HashMap::new(), HashMap::new(), group_mutation_rates_map.clone(), move_probas),
+            mover: Mover::new(tabu_entity_rate, HashMap::new(), HashMap::new(), HashMap::new(), group_mutation_rates_map.clone(), move_probas)?,
             random_generator: StdRng::from_entropy(),
-        }
+        })
     }
 
     fn adapt_parameters(&mut self, population: &mut Vec<Individual<ScoreType>>) {
@@ -210,7 +211,7 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
             population: &mut Vec<Individual<ScoreType>>, 
             current_top_individual: &Individual<ScoreType>,
             variables_manager: &VariablesManager
-        ) -> Vec<Vec<f64>> {
+        ) -> Result<Vec<Vec<f64>>, GreyjackError> {
 
         if self.mover.tabu_entity_size_map.len() == 0 {
             let semantic_groups_map = variables_manager.semantic_groups_map.clone();
@@ -297,9 +298,9 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
                 // my modification to prevent population degeneration and adapt LSHADE to mixed variable types cases
                 // take the whole crossover_vec and make mutation (move)
                 // p_best crossover changes all columns
-                let (candidate, _, _) = self.mover.do_move(&mut crossover_vector, &variables_manager, false);
+                let (candidate, _, _) = self.mover.do_move(&mut crossover_vector, &variables_manager, false)?;
                 let changed_columns: Vec<usize> = (0..crossover_vector.len()).collect();
-                (candidate.unwrap(), Some(changed_columns))
+                (Mover::take_moved(candidate)?, Some(changed_columns))
             } else {
                 let crossover_mask: Vec<bool> = (0..variables_manager.variables_count)
                     .map(|_| self.random_generator.gen::<f64>() < current_cr)
@@ -336,7 +337,7 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
             candidates.push(candidate_vector);
         }
         
-        return candidates;
+        return Ok(candidates);
     }
 
     fn sample_candidates_incremental(
@@ -344,8 +345,8 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         population: &mut Vec<Individual<ScoreType>>, 
         current_top_individual: &Individual<ScoreType>,
         variables_manager: &VariablesManager
-    ) -> (Vec<f64>, Vec<Vec<(usize, f64)>>) {
-        panic!("Incremental candidates sampling is available only for local search approaches (TabuSearch, LateAcceptance, etc).")
+    ) -> Result<(Vec<f64>, Vec<Vec<(usize, f64)>>), GreyjackError> {
+        return Err(GreyjackError::InvalidConfig("Incremental candidates sampling is available only for local search approaches (TabuSearch, LateAcceptance, etc). Use plain score calculator.".to_string()));
     }
 
     fn build_updated_population(
@@ -419,6 +420,12 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         panic!("Incremental candidates sampling is available only for local search approaches (TabuSearch, LateAcceptance, etc).")
     }
 
+    fn set_random_seed(&mut self, random_seed: u64) {
+        self.random_generator = StdRng::seed_from_u64(random_seed);
+        let mover_seed: u64 = self.random_generator.gen();
+        self.mover.set_random_seed(mover_seed);
+    }
+
     fn get_metaheuristic_kind(&self) -> MetaheuristicKind {
         self.metaheuristic_kind.clone()
     }
@@ -5,6 +5,7 @@ use crate::score_calculation::score_requesters::VariablesManager;
 use std::ops::{AddAssign, Sub};
 use std::fmt::Debug;
 use super::metaheuristic_kinds_and_names::{MetaheuristicKind, MetaheuristicNames};
+use crate::errors::GreyjackError;
 
 pub trait MetaheuristicBaseTrait<ScoreType>
 where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {
@@ -14,14 +15,14 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         population: &mut Vec<Individual<ScoreType>>, 
         current_top_individual: &Individual<ScoreType>,
         variables_manager: &VariablesManager
-    ) -> Vec<Vec<f64>>;
+    ) -> Result<Vec<Vec<f64>>, GreyjackError>;
 
     fn sample_candidates_incremental(
         &mut self,
         population: &mut Vec<Individual<ScoreType>>, 
         current_top_individual: &Individual<ScoreType>,
         variables_manager: &VariablesManager
-    ) -> (Vec<f64>, Vec<Vec<(usize, f64)>>);
+    ) -> Result<(Vec<f64>, Vec<Vec<(usize, f64)>>), GreyjackError>;
 
     fn build_updated_population(
         &mut self, 
@@ -37,6 +38,8 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         scores: Vec<ScoreType>,
     ) -> Vec<Individual<ScoreType>>;
 
+    fn set_random_seed(&mut self, random_seed: u64);
+
     fn get_metaheuristic_kind(&self) -> MetaheuristicKind;
 
     fn get_metaheuristic_name(&self) -> MetaheuristicNames;
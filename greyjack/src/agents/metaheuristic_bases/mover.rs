@@ -8,6 +8,7 @@ use rand::{seq::SliceRandom, SeedableRng};
 use rand::rngs::StdRng;
 use rand_distr::{Distribution, Uniform};
 use crate::utils::math_utils;
+use crate::errors::GreyjackError;
 
 pub struct Mover {
 
@@ -18,6 +19,7 @@ pub struct Mover {
     pub group_mutation_rates_map: HashMap<String, f64>,
     pub moves_count: u64,
     pub move_probas_tresholds: Vec<f64>,
+    pub random_generator: StdRng,
 
 }
 
@@ -31,7 +33,7 @@ impl Mover {
         group_mutation_rates_map: HashMap<String, f64>,
         move_probas: Option<Vec<f64>>,
         
-    ) -> Self {
+    ) -> Result<Self, GreyjackError> {
 
         let moves_count = 6;
         let move_probas_vec: Vec<f64>;
@@ -48,8 +50,12 @@ impl Mover {
                 move_probas_vec = proba_tresholds;
             },
             Some(probas) => {
-                assert_eq!(probas.len(), moves_count, "Optional move probas vector length is not equal to available moves count");
-                assert_eq!(utils::math_utils::round(probas.iter().sum(), 1), 1.0, "Optional move probas sum must be equal to 1.0");
+                if probas.len() != moves_count {
+                    return Err(GreyjackError::InvalidConfig(format!("Optional move probas vector length ({}) is not equal to available moves count ({})", probas.len(), moves_count)));
+                }
+                if utils::math_utils::round(probas.iter().sum(), 1) != 1.0 {
+                    return Err(GreyjackError::InvalidConfig("Optional move probas sum must be equal to 1.0".to_string()));
+                }
 
                 let mut proba_tresholds = vec![0.0; moves_count];
                 let mut accumulator: f64 = 0.0;
@@ -61,7 +67,7 @@ impl Mover {
             }
         }
 
-        Self {
+        Ok(Self {
             tabu_entity_rate: tabu_entity_rate,
             tabu_entity_size_map: tabu_entity_size_map,
             tabu_ids_sets_map: tabu_ids_sets_map,
@@ -69,14 +75,19 @@ impl Mover {
             group_mutation_rates_map: group_mutation_rates_map,
             moves_count: moves_count as u64,
             move_probas_tresholds: move_probas_vec,
-        }
+            random_generator: StdRng::from_entropy(),
+        })
+    }
+
+    pub fn set_random_seed(&mut self, random_seed: u64) {
+        self.random_generator = StdRng::seed_from_u64(random_seed);
     }
 
     pub fn select_non_tabu_ids(&mut self, group_name: &String, selection_size: usize, right_end: usize) -> Vec<usize> {
 
         let mut random_ids: Vec<usize> = Vec::new();
         while random_ids.len() != selection_size {
-            let random_id = math_utils::get_random_id(0, right_end);
+            let random_id = math_utils::get_random_id(0, right_end, &mut self.random_generator);
 
             if self.tabu_ids_sets_map[group_name].contains(&random_id) == false {
                 self.tabu_ids_sets_map.get_mut(group_name).unwrap().insert(random_id);
@@ -95,47 +106,62 @@ impl Mover {
         return random_ids;
     }
 
-    pub fn do_move(&mut self, candidate: &Vec<f64>, variables_manager: &VariablesManager, incremental: bool) -> (Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>) {
+    // moves return None when they can't be applied to the chosen semantic group
+    pub fn take_moved<T>(moved: Option<T>) -> Result<T, GreyjackError> {
+        match moved {
+            Some(x) => return Ok(x),
+            None => return Err(GreyjackError::MoveFailed("semantic group is too small for the chosen move. Check move_probas and semantic groups of planning variables".to_string())),
+        }
+    }
+
+    pub fn do_move(&mut self, candidate: &Vec<f64>, variables_manager: &VariablesManager, incremental: bool) -> Result<(Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>), GreyjackError> {
 
         let changed_candidate: Option<Vec<f64>>;
         let changed_columns: Option<Vec<usize>>;
         let deltas: Option<Vec<f64>>;
 
-        let random_value = Uniform::new_inclusive(0.0, 1.0).sample(&mut StdRng::from_entropy());
+        // list groups compete with semantic groups for a move, move_probas are used only for semantic groups
+        if variables_manager.n_list_groups != 0 {
+            let list_move_proba = variables_manager.n_list_groups as f64 / (variables_manager.n_list_groups + variables_manager.n_semantic_groups) as f64;
+            if Uniform::new_inclusive(0.0, 1.0).sample(&mut self.random_generator) <= list_move_proba {
+                return self.list_move(candidate, variables_manager, incremental);
+            }
+        }
+
+        let random_value = Uniform::new_inclusive(0.0, 1.0).sample(&mut self.random_generator);
         if random_value <= self.move_probas_tresholds[0] {
-            (changed_candidate, changed_columns, deltas) = self.change_move(candidate, variables_manager, incremental);
+            (changed_candidate, changed_columns, deltas) = self.change_move(candidate, variables_manager, incremental)?;
 
         } else if random_value <= self.move_probas_tresholds[1] {
-            (changed_candidate, changed_columns, deltas) = self.swap_move(candidate, variables_manager, incremental)
+            (changed_candidate, changed_columns, deltas) = self.swap_move(candidate, variables_manager, incremental)?
 
         } else if random_value <= self.move_probas_tresholds[2] {
-            (changed_candidate, changed_columns, deltas) = self.swap_edges_move(candidate, variables_manager, incremental)
+            (changed_candidate, changed_columns, deltas) = self.swap_edges_move(candidate, variables_manager, incremental)?
 
         } else if random_value <= self.move_probas_tresholds[3] {
-            (changed_candidate, changed_columns, deltas) = self.scramble_move(candidate, variables_manager, incremental)
+            (changed_candidate, changed_columns, deltas) = self.scramble_move(candidate, variables_manager, incremental)?
 
         } else if random_value <= self.move_probas_tresholds[4] {
-            (changed_candidate, changed_columns, deltas) = self.insertion_move(candidate, variables_manager, incremental)
+            (changed_candidate, changed_columns, deltas) = self.insertion_move(candidate, variables_manager, incremental)?
 
         } else if random_value <= self.move_probas_tresholds[5] {
-            (changed_candidate, changed_columns, deltas) = self.inverse_move(candidate, variables_manager, incremental)
+            (changed_candidate, changed_columns, deltas) = self.inverse_move(candidate, variables_manager, incremental)?
 
         } else {
-            panic!("Something wrong with probabilities");
+            return Err(GreyjackError::MoveFailed("Something wrong with move probabilities".to_string()));
         }
 
-        return (changed_candidate, changed_columns, deltas);
+        return Ok((changed_candidate, changed_columns, deltas));
     }
 
     fn get_necessary_info_for_move<'d>(
-        &self, 
+        &mut self, 
         variables_manager: &'d VariablesManager
     ) -> (&'d Vec<usize>, &'d String, usize) {
     
-        let (group_ids, group_name) = variables_manager.get_random_semantic_group_ids();
+        let (group_ids, group_name) = variables_manager.get_random_semantic_group_ids(&mut self.random_generator);
         let group_mutation_rate = self.group_mutation_rates_map[group_name];
-        let mut random_generator = StdRng::from_entropy();
-        let random_values: Vec<f64> = (0..variables_manager.variables_count).into_iter().map(|x| Uniform::new_inclusive(0.0, 1.0).sample(&mut random_generator)).collect();
+        let random_values: Vec<f64> = (0..variables_manager.variables_count).into_iter().map(|x| Uniform::new_inclusive(0.0, 1.0).sample(&mut self.random_generator)).collect();
         let crossover_mask: Vec<bool> = random_values.iter().map(|x| x < &group_mutation_rate).collect();
         let current_change_count = crossover_mask.iter().filter(|x| **x == true).count();
 
@@ -147,7 +173,7 @@ impl Mover {
         candidate: &Vec<f64>, 
         variables_manager: &VariablesManager,
         incremental: bool,
-    ) -> (Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>) {
+    ) -> Result<(Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>), GreyjackError> {
         
         let (group_ids, group_name, mut current_change_count) = self.get_necessary_info_for_move(variables_manager);
 
@@ -155,24 +181,24 @@ impl Mover {
             current_change_count = 1;
         }
         if group_ids.len() < current_change_count {
-            return (None, None, None);
+            return Ok((None, None, None));
         }
 
         let mut changed_columns: Vec<usize>;
         if self.tabu_entity_rate == 0.0 {
-            changed_columns = math_utils::choice(&(0..group_ids.len()).collect::<Vec<usize>>(), current_change_count, false);
+            changed_columns = math_utils::choice(&(0..group_ids.len()).collect::<Vec<usize>>(), current_change_count, false, &mut self.random_generator)?;
         } else {
             changed_columns = self.select_non_tabu_ids(group_name, current_change_count, group_ids.len());
         }
         changed_columns = changed_columns.iter().map(|i| group_ids[*i]).collect();
 
         if incremental {
-            let deltas: Vec<f64> = changed_columns.iter().map(|i| variables_manager.get_column_random_value(*i)).collect();
-            return (None, Some(changed_columns), Some(deltas));
+            let deltas: Vec<f64> = changed_columns.iter().map(|i| variables_manager.get_column_changed_value(*i, candidate[*i], &mut self.random_generator)).collect();
+            return Ok((None, Some(changed_columns), Some(deltas)));
         } else {
             let mut changed_candidate = candidate.clone();
-            changed_columns.iter().for_each(|i| changed_candidate[*i] = variables_manager.get_column_random_value(*i));
-            return (Some(changed_candidate), Some(changed_columns), None);
+            changed_columns.iter().for_each(|i| changed_candidate[*i] = variables_manager.get_column_changed_value(*i, candidate[*i], &mut self.random_generator));
+            return Ok((Some(changed_candidate), Some(changed_columns), None));
         }
     }
 
@@ -181,7 +207,7 @@ impl Mover {
         &Vec<f64>, 
         variables_manager: &VariablesManager, 
         incremental: bool,
-    ) -> (Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>) {
+    ) -> Result<(Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>), GreyjackError> {
 
         let (group_ids, group_name, mut current_change_count) = self.get_necessary_info_for_move(variables_manager);
 
@@ -189,12 +215,12 @@ impl Mover {
             current_change_count = 2;
         }
         if group_ids.len() < current_change_count {
-            return (None, None, None);
+            return Ok((None, None, None));
         }
 
         let mut changed_columns: Vec<usize>;
         if self.tabu_entity_rate == 0.0 {
-            changed_columns = math_utils::choice(&(0..group_ids.len()).collect::<Vec<usize>>(), current_change_count, false);
+            changed_columns = math_utils::choice(&(0..group_ids.len()).collect::<Vec<usize>>(), current_change_count, false, &mut self.random_generator)?;
         } else {
             changed_columns = self.select_non_tabu_ids(group_name, current_change_count, group_ids.len());
         }
@@ -205,13 +231,13 @@ impl Mover {
             (0..current_change_count).into_iter().for_each(|i| deltas.push(candidate[changed_columns[i]]));
             (1..current_change_count).into_iter().for_each(|i| deltas.swap(i-1, i));
 
-            return (None, Some(changed_columns), Some(deltas));
+            return Ok((None, Some(changed_columns), Some(deltas)));
         } else {
             let mut changed_candidate = candidate.clone();
             for i in 1..current_change_count {
                 changed_candidate.swap(changed_columns[i-1], changed_columns[i]);
             }
-            return (Some(changed_candidate), Some(changed_columns), None);
+            return Ok((Some(changed_candidate), Some(changed_columns), None));
         }
     }
 
@@ -220,12 +246,12 @@ impl Mover {
         candidate: &Vec<f64>, 
         variables_manager: &VariablesManager, 
         incremental: bool,
-    ) -> (Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>) {
+    ) -> Result<(Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>), GreyjackError> {
 
         let (group_ids, group_name, mut current_change_count) = self.get_necessary_info_for_move(variables_manager);
 
         if group_ids.len() == 0 {
-            return (None, None, None);
+            return Ok((None, None, None));
         }
         if current_change_count < 2 {
             current_change_count = 2;
@@ -236,7 +262,7 @@ impl Mover {
 
         let columns_to_change: Vec<usize>;
         if self.tabu_entity_rate == 0.0 {
-            columns_to_change = math_utils::choice(&(0..(group_ids.len()-1)).collect(), current_change_count, false);
+            columns_to_change = math_utils::choice(&(0..(group_ids.len()-1)).collect(), current_change_count, false, &mut self.random_generator)?;
         } else {
             columns_to_change = self.select_non_tabu_ids(group_name, current_change_count, group_ids.len()-1);
         }
@@ -264,7 +290,7 @@ impl Mover {
                 deltas.swap(2*(i-1) + 1, 2*i + 1);
             });
 
-            return (None, Some(changed_columns), Some(deltas));
+            return Ok((None, Some(changed_columns), Some(deltas)));
         } else {
             let mut changed_candidate = candidate.clone();
             for i in 1..current_change_count {
@@ -273,7 +299,7 @@ impl Mover {
                 changed_candidate.swap(left_edge.0, right_edge.0);
                 changed_candidate.swap(left_edge.1, right_edge.1);
             }
-            return (Some(changed_candidate), Some(changed_columns), None);
+            return Ok((Some(changed_candidate), Some(changed_columns), None));
         }
     }
 
@@ -282,36 +308,36 @@ impl Mover {
         candidate: &Vec<f64>, 
         variables_manager: &VariablesManager, 
         incremental: bool,
-    ) -> (Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>) {
+    ) -> Result<(Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>), GreyjackError> {
 
-        let current_change_count = Uniform::new_inclusive(3, 6).sample(&mut StdRng::from_entropy());
-        let (group_ids, group_name) = variables_manager.get_random_semantic_group_ids();
+        let current_change_count = Uniform::new_inclusive(3, 6).sample(&mut self.random_generator);
+        let (group_ids, group_name) = variables_manager.get_random_semantic_group_ids(&mut self.random_generator);
 
         if group_ids.len() < current_change_count - 1 {
-            return (None, None, None);
+            return Ok((None, None, None));
         }
 
         let current_start_id: usize;
         if self.tabu_entity_rate == 0.0 {
-            current_start_id = math_utils::get_random_id(0, group_ids.len() - current_change_count);
+            current_start_id = math_utils::get_random_id(0, group_ids.len() - current_change_count, &mut self.random_generator);
         } else {
             current_start_id = self.select_non_tabu_ids(group_name, 1, group_ids.len() - current_change_count)[0];
         }
 
         let native_columns: Vec<usize> = (0..current_change_count).into_iter().map(|i| group_ids[current_start_id + i]).collect();
         let mut scrambled_columns = native_columns.clone();
-        scrambled_columns.shuffle(&mut StdRng::from_entropy());
+        scrambled_columns.shuffle(&mut self.random_generator);
 
 
         if incremental {
             let mut deltas: Vec<f64> = Vec::new();
             scrambled_columns.iter().for_each(|i| deltas.push(candidate[*i]));
-            return (None, Some(scrambled_columns), Some(deltas));
+            return Ok((None, Some(scrambled_columns), Some(deltas)));
         } else {
             let changed_columns = native_columns.clone();
             let mut changed_candidate = candidate.clone();
             native_columns.iter().zip(scrambled_columns.iter()).for_each(|(oi, si)| changed_candidate.swap(*oi, *si));
-            return (Some(changed_candidate), Some(changed_columns), None);
+            return Ok((Some(changed_candidate), Some(changed_columns), None));
         }
     }
 
@@ -320,18 +346,18 @@ impl Mover {
         candidate: &Vec<f64>, 
         variables_manager: &VariablesManager, 
         incremental: bool,
-    ) -> (Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>) {
+    ) -> Result<(Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>), GreyjackError> {
 
-        let (group_ids, group_name) = variables_manager.get_random_semantic_group_ids();
+        let (group_ids, group_name) = variables_manager.get_random_semantic_group_ids(&mut self.random_generator);
         let current_change_count = 2;
 
         if group_ids.len() <= 1 {
-            return (None, None, None);
+            return Ok((None, None, None));
         }
 
         let columns_to_change: Vec<usize>;
         if self.tabu_entity_rate == 0.0 {
-            columns_to_change = math_utils::choice(&(0..group_ids.len()).collect::<Vec<usize>>(), current_change_count, false);
+            columns_to_change = math_utils::choice(&(0..group_ids.len()).collect::<Vec<usize>>(), current_change_count, false, &mut self.random_generator)?;
         } else {
             columns_to_change = self.select_non_tabu_ids(group_name, current_change_count, group_ids.len());
         }
@@ -354,7 +380,7 @@ impl Mover {
             left_rotate = false;
 
         } else {
-            return (None, None, None);
+            return Ok((None, None, None));
         }
 
         let changed_columns = old_ids.clone();
@@ -366,11 +392,11 @@ impl Mover {
             } else {
                 deltas.rotate_right(1);
             }
-            return (None, Some(changed_columns), Some(deltas));
+            return Ok((None, Some(changed_columns), Some(deltas)));
         } else {
             let mut changed_candidate = candidate.clone();
             old_ids.iter().zip(shifted_ids.iter()).for_each(|(oi, si)| changed_candidate.swap(*oi, *si));
-            return (Some(changed_candidate), Some(changed_columns), None);
+            return Ok((Some(changed_candidate), Some(changed_columns), None));
         }
     }
 
@@ -379,18 +405,18 @@ impl Mover {
         candidate: &Vec<f64>, 
         variables_manager: &VariablesManager, 
         incremental: bool,
-    ) -> (Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>) {
+    ) -> Result<(Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>), GreyjackError> {
 
-        let (group_ids, group_name) = variables_manager.get_random_semantic_group_ids();
+        let (group_ids, group_name) = variables_manager.get_random_semantic_group_ids(&mut self.random_generator);
         let current_change_count = 2;
 
         if group_ids.len() <= 1 {
-            return (None, None, None);
+            return Ok((None, None, None));
         }
 
         let columns_to_change: Vec<usize>;
         if self.tabu_entity_rate == 0.0 {
-            columns_to_change = math_utils::choice(&(0..group_ids.len()).collect::<Vec<usize>>(), current_change_count, false);
+            columns_to_change = math_utils::choice(&(0..group_ids.len()).collect::<Vec<usize>>(), current_change_count, false, &mut self.random_generator)?;
         } else {
             columns_to_change = self.select_non_tabu_ids(group_name, current_change_count, group_ids.len());
         }
@@ -411,12 +437,278 @@ impl Mover {
         let changed_columns = old_ids.clone();
         if incremental {
             let deltas: Vec<f64> = reversed_ids.iter().map(|rev_id| candidate[*rev_id]).collect();
-            return (None, Some(changed_columns), Some(deltas));
+            return Ok((None, Some(changed_columns), Some(deltas)));
         } else {
             let mut changed_candidate = candidate.clone();
             let changed_values: Vec<f64> = reversed_ids.iter().map(|i| candidate[*i]).collect();
             old_ids.iter().zip(changed_values.iter()).for_each(|(oi, new_value)| changed_candidate[*oi] = *new_value);
+            return Ok((Some(changed_candidate), Some(changed_columns), None));
+        }
+    }
+
+    /*
+        Moves of list variables (GJList). They take a random list group and permute its elements
+        between slots of its lists, so each element stays planned exactly once.
+        If the chosen move can't be applied (for example, relocate for a group with one list),
+        the next list move is tried. Tabu lists aren't used by list moves.
+    */
+    pub fn list_move(
+        &mut self, 
+        candidate: &Vec<f64>, 
+        variables_manager: &VariablesManager, 
+        incremental: bool,
+    ) -> Result<(Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>), GreyjackError> {
+
+        let (group_ids, _) = variables_manager.get_random_list_group_ids(&mut self.random_generator);
+        let lists: Vec<(usize, Vec<usize>)> = group_ids.iter()
+            .filter(|list_id| variables_manager.get_list(**list_id).frozen == false)
+            .map(|list_id| (*list_id, variables_manager.get_list_element_ids(candidate, *list_id)))
+            .collect();
+
+        let list_moves_count = 4;
+        let first_move_id = math_utils::get_random_id(0, list_moves_count, &mut self.random_generator);
+        for i in 0..list_moves_count {
+            let changed_lists: Option<Vec<(usize, Vec<usize>)>>;
+            match (first_move_id + i) % list_moves_count {
+                0 => changed_lists = self.two_opt_list_move(&lists),
+                1 => changed_lists = self.or_opt_list_move(&lists),
+                2 => changed_lists = self.relocate_list_move(&lists, variables_manager),
+                _ => changed_lists = self.swap_list_move(&lists),
+            }
+
+            if let Some(changed_lists) = changed_lists {
+                return Ok(Self::build_list_move_result(candidate, variables_manager, changed_lists, incremental));
+            }
+        }
+
+        return Ok((None, None, None));
+    }
+
+    fn build_list_move_result(
+        candidate: &Vec<f64>, 
+        variables_manager: &VariablesManager, 
+        changed_lists: Vec<(usize, Vec<usize>)>,
+        incremental: bool,
+    ) -> (Option<Vec<f64>>, Option<Vec<usize>>, Option<Vec<f64>>) {
+
+        let mut changed_columns: Vec<usize> = Vec::new();
+        let mut new_values: Vec<f64> = Vec::new();
+        for (list_id, element_ids) in changed_lists {
+            let column_start = variables_manager.variable_column_ranges[list_id].0;
+            let slot_values = variables_manager.get_list(list_id).to_slot_values(&element_ids);
+            slot_values.iter().enumerate().for_each(|(i, slot_value)| {
+                if candidate[column_start + i] != *slot_value {
+                    changed_columns.push(column_start + i);
+                    new_values.push(*slot_value);
+                }
+            });
+        }
+
+        if incremental {
+            return (None, Some(changed_columns), Some(new_values));
+        } else {
+            let mut changed_candidate = candidate.clone();
+            changed_columns.iter().zip(new_values.iter()).for_each(|(i, new_value)| changed_candidate[*i] = *new_value);
             return (Some(changed_candidate), Some(changed_columns), None);
         }
     }
-}
\ No newline at end of file
+
+    fn choose_list_position(&mut self, lists: &Vec<(usize, Vec<usize>)>, filter: impl Fn(&(usize, Vec<usize>)) -> bool) -> Option<usize> {
+        let suitable_positions: Vec<usize> = (0..lists.len()).filter(|i| filter(&lists[*i])).collect();
+        if suitable_positions.len() == 0 {
+            return None;
+        }
+        return Some(suitable_positions[math_utils::get_random_id(0, suitable_positions.len(), &mut self.random_generator)]);
+    }
+
+    // reverses a segment of a list
+    pub fn two_opt_list_move(&mut self, lists: &Vec<(usize, Vec<usize>)>) -> Option<Vec<(usize, Vec<usize>)>> {
+
+        let list_position = self.choose_list_position(lists, |(_, element_ids)| element_ids.len() >= 2)?;
+        let (list_id, element_ids) = &lists[list_position];
+
+        let mut segment_ends = math_utils::choice(&(0..element_ids.len()).collect::<Vec<usize>>(), 2, false, &mut self.random_generator).ok()?;
+        segment_ends.sort();
+        let mut changed_element_ids = element_ids.clone();
+        changed_element_ids[segment_ends[0]..=segment_ends[1]].reverse();
+
+        return Some(vec![(*list_id, changed_element_ids)]);
+    }
+
+    // moves a chain of 1-3 consecutive elements to another place of the same list
+    pub fn or_opt_list_move(&mut self, lists: &Vec<(usize, Vec<usize>)>) -> Option<Vec<(usize, Vec<usize>)>> {
+
+        let list_position = self.choose_list_position(lists, |(_, element_ids)| element_ids.len() >= 2)?;
+        let (list_id, element_ids) = &lists[list_position];
+
+        let max_chain_length = std::cmp::min(3, element_ids.len() - 1);
+        let chain_length = Uniform::new_inclusive(1, max_chain_length).sample(&mut self.random_generator);
+        let chain_start = math_utils::get_random_id(0, element_ids.len() - chain_length + 1, &mut self.random_generator);
+        let mut changed_element_ids = element_ids.clone();
+        let chain: Vec<usize> = changed_element_ids.drain(chain_start..(chain_start + chain_length)).collect();
+
+        let mut insert_position = math_utils::get_random_id(0, changed_element_ids.len(), &mut self.random_generator);
+        if insert_position >= chain_start {
+            insert_position += 1;
+        }
+        changed_element_ids.splice(insert_position..insert_position, chain);
+
+        return Some(vec![(*list_id, changed_element_ids)]);
+    }
+
+    // moves an element from one list to a random place of another list
+    pub fn relocate_list_move(&mut self, lists: &Vec<(usize, Vec<usize>)>, variables_manager: &VariablesManager) -> Option<Vec<(usize, Vec<usize>)>> {
+
+        let source_position = self.choose_list_position(lists, |(_, element_ids)| element_ids.len() >= 1)?;
+        let source_list_id = lists[source_position].0;
+        let target_position = self.choose_list_position(lists, |(list_id, element_ids)| {
+            *list_id != source_list_id && element_ids.len() < variables_manager.get_list(*list_id).max_length
+        })?;
+
+        let mut source_element_ids = lists[source_position].1.clone();
+        let mut target_element_ids = lists[target_position].1.clone();
+        let element_id = source_element_ids.remove(math_utils::get_random_id(0, source_element_ids.len(), &mut self.random_generator));
+        let insert_position = math_utils::get_random_id(0, target_element_ids.len() + 1, &mut self.random_generator);
+        target_element_ids.insert(insert_position, element_id);
+
+        return Some(vec![(source_list_id, source_element_ids), (lists[target_position].0, target_element_ids)]);
+    }
+
+    // exchanges two elements of different lists
+    pub fn swap_list_move(&mut self, lists: &Vec<(usize, Vec<usize>)>) -> Option<Vec<(usize, Vec<usize>)>> {
+
+        let first_position = self.choose_list_position(lists, |(_, element_ids)| element_ids.len() >= 1)?;
+        let first_list_id = lists[first_position].0;
+        let second_position = self.choose_list_position(lists, |(list_id, element_ids)| *list_id != first_list_id && element_ids.len() >= 1)?;
+
+        let mut first_element_ids = lists[first_position].1.clone();
+        let mut second_element_ids = lists[second_position].1.clone();
+        let first_id = math_utils::get_random_id(0, first_element_ids.len(), &mut self.random_generator);
+        let second_id = math_utils::get_random_id(0, second_element_ids.len(), &mut self.random_generator);
+        std::mem::swap(&mut first_element_ids[first_id], &mut second_element_ids[second_id]);
+
+        return Some(vec![(first_list_id, first_element_ids), (lists[second_position].0, second_element_ids)]);
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variables::{GJInteger, GJList, PlanningVariablesVariants};
+
+    fn build_seeded_manager_and_mover(random_seed: u64) -> (VariablesManager, Mover) {
+        let variables_vec: Vec<PlanningVariablesVariants> = (0..20).into_iter().map(|i| {
+            let mut variable = GJInteger::new(None, 0, 100, false, None);
+            variable.set_name(format!("x_{}", i));
+            PlanningVariablesVariants::GJI(variable)
+        }).collect();
+        let mut variables_manager = VariablesManager::new(variables_vec).unwrap();
+        variables_manager.set_random_seed(random_seed);
+
+        let mut group_mutation_rates_map = HashMap::new();
+        group_mutation_rates_map.insert("common".to_string(), 0.1);
+        let mut mover = Mover::new(0.0, HashMap::new(), HashMap::new(), HashMap::new(), group_mutation_rates_map, None).unwrap();
+        mover.set_random_seed(random_seed);
+
+        return (variables_manager, mover);
+    }
+
+    #[test]
+    fn test_seeded_moves_are_repeatable() {
+        let (mut variables_manager_1, mut mover_1) = build_seeded_manager_and_mover(42);
+        let (mut variables_manager_2, mut mover_2) = build_seeded_manager_and_mover(42);
+
+        let candidate_1 = variables_manager_1.sample_variables();
+        let candidate_2 = variables_manager_2.sample_variables();
+        assert_eq!(candidate_1, candidate_2);
+
+        for _ in 0..100 {
+            let (changed_candidate_1, changed_columns_1, _) = mover_1.do_move(&candidate_1, &variables_manager_1, false).unwrap();
+            let (changed_candidate_2, changed_columns_2, _) = mover_2.do_move(&candidate_2, &variables_manager_2, false).unwrap();
+            assert_eq!(changed_candidate_1, changed_candidate_2);
+            assert_eq!(changed_columns_1, changed_columns_2);
+        }
+    }
+
+    #[test]
+    fn test_list_moves_keep_permutation() {
+        let mut variables_vec: Vec<PlanningVariablesVariants> = (0..3).into_iter().map(|i| {
+            let mut variable = GJList::new(None, (100..110).collect(), "routes", false, Some(6));
+            variable.set_name(format!("route_{}", i));
+            PlanningVariablesVariants::GJL(variable)
+        }).collect();
+        let mut frozen_list = GJList::new(Some(vec![110, 111]), (100..112).collect(), "other_routes", true, None);
+        frozen_list.set_name("frozen_route".to_string());
+        variables_vec.push(PlanningVariablesVariants::GJL(frozen_list));
+        let mut free_list = GJList::new(None, (100..112).collect(), "other_routes", false, None);
+        free_list.set_name("free_route".to_string());
+        variables_vec.push(PlanningVariablesVariants::GJL(free_list));
+
+        let mut variables_manager = VariablesManager::new(variables_vec).unwrap();
+        variables_manager.set_random_seed(42);
+        let mut mover = Mover::new(0.0, HashMap::new(), HashMap::new(), HashMap::new(), HashMap::new(), None).unwrap();
+        mover.set_random_seed(42);
+        let mut candidate = variables_manager.sample_variables();
+
+        let is_permutation = |candidate: &Vec<f64>| {
+            let mut routes: Vec<usize> = (0..3).into_iter().flat_map(|i| variables_manager.get_list_element_ids(candidate, i)).collect();
+            routes.sort();
+            let mut other_routes: Vec<usize> = (3..5).into_iter().flat_map(|i| variables_manager.get_list_element_ids(candidate, i)).collect();
+            other_routes.sort();
+            routes == (0..10).collect::<Vec<usize>>() && other_routes == (0..12).collect::<Vec<usize>>()
+        };
+
+        assert_eq!(is_permutation(&candidate), true);
+        for _ in 0..200 {
+            let (changed_candidate, _, _) = mover.do_move(&candidate, &variables_manager, false).unwrap();
+            candidate = changed_candidate.unwrap();
+            assert_eq!(is_permutation(&candidate), true);
+            assert_eq!(variables_manager.get_list_element_ids(&candidate, 3), vec![10, 11]);
+        }
+
+        // crossover-like damage is repaired
+        candidate[0] = candidate[6];
+        candidate[1] = 55.0;
+        variables_manager.fix_variables(&mut candidate, Some(vec![0, 1]));
+        assert_eq!(is_permutation(&candidate), true);
+    }
+
+    #[test]
+    fn test_moves_change_scalars_after_list() {
+        let mut route = GJList::new(None, (100..105).collect(), "routes", false, Some(5));
+        route.set_name("route".to_string());
+        let mut variables_vec = vec![PlanningVariablesVariants::GJL(route)];
+        for i in 0..2 {
+            let mut x = GJInteger::new(None, 0, 100, false, None);
+            x.set_name(format!("x_{}", i));
+            variables_vec.push(PlanningVariablesVariants::GJI(x));
+        }
+
+        let mut variables_manager = VariablesManager::new(variables_vec).unwrap();
+        assert_eq!(variables_manager.semantic_groups_map["common"], vec![5, 6]);
+        variables_manager.set_random_seed(42);
+        // change and swap moves only
+        let mut group_mutation_rates_map = HashMap::new();
+        group_mutation_rates_map.insert("common".to_string(), 0.5);
+        let mut mover = Mover::new(0.0, HashMap::new(), HashMap::new(), HashMap::new(), group_mutation_rates_map, Some(vec![0.5, 0.5, 0.0, 0.0, 0.0, 0.0])).unwrap();
+        mover.set_random_seed(42);
+        let candidate = variables_manager.sample_variables();
+
+        let mut is_scalar_changed = false;
+        for _ in 0..200 {
+            let (changed_candidate_option, changed_columns, _) = mover.do_move(&candidate, &variables_manager, false).unwrap();
+            let mut changed_candidate;
+            match changed_candidate_option {
+                None => continue,
+                Some(x) => changed_candidate = x,
+            }
+            variables_manager.fix_variables(&mut changed_candidate, changed_columns);
+            let mut route_element_ids = variables_manager.get_list_element_ids(&changed_candidate, 0);
+            route_element_ids.sort();
+            assert_eq!(route_element_ids, (0..5).collect::<Vec<usize>>());
+            if changed_candidate[5..] != candidate[5..] {
+                is_scalar_changed = true;
+            }
+        }
+        assert_eq!(is_scalar_changed, true);
+    }
+}
@@ -18,6 +18,7 @@ use rand_distr::{Distribution, Uniform};
 use super::Mover;
 use super::metaheuristic_kinds_and_names::{MetaheuristicKind, MetaheuristicNames};
 use crate::utils::math_utils;
+use crate::errors::GreyjackError;
 use std::collections::VecDeque;
 use std::collections::HashSet;
 use std::cmp::max;
@@ -56,7 +57,7 @@ impl SimulatedAnnealingBase  {
         move_probas: Option<Vec<f64>>,
         semantic_groups_dict: HashMap<String, Vec<usize>>,
         discrete_ids: Option<Vec<usize>>,
-    ) -> Self {
+    ) -> Result<Self, GreyjackError> {
 
         let current_mutation_rate_multiplier;
         match mutation_rate_multiplier {
@@ -70,7 +71,7 @@ impl SimulatedAnnealingBase  {
             group_mutation_rates_map.insert(group_name.clone(), current_group_mutation_rate);
         }
 
-        Self {
+        Ok(Self {
             initial_temperature: initial_temperature.clone(),
             cooling_rate: cooling_rate,
             tabu_entity_rate: tabu_entity_rate,
@@ -80,13 +81,13 @@ impl SimulatedAnnealingBase  {
 
             group_mutation_rates_map: group_mutation_rates_map.clone(),
             discrete_ids: discrete_ids.clone(),
-            mover: Mover::new(tabu_entity_rate, HashMap::new(), HashMap::new(), HashMap::new(), group_mutation_rates_map.clone(), move_probas),
+            mover: Mover::new(tabu_entity_rate, HashMap::new(), HashMap::new(), HashMap::new(), group_mutation_rates_map.clone(), move_probas)?,
             current_temperature: initial_temperature,
             inverted_accomplish_rate: 1.0,
             random_sampler: Uniform::new_inclusive(0.0, 1.0),
             random_generator: StdRng::from_entropy(),
             exp: 2.7182818284590452
-        }
+        })
     }
 
 }
@@ -99,7 +100,7 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
             population: &mut Vec<Individual<ScoreType>>, 
             current_top_individual: &Individual<ScoreType>,
             variables_manager: &VariablesManager
-        ) -> Vec<Vec<f64>> {
+        ) -> Result<Vec<Vec<f64>>, GreyjackError> {
 
         if self.mover.tabu_entity_size_map.len() == 0 {
             let semantic_groups_map = variables_manager.semantic_groups_map.clone();
@@ -111,12 +112,12 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         }
 
         let mut candidate = population[0].variable_values.clone();
-        let (changed_candidate, changed_columns, candidate_deltas) = self.mover.do_move(&mut candidate, variables_manager, false);
-        candidate = changed_candidate.unwrap();
+        let (changed_candidate, changed_columns, candidate_deltas) = self.mover.do_move(&mut candidate, variables_manager, false)?;
+        candidate = Mover::take_moved(changed_candidate)?;
         variables_manager.fix_variables(&mut candidate, changed_columns);
         let candidate = vec![candidate; 1];
 
-        return candidate;
+        return Ok(candidate);
 
     }
 
@@ -125,7 +126,7 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         population: &mut Vec<Individual<ScoreType>>, 
         current_top_individual: &Individual<ScoreType>,
         variables_manager: &VariablesManager
-    ) -> (Vec<f64>, Vec<Vec<(usize, f64)>>) {
+    ) -> Result<(Vec<f64>, Vec<Vec<(usize, f64)>>), GreyjackError> {
 
         if self.mover.tabu_entity_size_map.len() == 0 {
             let semantic_groups_map = variables_manager.semantic_groups_map.clone();
@@ -137,14 +138,14 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         }
 
         let mut candidate = population[0].variable_values.clone();
-        let (_, changed_columns, candidate_deltas) = self.mover.do_move(&mut candidate, variables_manager, true);
-        let mut candidate_deltas = candidate_deltas.unwrap();
+        let (_, changed_columns, candidate_deltas) = self.mover.do_move(&mut candidate, variables_manager, true)?;
+        let mut candidate_deltas = Mover::take_moved(candidate_deltas)?;
         variables_manager.fix_deltas(&mut candidate_deltas, changed_columns.clone());
-        let changed_columns = changed_columns.unwrap();
+        let changed_columns = Mover::take_moved(changed_columns)?;
         let candidate_deltas: Vec<(usize, f64)> = changed_columns.iter().zip(candidate_deltas.iter()).map(|(col_id, delta_value)| (*col_id, *delta_value)).collect();
         let deltas = vec![candidate_deltas; 1];
 
-        return (candidate, deltas);
+        return Ok((candidate, deltas));
     }
 
     fn build_updated_population(
@@ -232,6 +233,12 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         return new_population;
     }
 
+    fn set_random_seed(&mut self, random_seed: u64) {
+        self.random_generator = StdRng::seed_from_u64(random_seed);
+        let mover_seed: u64 = self.random_generator.gen();
+        self.mover.set_random_seed(mover_seed);
+    }
+
     fn get_metaheuristic_kind(&self) -> MetaheuristicKind {
         self.metaheuristic_kind.clone()
     }
@@ -15,6 +15,7 @@ use rand_distr::{Distribution, Uniform};
 
 use super::metaheuristic_kinds_and_names::{MetaheuristicKind, MetaheuristicNames};
 use crate::utils::math_utils;
+use crate::errors::GreyjackError;
 use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::collections::HashSet;
@@ -45,7 +46,7 @@ impl TabuSearchBase {
         move_probas: Option<Vec<f64>>,
         semantic_groups_map: HashMap<String, Vec<usize>>,
         discrete_ids: Option<Vec<usize>>,
-    ) -> Self {
+    ) -> Result<Self, GreyjackError> {
 
         let current_mutation_rate_multiplier;
         match mutation_rate_multiplier {
@@ -59,7 +60,7 @@ impl TabuSearchBase {
             group_mutation_rates_map.insert(group_name.clone(), current_group_mutation_rate);
         }
 
-        Self {
+        Ok(Self {
             neighbours_count: neighbours_count,
             tabu_entity_rate: tabu_entity_rate,
             compare_to_global: compare_to_global,
@@ -68,8 +69,8 @@ impl TabuSearchBase {
             metaheuristic_name: MetaheuristicNames::TabuSearch,
 
             discrete_ids: discrete_ids.clone(),
-            mover: Mover::new(tabu_entity_rate, HashMap::new(), HashMap::new(), HashMap::new(), group_mutation_rates_map, move_probas),
-        }
+            mover: Mover::new(tabu_entity_rate, HashMap::new(), HashMap::new(), HashMap::new(), group_mutation_rates_map, move_probas)?,
+        })
     }
 
 }
@@ -82,7 +83,7 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
             population: &mut Vec<Individual<ScoreType>>, 
             current_top_individual: &Individual<ScoreType>,
             variables_manager: &VariablesManager
-        ) -> Vec<Vec<f64>> {
+        ) -> Result<Vec<Vec<f64>>, GreyjackError> {
 
         if self.mover.tabu_entity_size_map.len() == 0 {
             let semantic_groups_map = variables_manager.semantic_groups_map.clone();
@@ -95,13 +96,13 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
 
         let current_best_candidate = population[0].variable_values.clone();
         let mut candidates: Vec<Vec<f64>> = (0..self.neighbours_count).into_iter().map(|i| {
-            let (changed_candidate, changed_columns, _) = self.mover.do_move(&current_best_candidate, variables_manager, false);
-            let mut candidate = changed_candidate.unwrap();
+            let (changed_candidate, changed_columns, _) = self.mover.do_move(&current_best_candidate, variables_manager, false)?;
+            let mut candidate = Mover::take_moved(changed_candidate)?;
             variables_manager.fix_variables(&mut candidate, changed_columns);
-            candidate
-        }).collect();
+            Ok(candidate)
+        }).collect::<Result<Vec<Vec<f64>>, GreyjackError>>()?;
 
-        return candidates;
+        return Ok(candidates);
     }
 
     fn sample_candidates_incremental(
@@ -109,7 +110,7 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         population: &mut Vec<Individual<ScoreType>>, 
         current_top_individual: &Individual<ScoreType>,
         variables_manager: &VariablesManager
-    ) -> (Vec<f64>, Vec<Vec<(usize, f64)>>) {
+    ) -> Result<(Vec<f64>, Vec<Vec<(usize, f64)>>), GreyjackError> {
 
         if self.mover.tabu_entity_size_map.len() == 0 {
             let semantic_groups_map = variables_manager.semantic_groups_map.clone();
@@ -123,15 +124,15 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         let current_best_candidate = population[0].variable_values.clone();
         let mut deltas: Vec<Vec<(usize, f64)>> = (0..self.neighbours_count).into_iter().map(|i| {
 
-            let (_, changed_columns, candidate_deltas) = self.mover.do_move(&current_best_candidate, variables_manager, true);
-            let mut candidate_deltas = candidate_deltas.unwrap();
+            let (_, changed_columns, candidate_deltas) = self.mover.do_move(&current_best_candidate, variables_manager, true)?;
+            let mut candidate_deltas = Mover::take_moved(candidate_deltas)?;
             variables_manager.fix_deltas(&mut candidate_deltas, changed_columns.clone());
-            let changed_columns = changed_columns.unwrap();
+            let changed_columns = Mover::take_moved(changed_columns)?;
             let candidate_deltas: Vec<(usize, f64)> = changed_columns.iter().zip(candidate_deltas.iter()).map(|(col_id, delta_value)| (*col_id, *delta_value)).collect();
-            candidate_deltas
-        }).collect();
+            Ok(candidate_deltas)
+        }).collect::<Result<Vec<Vec<(usize, f64)>>, GreyjackError>>()?;
 
-        return (current_best_candidate.clone(), deltas);
+        return Ok((current_best_candidate.clone(), deltas));
 
 
     }
@@ -187,6 +188,10 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
 
     }
 
+    fn set_random_seed(&mut self, random_seed: u64) {
+        self.mover.set_random_seed(random_seed);
+    }
+
     fn get_metaheuristic_kind(&self) -> MetaheuristicKind {
         self.metaheuristic_kind.clone()
     }
@@ -3,16 +3,17 @@ use super::base::agent_base::Agent;
 use super::metaheuristic_bases::SimulatedAnnealingBase;
 use super::metaheuristic_bases::MetaheuristicsBasesVariants;
 use crate::agents::termination_strategies::TerminationStrategiesVariants;
+use super::agent_builders_variants::validate_mover_params;
 use crate::score_calculation::score_requesters::OOPScoreRequester;
 use crate::score_calculation::scores::ScoreTrait;
 use crate::cotwin::CotwinEntityTrait;
-use crate::cotwin::Cotwin;
+use crate::errors::GreyjackError;
 use std::ops::{AddAssign, Sub};
 use std::fmt::{Debug, Display};
-use serde::Serialize;
+use serde::{Serialize, Deserialize};
 
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SimulatedAnnealing<ScoreType>
 where
     ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
@@ -50,20 +51,40 @@ where
         }
     }
 
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+
+        if self.initial_temperature.len() != ScoreType::precision_len() {
+            return Err(GreyjackError::InvalidConfig(format!("SimulatedAnnealing: initial_temperature must contain {} values (one per score level), got {}", 
+                                ScoreType::precision_len(), self.initial_temperature.len())));
+        }
+        if self.initial_temperature.iter().any(|temperature| *temperature <= 0.0) {
+            return Err(GreyjackError::InvalidConfig("SimulatedAnnealing: initial_temperature values must be greater than 0".to_string()));
+        }
+        match self.cooling_rate {
+            Some(cooling_rate) if cooling_rate <= 0.0 || cooling_rate >= 1.0 => {
+                return Err(GreyjackError::InvalidConfig(format!("SimulatedAnnealing: cooling_rate must be in (0.0, 1.0), got {}", cooling_rate)));
+            }
+            _ => ()
+        }
+        validate_mover_params("SimulatedAnnealing", self.tabu_entity_rate, self.mutation_rate_multiplier, &self.move_probas, self.migration_frequency)?;
+        self.termination_strategy.validate()?;
+
+        return Ok(());
+    }
+
     pub fn build_agent<EntityVariants, UtilityObjectVariants>(
         &self, 
-        cotwin: Cotwin<EntityVariants, UtilityObjectVariants, ScoreType>
-    )  -> Agent<EntityVariants, UtilityObjectVariants, ScoreType>
+        score_requester: OOPScoreRequester<EntityVariants, UtilityObjectVariants, ScoreType>
+    )  -> Result<Agent<EntityVariants, UtilityObjectVariants, ScoreType>, GreyjackError>
     where 
         EntityVariants: CotwinEntityTrait {
 
-        let score_requester = OOPScoreRequester::new(cotwin);
         let semantic_groups_dict = score_requester.variables_manager.semantic_groups_map.clone();
         let discrete_ids = score_requester.variables_manager.discrete_ids.clone();
 
         let metaheuristic_base = SimulatedAnnealingBase::new(self.initial_temperature.clone(), self.cooling_rate, self.tabu_entity_rate,
                                                                                  self.mutation_rate_multiplier, self.move_probas.clone(),
-                                                                                 semantic_groups_dict, discrete_ids);
+                                                                                 semantic_groups_dict, discrete_ids)?;
         let metaheuristic_base = MetaheuristicsBasesVariants::SAB(metaheuristic_base);
         
         let agent: Agent<EntityVariants, UtilityObjectVariants, ScoreType> = Agent::new(1.0, 
@@ -71,7 +92,7 @@ where
                                                                                         1, score_requester, 
                                                                                         metaheuristic_base);
         
-        return agent;
+        return Ok(agent);
 
     }
 
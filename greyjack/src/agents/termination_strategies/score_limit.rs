@@ -5,12 +5,14 @@ use crate::agents::base::Individual;
 use crate::score_calculation::scores::ScoreTrait;
 use std::ops::{AddAssign, Sub};
 use std::fmt::Debug;
+use serde::{Serialize, Deserialize};
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ScoreLimit<ScoreType>
 where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {
 
     target_score: ScoreType,
+    #[serde(skip, default = "ScoreType::get_stub_score")]
     current_best_score: ScoreType
 
 }
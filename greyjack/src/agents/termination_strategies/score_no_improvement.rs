@@ -3,20 +3,29 @@ use super::TerminationStrategyTrait;
 use chrono::prelude::*;
 use crate::agents::base::Individual;
 use crate::score_calculation::scores::ScoreTrait;
+use crate::errors::GreyjackError;
 use std::ops::{AddAssign, Sub};
 use std::fmt::Debug;
+use serde::{Serialize, Deserialize};
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ScoreNoImprovement<ScoreType>
 where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {
 
     limit_milliseconds: i64,
+    #[serde(skip, default = "unset_start_time")]
     start_time: i64,
+    #[serde(skip, default = "ScoreType::get_stub_score")]
     current_best_score: ScoreType,
+    #[serde(skip)]
     time_delta: i64
 
 }
 
+fn unset_start_time() -> i64 {
+    -1
+}
+
 impl<ScoreType> ScoreNoImprovement<ScoreType>
 where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {
 
@@ -53,6 +62,13 @@ where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord +
         }
     }
 
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+        if self.limit_milliseconds <= 0 {
+            return Err(GreyjackError::InvalidConfig("ScoreNoImprovement: limit_milliseconds must be greater than 0".to_string()));
+        }
+        return Ok(());
+    }
+
 }
 
 impl<ScoreType> TerminationStrategyTrait for ScoreNoImprovement<ScoreType>
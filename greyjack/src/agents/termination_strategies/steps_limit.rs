@@ -1,11 +1,14 @@
 
 
 use super::TerminationStrategyTrait;
+use crate::errors::GreyjackError;
+use serde::{Serialize, Deserialize};
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct StepsLimit {
 
     steps_limit: u64,
+    #[serde(skip)]
     steps_made: u64
 
 }
@@ -23,6 +26,13 @@ impl StepsLimit {
         self.steps_made += 1;
     }
 
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+        if self.steps_limit == 0 {
+            return Err(GreyjackError::InvalidConfig("StepsLimit: steps_limit must be greater than 0".to_string()));
+        }
+        return Ok(());
+    }
+
 }
 
 impl TerminationStrategyTrait for StepsLimit {
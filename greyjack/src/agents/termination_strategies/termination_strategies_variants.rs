@@ -5,17 +5,27 @@ use super::TimeSpentLimit;
 use super::ScoreNoImprovement;
 use super::ScoreLimit;
 use super::TerminationStrategyTrait;
+use crate::agents::base::Individual;
 use crate::score_calculation::scores::ScoreTrait;
+use crate::errors::GreyjackError;
 use std::ops::{AddAssign, Sub};
 use std::fmt::Debug;
+use serde::{Serialize, Deserialize};
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum TerminationStrategiesVariants<ScoreType>
 where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {
     StL(StepsLimit),
     SNI(ScoreNoImprovement<ScoreType>),
     TSL(TimeSpentLimit),
-    ScL(ScoreLimit<ScoreType>)
+    ScL(ScoreLimit<ScoreType>),
+    /*
+        Composites: AnyOf is accomplished when at least one child is accomplished
+        ("stop at 60 s OR when hard score reaches 0 OR after 10 s without improvement"),
+        AllOf - when all children are accomplished. Children can be composites too.
+    */
+    AnyOf(Vec<TerminationStrategiesVariants<ScoreType>>),
+    AllOf(Vec<TerminationStrategiesVariants<ScoreType>>),
 
 }
 
@@ -30,8 +40,134 @@ ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug
             TerminationStrategiesVariants::SNI(tsb) => tsb,
             TerminationStrategiesVariants::TSL(lab) => lab,
             TerminationStrategiesVariants::ScL(sab) => sab,
+            TerminationStrategiesVariants::AnyOf(_) => self,
+            TerminationStrategiesVariants::AllOf(_) => self,
 
+        }
+    }
+
+    pub fn update(&mut self, agent_top_individual: &Individual<ScoreType>) {
 
+        match self {
+            TerminationStrategiesVariants::StL(steps_limit) => steps_limit.update(),
+            TerminationStrategiesVariants::SNI(no_improvement) => no_improvement.update(agent_top_individual),
+            TerminationStrategiesVariants::TSL(time_spent_limit) => time_spent_limit.update(),
+            TerminationStrategiesVariants::ScL(score_limit) => score_limit.update(agent_top_individual),
+            TerminationStrategiesVariants::AnyOf(strategies) => strategies.iter_mut().for_each(|strategy| strategy.update(agent_top_individual)),
+            TerminationStrategiesVariants::AllOf(strategies) => strategies.iter_mut().for_each(|strategy| strategy.update(agent_top_individual)),
         }
     }
-}
\ No newline at end of file
+
+    pub fn get_name(&self) -> String {
+
+        match self {
+            TerminationStrategiesVariants::StL(_) => "StepsLimit".to_string(),
+            TerminationStrategiesVariants::SNI(_) => "ScoreNoImprovement".to_string(),
+            TerminationStrategiesVariants::TSL(_) => "TimeSpentLimit".to_string(),
+            TerminationStrategiesVariants::ScL(_) => "ScoreLimit".to_string(),
+            TerminationStrategiesVariants::AnyOf(_) => "AnyOf".to_string(),
+            TerminationStrategiesVariants::AllOf(_) => "AllOf".to_string(),
+        }
+    }
+
+    // names of accomplished leaf strategies, which caused termination (empty if strategy isn't accomplished)
+    pub fn get_fired_strategies(&self) -> Vec<String> {
+
+        if self.is_accomplish() == false {
+            return Vec::new();
+        }
+        match self {
+            TerminationStrategiesVariants::AnyOf(strategies) | TerminationStrategiesVariants::AllOf(strategies) => {
+                return strategies.iter().map(|strategy| strategy.get_fired_strategies()).flatten().collect();
+            }
+            _ => return vec![self.get_name()],
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+
+        match self {
+            TerminationStrategiesVariants::StL(stl) => stl.validate(),
+            TerminationStrategiesVariants::SNI(sni) => sni.validate(),
+            TerminationStrategiesVariants::TSL(tsl) => tsl.validate(),
+            TerminationStrategiesVariants::ScL(_) => Ok(()),
+            TerminationStrategiesVariants::AnyOf(strategies) | TerminationStrategiesVariants::AllOf(strategies) => {
+                if strategies.len() == 0 {
+                    return Err(GreyjackError::InvalidConfig(format!("{}: composite termination strategy must contain at least one child strategy", self.get_name())));
+                }
+                for strategy in strategies {
+                    strategy.validate()?;
+                }
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl<ScoreType> TerminationStrategyTrait for TerminationStrategiesVariants<ScoreType>
+where 
+ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {
+
+    fn is_accomplish(&self) -> bool {
+
+        match self {
+            TerminationStrategiesVariants::StL(steps_limit) => steps_limit.is_accomplish(),
+            TerminationStrategiesVariants::SNI(no_improvement) => no_improvement.is_accomplish(),
+            TerminationStrategiesVariants::TSL(time_spent_limit) => time_spent_limit.is_accomplish(),
+            TerminationStrategiesVariants::ScL(score_limit) => score_limit.is_accomplish(),
+            TerminationStrategiesVariants::AnyOf(strategies) => strategies.iter().any(|strategy| strategy.is_accomplish()),
+            TerminationStrategiesVariants::AllOf(strategies) => strategies.iter().all(|strategy| strategy.is_accomplish()),
+        }
+    }
+
+    fn get_accomplish_rate(&self) -> f64 {
+
+        /*
+            Simulated annealing cools by accomplish rate, so composites give rate in [0, 1]:
+            AnyOf - rate of the closest to finish child, AllOf - rate of the farthest one.
+        */
+        match self {
+            TerminationStrategiesVariants::StL(steps_limit) => steps_limit.get_accomplish_rate(),
+            TerminationStrategiesVariants::SNI(no_improvement) => no_improvement.get_accomplish_rate(),
+            TerminationStrategiesVariants::TSL(time_spent_limit) => time_spent_limit.get_accomplish_rate(),
+            TerminationStrategiesVariants::ScL(score_limit) => score_limit.get_accomplish_rate(),
+            TerminationStrategiesVariants::AnyOf(strategies) => {
+                strategies.iter().map(|strategy| strategy.get_accomplish_rate().clamp(0.0, 1.0)).fold(0.0, f64::max)
+            }
+            TerminationStrategiesVariants::AllOf(strategies) => {
+                strategies.iter().map(|strategy| strategy.get_accomplish_rate().clamp(0.0, 1.0)).fold(1.0, f64::min)
+            }
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::score_calculation::scores::SimpleScore;
+
+    #[test]
+    fn test_composite_termination_strategies() {
+        let top_individual = Individual::new(vec![1.0], SimpleScore::new(10.0));
+        let mut any_of: TerminationStrategiesVariants<SimpleScore> = TerminationStrategiesVariants::AnyOf(vec![
+            TerminationStrategiesVariants::StL(StepsLimit::new(2)),
+            TerminationStrategiesVariants::StL(StepsLimit::new(4)),
+        ]);
+        let mut all_of: TerminationStrategiesVariants<SimpleScore> = TerminationStrategiesVariants::AllOf(vec![
+            TerminationStrategiesVariants::StL(StepsLimit::new(2)),
+            TerminationStrategiesVariants::ScL(ScoreLimit::new(SimpleScore::new(0.0))),
+        ]);
+
+        for _ in 0..3 {
+            any_of.update(&top_individual);
+            all_of.update(&top_individual);
+        }
+        assert_eq!(any_of.is_accomplish(), true);
+        assert_eq!(any_of.get_fired_strategies(), vec!["StepsLimit".to_string()]);
+        assert_eq!(any_of.get_accomplish_rate(), 1.0);
+        assert_eq!(all_of.is_accomplish(), false);
+        assert_eq!(all_of.get_fired_strategies().len(), 0);
+
+        let empty_any_of: TerminationStrategiesVariants<SimpleScore> = TerminationStrategiesVariants::AnyOf(Vec::new());
+        assert_eq!(empty_any_of.validate().is_err(), true);
+    }
+}
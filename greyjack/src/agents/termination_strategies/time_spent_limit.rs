@@ -1,16 +1,24 @@
 
 use super::TerminationStrategyTrait;
+use crate::errors::GreyjackError;
 use chrono::prelude::*;
+use serde::{Serialize, Deserialize};
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TimeSpentLimit {
 
     limit_milliseconds: i64,
+    #[serde(skip, default = "unset_start_time")]
     start_time: i64,
+    #[serde(skip)]
     time_delta: i64
 
 }
 
+fn unset_start_time() -> i64 {
+    -1
+}
+
 impl TimeSpentLimit {
 
     pub fn new(limit_milliseconds: i64) -> Self{
@@ -32,6 +40,13 @@ impl TimeSpentLimit {
         
     }
 
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+        if self.limit_milliseconds <= 0 {
+            return Err(GreyjackError::InvalidConfig("TimeSpentLimit: limit_milliseconds must be greater than 0".to_string()));
+        }
+        return Ok(());
+    }
+
 }
 
 impl TerminationStrategyTrait for TimeSpentLimit {
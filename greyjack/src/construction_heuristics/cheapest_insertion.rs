@@ -0,0 +1,29 @@
+
+use crate::errors::GreyjackError;
+use serde::{Serialize, Deserialize};
+
+/*
+    On each step tries the next unassigned variable of every entity, that isn't fully assigned yet,
+    and makes the assignment with the best partial score among all of them.
+    Gives better starting solutions than first fit, but needs entities_count times more score requests.
+*/
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CheapestInsertion {
+    pub max_values_count: usize,
+}
+
+impl CheapestInsertion {
+
+    pub fn new(max_values_count: usize) -> Self {
+        Self {
+            max_values_count: max_values_count,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+        if self.max_values_count == 0 {
+            return Err(GreyjackError::InvalidConfig("CheapestInsertion: max_values_count must be greater than 0".to_string()));
+        }
+        return Ok(());
+    }
+}
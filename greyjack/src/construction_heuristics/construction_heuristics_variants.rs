@@ -0,0 +1,146 @@
+
+use super::{CheapestInsertion, Constructor, FirstFit, FirstFitDecreasing};
+use crate::agents::base::Individual;
+use crate::cotwin::CotwinEntityTrait;
+use crate::score_calculation::score_requesters::OOPScoreRequester;
+use crate::score_calculation::scores::ScoreTrait;
+use crate::errors::GreyjackError;
+
+use std::fmt::{Debug, Display};
+use std::ops::AddAssign;
+use serde::{Serialize, Deserialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub enum ConstructionHeuristicsVariants {
+    FF(FirstFit),
+    FFD(FirstFitDecreasing),
+    CI(CheapestInsertion),
+}
+
+impl ConstructionHeuristicsVariants {
+
+    pub fn get_name(&self) -> String {
+        match self {
+            ConstructionHeuristicsVariants::FF(_) => "FirstFit".to_string(),
+            ConstructionHeuristicsVariants::FFD(_) => "FirstFitDecreasing".to_string(),
+            ConstructionHeuristicsVariants::CI(_) => "CheapestInsertion".to_string(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+        match self {
+            ConstructionHeuristicsVariants::FF(ff) => ff.validate(),
+            ConstructionHeuristicsVariants::FFD(ffd) => ffd.validate(),
+            ConstructionHeuristicsVariants::CI(ci) => ci.validate(),
+        }
+    }
+
+    pub fn construct<EntityVariants, UtilityObjectVariants, ScoreType>(
+        &self,
+        score_requester: &mut OOPScoreRequester<EntityVariants, UtilityObjectVariants, ScoreType>
+    ) -> Result<Individual<ScoreType>, GreyjackError>
+    where
+        EntityVariants: CotwinEntityTrait,
+        ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
+
+        self.validate()?;
+
+        let mut constructor;
+        match self {
+            ConstructionHeuristicsVariants::FF(ff) => {
+                constructor = Constructor::new(score_requester, ff.max_values_count)?;
+                constructor.construct_in_order()?;
+            }
+            ConstructionHeuristicsVariants::FFD(ffd) => {
+                constructor = Constructor::new(score_requester, ffd.max_values_count)?;
+                constructor.sort_entities_by_difficulty(ffd.difficulty_comparator.as_ref().unwrap())?;
+                constructor.construct_in_order()?;
+            }
+            ConstructionHeuristicsVariants::CI(ci) => {
+                constructor = Constructor::new(score_requester, ci.max_values_count)?;
+                constructor.construct_cheapest_insertion()?;
+            }
+        }
+
+        return constructor.get_constructed_individual();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cotwin::{Cotwin, CotwinValueTypes};
+    use crate::score_calculation::score_calculators::{PlainScoreCalculator, ScoreCalculatorVariants};
+    use crate::score_calculation::scores::SimpleScore;
+    use crate::variables::GJInteger;
+    use polars::prelude::*;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    struct CotItem {
+        size: i64,
+        bin_id: GJInteger,
+    }
+
+    impl CotwinEntityTrait for CotItem {
+        fn to_vec(&self) -> Vec<(String, CotwinValueTypes)> {
+            vec![
+                ("size".to_string(), CotwinValueTypes::PAV(AnyValue::Int64(self.size))),
+                ("bin_id".to_string(), CotwinValueTypes::GJI(self.bin_id.clone())),
+            ]
+        }
+    }
+
+    // overflow of bins with capacity 10
+    fn bins_overflow(items_df: &DataFrame) -> Vec<SimpleScore> {
+        let sample_ids: Vec<usize> = items_df["sample_id"].u64().unwrap().into_no_null_iter().map(|x| x as usize).collect();
+        let bin_ids: Vec<i64> = items_df["bin_id"].i64().unwrap().into_no_null_iter().collect();
+        let sizes: Vec<i64> = items_df["size"].i64().unwrap().into_no_null_iter().collect();
+
+        let mut loads: HashMap<(usize, i64), i64> = HashMap::new();
+        (0..sample_ids.len()).for_each(|i| *loads.entry((sample_ids[i], bin_ids[i])).or_insert(0) += sizes[i]);
+        let mut scores = vec![SimpleScore::new(0.0); sample_ids.iter().max().unwrap() + 1];
+        loads.iter().for_each(|((sample_id, _), load)| scores[*sample_id] += SimpleScore::new((load - 10).max(0) as f64));
+        return scores;
+    }
+
+    fn build_score_requester() -> OOPScoreRequester<CotItem, (), SimpleScore> {
+        let items: Vec<CotItem> = vec![3, 3, 4, 4, 5, 5, 6].into_iter()
+            .map(|size| CotItem { size: size, bin_id: GJInteger::new(None, 0, 2, false, None) })
+            .collect();
+
+        let mut cotwin = Cotwin::new();
+        cotwin.add_planning_entities("items".to_string(), items);
+        let mut score_calculator: PlainScoreCalculator<(), SimpleScore> = PlainScoreCalculator::new();
+        score_calculator.add_constraint("bins_overflow".to_string(), Box::new(|planning_entity_dfs, _, _| bins_overflow(&planning_entity_dfs["items"])));
+        cotwin.add_score_calculator(ScoreCalculatorVariants::PSC(score_calculator));
+
+        let mut score_requester = OOPScoreRequester::new(cotwin).unwrap();
+        score_requester.variables_manager.set_random_seed(42);
+        return score_requester;
+    }
+
+    #[test]
+    fn test_construction_heuristics() {
+        let mut score_requester = build_score_requester();
+        let first_fit = ConstructionHeuristicsVariants::FF(FirstFit::new(16));
+        let individual = first_fit.construct(&mut score_requester).unwrap();
+        assert_eq!(individual.score, SimpleScore::new(1.0));
+        assert_eq!(score_requester.partial_scoring_mask.is_none(), true);
+        assert_eq!(score_requester.request_score_plain(&vec![individual.variable_values.clone()]).unwrap()[0], individual.score);
+
+        // the largest items go first
+        let mut score_requester = build_score_requester();
+        let first_fit_decreasing = ConstructionHeuristicsVariants::FFD(FirstFitDecreasing::new(16, Arc::new(|a, b| a["size"].extract::<i64>().cmp(&b["size"].extract::<i64>()))));
+        let individual = first_fit_decreasing.construct(&mut score_requester).unwrap();
+        assert_eq!(individual.score, SimpleScore::new(0.0));
+
+        let mut score_requester = build_score_requester();
+        let cheapest_insertion = ConstructionHeuristicsVariants::CI(CheapestInsertion::new(16));
+        let individual = cheapest_insertion.construct(&mut score_requester).unwrap();
+        assert_eq!(score_requester.request_score_plain(&vec![individual.variable_values.clone()]).unwrap()[0], individual.score);
+
+        let invalid_heuristic = ConstructionHeuristicsVariants::FFD(FirstFitDecreasing { max_values_count: 16, difficulty_comparator: None });
+        assert_eq!(invalid_heuristic.validate().is_err(), true);
+    }
+}
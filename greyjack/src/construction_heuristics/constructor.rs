@@ -0,0 +1,203 @@
+
+use crate::agents::base::Individual;
+use crate::cotwin::CotwinEntityTrait;
+use crate::domain::SolutionView;
+use crate::score_calculation::score_calculators::ScoreCalculatorVariants;
+use crate::score_calculation::score_requesters::OOPScoreRequester;
+use crate::score_calculation::scores::ScoreTrait;
+use crate::errors::GreyjackError;
+use super::{DifficultyComparator, EntityValues};
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Debug, Display};
+use std::ops::AddAssign;
+use serde::Serialize;
+
+/*
+    Common machinery of construction heuristics.
+    The start sample is taken from VariablesManager::sample_variables() (initial values are kept),
+    then variables without initial values are assigned entity by entity.
+    Entities with unassigned variables are hidden from score calculators (OOPScoreRequester::partial_scoring_mask),
+    so values are chosen by the partial score of already constructed entities.
+    List variables aren't constructed, they keep their sampled permutations.
+*/
+pub struct Constructor<'a, EntityVariants, UtilityObjectVariants, ScoreType>
+where
+    ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
+    pub score_requester: &'a mut OOPScoreRequester<EntityVariants, UtilityObjectVariants, ScoreType>,
+    pub max_values_count: usize,
+    pub sample: Vec<f64>,
+    // (entity group, entity index, ids of variables to assign)
+    pub entities: Vec<(String, usize, Vec<usize>)>,
+    pub entities_mask: HashMap<String, Vec<bool>>,
+}
+
+impl<'a, EntityVariants, UtilityObjectVariants, ScoreType>
+Constructor<'a, EntityVariants, UtilityObjectVariants, ScoreType>
+where
+    EntityVariants: CotwinEntityTrait,
+    ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
+
+    pub fn new(score_requester: &'a mut OOPScoreRequester<EntityVariants, UtilityObjectVariants, ScoreType>, max_values_count: usize) -> Result<Self, GreyjackError> {
+
+        let sample = score_requester.variables_manager.sample_variables();
+
+        let mut entities_map: BTreeMap<(String, usize), Vec<usize>> = BTreeMap::new();
+        let variables_names = score_requester.variables_manager.get_variables_names_vec();
+        for (variable_id, variable_name) in variables_names.iter().enumerate() {
+            if score_requester.variables_manager.is_constructable(variable_id) == false {
+                continue;
+            }
+            let (entity_group, entity_index, _) = SolutionView::parse_variable_name(variable_name)?;
+            entities_map.entry((entity_group, entity_index)).or_default().push(variable_id);
+        }
+
+        let mut entities_mask: HashMap<String, Vec<bool>> = score_requester.raw_dfs.iter()
+            .map(|(df_name, df)| (df_name.clone(), vec![true; df.height()]))
+            .collect();
+        let entities: Vec<(String, usize, Vec<usize>)> = entities_map.into_iter()
+            .map(|((entity_group, entity_index), variable_ids)| (entity_group, entity_index, variable_ids))
+            .collect();
+        entities.iter().for_each(|(entity_group, entity_index, _)| entities_mask.get_mut(entity_group).unwrap()[*entity_index] = false);
+
+        return Ok(Self {
+            score_requester: score_requester,
+            max_values_count: max_values_count,
+            sample: sample,
+            entities: entities,
+            entities_mask: entities_mask,
+        });
+    }
+
+    // entities of each group go from the most difficult to the easiest one, order of groups is kept
+    pub fn sort_entities_by_difficulty(&mut self, difficulty_comparator: &DifficultyComparator) -> Result<(), GreyjackError> {
+
+        let mut entities_values: Vec<EntityValues> = Vec::new();
+        for (entity_group, entity_index, _) in &self.entities {
+            let raw_df = &self.score_requester.raw_dfs[entity_group];
+            let mut entity_values: EntityValues = HashMap::new();
+            for column in raw_df.get_columns() {
+                if column.name().as_str() == "sample_id" {
+                    continue;
+                }
+                entity_values.insert(column.name().to_string(), column.get(*entity_index)?.into_static());
+            }
+            entities_values.push(entity_values);
+        }
+
+        let mut entity_ids: Vec<usize> = (0..self.entities.len()).collect();
+        entity_ids.sort_by(|a, b| {
+            self.entities[*a].0.cmp(&self.entities[*b].0)
+                .then_with(|| difficulty_comparator(&entities_values[*b], &entities_values[*a]))
+        });
+        self.entities = entity_ids.into_iter().map(|i| self.entities[i].clone()).collect();
+
+        return Ok(());
+    }
+
+    // entities are assigned in the current order (first fit and first fit decreasing)
+    pub fn construct_in_order(&mut self) -> Result<(), GreyjackError> {
+
+        for entity_id in 0..self.entities.len() {
+            let (entity_group, entity_index, variable_ids) = self.entities[entity_id].clone();
+            self.entities_mask.get_mut(&entity_group).unwrap()[entity_index] = true;
+            for variable_id in variable_ids {
+                let (best_value, _) = self.get_best_value(variable_id)?;
+                self.set_value(variable_id, best_value);
+            }
+        }
+
+        return Ok(());
+    }
+
+    pub fn construct_cheapest_insertion(&mut self) -> Result<(), GreyjackError> {
+
+        let mut remaining_entities = self.entities.clone();
+        while remaining_entities.len() > 0 {
+
+            let mut best_insertion: Option<(usize, f64, ScoreType)> = None;
+            for (entity_id, (entity_group, entity_index, variable_ids)) in remaining_entities.iter().enumerate() {
+                let was_visible = self.entities_mask[entity_group][*entity_index];
+                self.entities_mask.get_mut(entity_group).unwrap()[*entity_index] = true;
+                let (value, score) = self.get_best_value(variable_ids[0])?;
+                self.entities_mask.get_mut(entity_group).unwrap()[*entity_index] = was_visible;
+
+                let is_better;
+                match &best_insertion {
+                    None => is_better = true,
+                    Some((_, _, best_score)) => is_better = score < *best_score,
+                }
+                if is_better {
+                    best_insertion = Some((entity_id, value, score));
+                }
+            }
+
+            let (entity_id, value, _) = best_insertion.unwrap();
+            let (entity_group, entity_index, variable_ids) = &mut remaining_entities[entity_id];
+            self.entities_mask.get_mut(entity_group).unwrap()[*entity_index] = true;
+            let variable_id = variable_ids.remove(0);
+            self.set_value(variable_id, value);
+            if variable_ids.len() == 0 {
+                remaining_entities.remove(entity_id);
+            }
+        }
+
+        return Ok(());
+    }
+
+    // the best candidate value of the variable with its partial score, the first one wins ties
+    fn get_best_value(&mut self, variable_id: usize) -> Result<(f64, ScoreType), GreyjackError> {
+
+        let column_id = self.score_requester.variables_manager.variable_column_ranges[variable_id].0;
+        let candidate_values = self.score_requester.variables_manager.get_column_candidate_values(column_id, self.max_values_count);
+
+        self.score_requester.partial_scoring_mask = Some(self.entities_mask.clone());
+        let scores;
+        match &self.score_requester.cotwin.score_calculator {
+            ScoreCalculatorVariants::PSC(_) => {
+                let samples: Vec<Vec<f64>> = candidate_values.iter().map(|value| {
+                    let mut candidate_sample = self.sample.clone();
+                    candidate_sample[column_id] = *value;
+                    return candidate_sample;
+                }).collect();
+                scores = self.score_requester.request_score_plain(&samples);
+            }
+            ScoreCalculatorVariants::ISC(_) => {
+                let deltas: Vec<Vec<(usize, f64)>> = candidate_values.iter().map(|value| vec![(column_id, *value)]).collect();
+                scores = self.score_requester.request_score_incremental(&self.sample, &deltas);
+            }
+            ScoreCalculatorVariants::None => scores = Err(GreyjackError::InvalidCotwin("Score calculator is not existing. Check your cotwin builder.".to_string())),
+        }
+        self.score_requester.partial_scoring_mask = None;
+        let scores = scores?;
+
+        let mut best_id = 0;
+        for i in 1..scores.len() {
+            if scores[i] < scores[best_id] {
+                best_id = i;
+            }
+        }
+
+        return Ok((candidate_values[best_id], scores[best_id].clone()));
+    }
+
+    fn set_value(&mut self, variable_id: usize, value: f64) {
+        let column_id = self.score_requester.variables_manager.variable_column_ranges[variable_id].0;
+        self.sample[column_id] = value;
+    }
+
+    // the constructed sample with its full score
+    pub fn get_constructed_individual(&mut self) -> Result<Individual<ScoreType>, GreyjackError> {
+
+        let score;
+        match &self.score_requester.cotwin.score_calculator {
+            ScoreCalculatorVariants::ISC(_) => {
+                let deltas: Vec<Vec<(usize, f64)>> = vec![self.sample.iter().enumerate().map(|(i, value)| (i, *value)).collect()];
+                score = self.score_requester.request_score_incremental(&self.sample, &deltas)?[0].clone();
+            }
+            _ => score = self.score_requester.request_score_plain(&vec![self.sample.clone()])?[0].clone(),
+        }
+
+        return Ok(Individual::new(self.sample.clone(), score));
+    }
+}
@@ -0,0 +1,28 @@
+
+use crate::errors::GreyjackError;
+use serde::{Serialize, Deserialize};
+
+/*
+    Assigns entities one by one in the order of cotwin (groups are sorted by name),
+    each variable gets the value with the best partial score.
+*/
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FirstFit {
+    pub max_values_count: usize,
+}
+
+impl FirstFit {
+
+    pub fn new(max_values_count: usize) -> Self {
+        Self {
+            max_values_count: max_values_count,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+        if self.max_values_count == 0 {
+            return Err(GreyjackError::InvalidConfig("FirstFit: max_values_count must be greater than 0".to_string()));
+        }
+        return Ok(());
+    }
+}
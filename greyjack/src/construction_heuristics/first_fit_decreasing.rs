@@ -0,0 +1,49 @@
+
+use crate::errors::GreyjackError;
+use polars::datatypes::AnyValue;
+use serde::{Serialize, Deserialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// problem values (not planning ones) of an entity: column name -> value
+pub type EntityValues = HashMap<String, AnyValue<'static>>;
+pub type DifficultyComparator = Arc<dyn Fn(&EntityValues, &EntityValues) -> Ordering + Send + Sync>;
+
+/*
+    First fit, where entities of each group are assigned from the most difficult to the easiest one.
+    difficulty_comparator orders entities by increasing difficulty, for example by demand:
+        Arc::new(|a, b| a["demand"].extract::<i64>().cmp(&b["demand"].extract::<i64>()))
+    Comparator is a runtime object, so FirstFitDecreasing from JSON / TOML must get it with_difficulty_comparator().
+*/
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FirstFitDecreasing {
+    pub max_values_count: usize,
+    #[serde(skip)]
+    pub difficulty_comparator: Option<DifficultyComparator>,
+}
+
+impl FirstFitDecreasing {
+
+    pub fn new(max_values_count: usize, difficulty_comparator: DifficultyComparator) -> Self {
+        Self {
+            max_values_count: max_values_count,
+            difficulty_comparator: Some(difficulty_comparator),
+        }
+    }
+
+    pub fn with_difficulty_comparator(mut self, difficulty_comparator: DifficultyComparator) -> Self {
+        self.difficulty_comparator = Some(difficulty_comparator);
+        return self;
+    }
+
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+        if self.max_values_count == 0 {
+            return Err(GreyjackError::InvalidConfig("FirstFitDecreasing: max_values_count must be greater than 0".to_string()));
+        }
+        if self.difficulty_comparator.is_none() {
+            return Err(GreyjackError::InvalidConfig("FirstFitDecreasing: difficulty_comparator isn't set".to_string()));
+        }
+        return Ok(());
+    }
+}
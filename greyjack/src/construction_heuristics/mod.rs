@@ -0,0 +1,12 @@
+
+pub mod constructor;
+pub mod construction_heuristics_variants;
+pub mod first_fit;
+pub mod first_fit_decreasing;
+pub mod cheapest_insertion;
+
+pub use constructor::Constructor;
+pub use construction_heuristics_variants::ConstructionHeuristicsVariants;
+pub use first_fit::FirstFit;
+pub use first_fit_decreasing::{FirstFitDecreasing, DifficultyComparator, EntityValues};
+pub use cheapest_insertion::CheapestInsertion;
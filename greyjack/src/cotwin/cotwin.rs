@@ -5,7 +5,11 @@ use polars::frame::DataFrame;
 
 use crate::score_calculation::score_calculators::PlainScoreCalculator;
 use crate::score_calculation::score_calculators::score_calculator_variants::ScoreCalculatorVariants;
+use crate::score_calculation::score_calculators::ConstraintTimingStats;
 use crate::score_calculation::scores::ScoreTrait;
+use crate::score_calculation::score_explanations::ScoreExplanation;
+use crate::errors::GreyjackError;
+use super::ShadowVariablesListener;
 use std::ops::{AddAssign, Sub};
 
 
@@ -14,7 +18,8 @@ where
     ScoreType: ScoreTrait + Clone + AddAssign + Send {
     pub planning_entities: HashMap<String, Vec<EntityVariants>>,
     pub problem_facts: HashMap<String, Vec<EntityVariants>>,
-    pub score_calculator: ScoreCalculatorVariants<UtilityObjectVariants, ScoreType>
+    pub score_calculator: ScoreCalculatorVariants<UtilityObjectVariants, ScoreType>,
+    pub shadow_variables_listeners: HashMap<String, Vec<ShadowVariablesListener>>,
 }
 
 impl<EntityVariants, UtilityObjectVariants, ScoreType> 
@@ -26,7 +31,8 @@ where
         Self {
             planning_entities: HashMap::new(),
             problem_facts: HashMap::new(),
-            score_calculator: ScoreCalculatorVariants::None
+            score_calculator: ScoreCalculatorVariants::None,
+            shadow_variables_listeners: HashMap::new(),
         }
     }
     
@@ -38,6 +44,14 @@ where
         self.problem_facts.insert(name, problem_facts);
     }
 
+    // listeners of a group are applied in the order of adding, so a listener can use shadows of the previous ones
+    pub fn add_shadow_variables_listener(&mut self, planning_entities_name: String, listener: ShadowVariablesListener) {
+        if self.shadow_variables_listeners.contains_key(&planning_entities_name) == false {
+            self.shadow_variables_listeners.insert(planning_entities_name.clone(), Vec::new());
+        }
+        self.shadow_variables_listeners.get_mut(&planning_entities_name).unwrap().push(listener);
+    }
+
     pub fn add_score_calculator(&mut self, score_calculator: ScoreCalculatorVariants<UtilityObjectVariants, ScoreType>) {
         self.score_calculator = score_calculator;
     }
@@ -47,12 +61,63 @@ where
         planning_entity_dfs: &HashMap<String, DataFrame>, 
         problem_fact_dfs: &HashMap<String, DataFrame>,
         delta_dfs: Option<&HashMap<String, DataFrame>>
-    ) -> Vec<ScoreType> {
+    ) -> Result<Vec<ScoreType>, GreyjackError> {
 
         match &mut self.score_calculator {
             ScoreCalculatorVariants::PSC(psc) => psc.get_score(planning_entity_dfs, problem_fact_dfs),
-            ScoreCalculatorVariants::ISC(isc) => isc.get_score(planning_entity_dfs, problem_fact_dfs, delta_dfs.unwrap()),
-            ScoreCalculatorVariants::None => panic!("No score calculators in cotwin. Add plain or incremental calculator in cotwin builder") 
+            ScoreCalculatorVariants::ISC(isc) => {
+                match delta_dfs {
+                    Some(delta_dfs) => isc.get_score(planning_entity_dfs, problem_fact_dfs, delta_dfs),
+                    None => Err(GreyjackError::ScoreCalculation("incremental score calculator needs delta dataframes".to_string())),
+                }
+            },
+            ScoreCalculatorVariants::None => Err(GreyjackError::InvalidCotwin("No score calculators in cotwin. Add plain or incremental calculator in cotwin builder".to_string()))
+        }
+    }
+
+    pub fn explain_score(
+        &mut self, 
+        planning_entity_dfs: &HashMap<String, DataFrame>, 
+        problem_fact_dfs: &HashMap<String, DataFrame>,
+        delta_dfs: Option<&HashMap<String, DataFrame>>
+    ) -> Result<ScoreExplanation<ScoreType>, GreyjackError> {
+
+        match &mut self.score_calculator {
+            ScoreCalculatorVariants::PSC(psc) => psc.explain_score(planning_entity_dfs, problem_fact_dfs),
+            ScoreCalculatorVariants::ISC(isc) => {
+                match delta_dfs {
+                    Some(delta_dfs) => isc.explain_score(planning_entity_dfs, problem_fact_dfs, delta_dfs),
+                    None => Err(GreyjackError::ScoreCalculation("incremental score calculator needs delta dataframes".to_string())),
+                }
+            },
+            ScoreCalculatorVariants::None => Err(GreyjackError::InvalidCotwin("No score calculators in cotwin. Add plain or incremental calculator in cotwin builder".to_string()))
+        }
+    }
+
+    pub fn override_constraint_weights(&mut self, constraint_weight_overrides: &HashMap<String, f64>) -> Result<(), GreyjackError> {
+
+        match &mut self.score_calculator {
+            ScoreCalculatorVariants::PSC(psc) => psc.override_constraint_weights(constraint_weight_overrides),
+            ScoreCalculatorVariants::ISC(isc) => isc.override_constraint_weights(constraint_weight_overrides),
+            ScoreCalculatorVariants::None => Err(GreyjackError::InvalidCotwin("No score calculators in cotwin. Add plain or incremental calculator in cotwin builder".to_string()))
+        }
+    }
+
+    pub fn get_constraint_weights(&self) -> Vec<(String, f64)> {
+
+        match &self.score_calculator {
+            ScoreCalculatorVariants::PSC(psc) => psc.get_constraint_weights(),
+            ScoreCalculatorVariants::ISC(isc) => isc.get_constraint_weights(),
+            ScoreCalculatorVariants::None => Vec::new(),
+        }
+    }
+
+    pub fn get_timing_stats(&mut self) -> Vec<ConstraintTimingStats> {
+
+        match &mut self.score_calculator {
+            ScoreCalculatorVariants::PSC(psc) => psc.get_timing_stats(),
+            ScoreCalculatorVariants::ISC(isc) => isc.get_timing_stats(),
+            ScoreCalculatorVariants::None => Vec::new(),
         }
     }
 
@@ -2,10 +2,11 @@
 
 use crate::cotwin::Cotwin;
 use crate::score_calculation::scores::ScoreTrait;
+use crate::errors::GreyjackError;
 use std::ops::{AddAssign, Sub};
 
 pub trait CotwinBuilderTrait<DomainType, EntityVariants, UtilityObjectVariants, ScoreType>
 where 
     ScoreType: ScoreTrait + Clone + AddAssign + Send {
-    fn build_cotwin(&self, domain: DomainType, is_already_initialized: bool) -> Cotwin<EntityVariants, UtilityObjectVariants, ScoreType>;
+    fn build_cotwin(&self, domain: DomainType, is_already_initialized: bool) -> Result<Cotwin<EntityVariants, UtilityObjectVariants, ScoreType>, GreyjackError>;
 }
\ No newline at end of file
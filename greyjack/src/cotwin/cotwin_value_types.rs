@@ -2,12 +2,18 @@
 
 use crate::variables::GJFloat;
 use crate::variables::GJInteger;
+use crate::variables::GJBool;
+use crate::variables::GJCategorical;
+use crate::variables::GJList;
 use polars::datatypes::{AnyValue, *};
 
 #[derive(Debug, Clone)]
 pub enum CotwinValueTypes<'a> {
     GJF(GJFloat),
     GJI(GJInteger),
+    GJB(GJBool),
+    GJC(GJCategorical),
+    GJL(GJList),
     PAV(AnyValue<'a>) //PolarsAnyValue,
 }
 
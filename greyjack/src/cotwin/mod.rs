@@ -3,8 +3,17 @@ pub mod cotwin;
 pub mod cotwin_entity_trait;
 pub mod cotwin_value_types;
 pub mod cotwin_builder_trait;
+pub mod shadow_variables_listener;
+pub mod to_any_value;
+pub mod planning_variable_trait;
 
 pub use cotwin::Cotwin;
 pub use cotwin_entity_trait::CotwinEntityTrait;
 pub use cotwin_value_types::CotwinValueTypes;
-pub use cotwin_builder_trait::CotwinBuilderTrait;
\ No newline at end of file
+pub use cotwin_builder_trait::CotwinBuilderTrait;
+pub use shadow_variables_listener::ShadowVariablesListener;
+pub use to_any_value::ToAnyValue;
+pub use planning_variable_trait::PlanningVariableTrait;
+
+#[cfg(feature = "derive")]
+pub use greyjack_derive::CotwinEntity;
\ No newline at end of file
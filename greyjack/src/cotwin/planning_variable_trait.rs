@@ -0,0 +1,84 @@
+
+use crate::cotwin::CotwinValueTypes;
+use crate::variables::{GJBool, GJCategorical, GJFloat, GJInteger, GJList};
+
+// fields marked #[planning_variable] in #[derive(CotwinEntity)] structs
+pub trait PlanningVariableTrait {
+    fn to_cotwin_value(&self) -> CotwinValueTypes<'static>;
+}
+
+impl PlanningVariableTrait for GJFloat {
+    fn to_cotwin_value(&self) -> CotwinValueTypes<'static> {
+        return CotwinValueTypes::GJF(self.clone());
+    }
+}
+
+impl PlanningVariableTrait for GJInteger {
+    fn to_cotwin_value(&self) -> CotwinValueTypes<'static> {
+        return CotwinValueTypes::GJI(self.clone());
+    }
+}
+
+impl PlanningVariableTrait for GJBool {
+    fn to_cotwin_value(&self) -> CotwinValueTypes<'static> {
+        return CotwinValueTypes::GJB(self.clone());
+    }
+}
+
+impl PlanningVariableTrait for GJCategorical {
+    fn to_cotwin_value(&self) -> CotwinValueTypes<'static> {
+        return CotwinValueTypes::GJC(self.clone());
+    }
+}
+
+impl PlanningVariableTrait for GJList {
+    fn to_cotwin_value(&self) -> CotwinValueTypes<'static> {
+        return CotwinValueTypes::GJL(self.clone());
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+    use crate::cotwin::{CotwinEntity, CotwinEntityTrait};
+    use polars::datatypes::AnyValue;
+
+    #[derive(CotwinEntity)]
+    struct CotMachine {
+        machine_id: u64,
+        name: String,
+        #[planning_variable]
+        speed: GJFloat,
+        capacity: Option<i64>,
+        #[planning_variable]
+        job_id: GJInteger,
+    }
+
+    #[test]
+    fn test_cotwin_entity_derive() {
+        let machine = CotMachine {
+            machine_id: 3,
+            name: "lathe".to_string(),
+            speed: GJFloat::new(Some(1.5), 0.0, 10.0, false, None),
+            capacity: None,
+            job_id: GJInteger::new(Some(2), 0, 5, false, None),
+        };
+
+        let machine_vec = machine.to_vec();
+        let column_names: Vec<String> = machine_vec.iter().map(|(name, _)| name.clone()).collect();
+        assert_eq!(column_names, vec!["machine_id", "name", "speed", "capacity", "job_id"]);
+
+        let mut value_kinds = Vec::new();
+        for (_, value) in &machine_vec {
+            match value {
+                CotwinValueTypes::PAV(AnyValue::UInt64(3)) => value_kinds.push("u64"),
+                CotwinValueTypes::PAV(AnyValue::StringOwned(x)) => value_kinds.push(if x.as_str() == "lathe" {"string"} else {"wrong"}),
+                CotwinValueTypes::PAV(AnyValue::Null) => value_kinds.push("null"),
+                CotwinValueTypes::GJF(_) => value_kinds.push("float"),
+                CotwinValueTypes::GJI(_) => value_kinds.push("integer"),
+                _ => value_kinds.push("wrong"),
+            }
+        }
+        assert_eq!(value_kinds, vec!["u64", "string", "float", "null", "integer"]);
+    }
+}
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+use polars::frame::DataFrame;
+
+/*
+    Shadow variables are columns of a planning entities df derived from genuine planning variables
+    (arrival times, vehicle loads, position in a route, etc). The update function gets entities
+    of one or many samples (column "sample_id", rows of a sample keep the cotwin order) and problem fact dfs
+    and must return a df of the same height with the shadow columns.
+
+    In incremental mode only entities that share scope_column value with a changed entity
+    (old or new one, e.g. the same vehicle_id) are recomputed. Without scope column
+    all entities of a changed sample are recomputed. Recomputed rows with changed shadow values
+    are added to delta dfs, so incremental constraints see them as changed entities.
+*/
+pub struct ShadowVariablesListener {
+    pub shadow_variable_names: Vec<String>,
+    pub scope_column: Option<String>,
+    pub update_function: Box<dyn (Fn(&DataFrame, &HashMap<String, DataFrame>) -> DataFrame) + Send>,
+}
+
+impl ShadowVariablesListener {
+    pub fn new(
+        shadow_variable_names: Vec<String>, 
+        scope_column: Option<String>, 
+        update_function: Box<dyn (Fn(&DataFrame, &HashMap<String, DataFrame>) -> DataFrame) + Send>
+    ) -> Self {
+        Self {
+            shadow_variable_names: shadow_variable_names,
+            scope_column: scope_column,
+            update_function: update_function,
+        }
+    }
+}
+
+unsafe impl Send for ShadowVariablesListener {}
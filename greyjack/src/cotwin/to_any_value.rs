@@ -0,0 +1,60 @@
+
+use polars::datatypes::AnyValue;
+
+// problem values of entities, used by #[derive(CotwinEntity)] to build PAV columns with the right dtype
+pub trait ToAnyValue {
+    fn to_any_value(&self) -> AnyValue<'static>;
+}
+
+macro_rules! impl_to_any_value {
+    ($($value_type:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl ToAnyValue for $value_type {
+                fn to_any_value(&self) -> AnyValue<'static> {
+                    return AnyValue::$variant(*self);
+                }
+            }
+        )*
+    };
+}
+
+impl_to_any_value!(
+    bool => Boolean,
+    i8 => Int8,
+    i16 => Int16,
+    i32 => Int32,
+    i64 => Int64,
+    u8 => UInt8,
+    u16 => UInt16,
+    u32 => UInt32,
+    u64 => UInt64,
+    f32 => Float32,
+    f64 => Float64,
+);
+
+impl ToAnyValue for usize {
+    fn to_any_value(&self) -> AnyValue<'static> {
+        return AnyValue::UInt64(*self as u64);
+    }
+}
+
+impl ToAnyValue for String {
+    fn to_any_value(&self) -> AnyValue<'static> {
+        return AnyValue::StringOwned(self.as_str().into());
+    }
+}
+
+impl ToAnyValue for AnyValue<'static> {
+    fn to_any_value(&self) -> AnyValue<'static> {
+        return self.clone();
+    }
+}
+
+impl<T: ToAnyValue> ToAnyValue for Option<T> {
+    fn to_any_value(&self) -> AnyValue<'static> {
+        match self {
+            Some(x) => return x.to_any_value(),
+            None => return AnyValue::Null,
+        }
+    }
+}
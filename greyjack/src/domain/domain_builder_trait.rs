@@ -17,6 +17,8 @@ where
     // due to offset of enities (for example: build_domain_from_scratch() builds 10 vehicles, but domain for 
     // replanning has 9 vehicles (something was removed, that's why need replanning))
     // For replanning scenario always write case for processing initial_domain=YOUR_DOMAIN_OBJECT_FOR_REPLANNING
+    // SolutionView::from_raw_solution(solution) gives solved values by (entity_group, entity_index, field_name)
+    // and can write them into domain entities, that implement DomainEntityTrait
     fn build_from_solution(&self, solution: &Value, initial_domain: Option<DomainType>) -> DomainType;
 
     // For multistage solving cases, when you need to take solution
@@ -24,6 +26,8 @@ where
     // by some logic (for example: freeze some variables to prevent changes in the Nth stage)
     // and then use it as initial solution.
     // Suggest just to use return domain.clone() in most cases due to the described logic above.
+    // If stages differ only by solving settings, use SolverConfig::with_phase() instead,
+    // phases continue from the best solution without building domains.
     fn build_from_domain(&self, domain: &DomainType) -> DomainType {
         return domain.clone();
     }
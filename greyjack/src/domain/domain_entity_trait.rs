@@ -0,0 +1,51 @@
+
+use std::collections::BTreeMap;
+use polars::datatypes::AnyValue;
+use crate::errors::GreyjackError;
+
+// column name -> solved value of one cotwin entity
+pub type SolvedValues = BTreeMap<String, AnyValue<'static>>;
+
+/*
+    Domain entity, that receives solved values of its cotwin entity (column name -> value).
+    Usually derived with #[derive(DomainEntity)] and filled by SolutionView::set_solved_values().
+*/
+pub trait DomainEntityTrait {
+    fn set_solved_values(&mut self, solved_values: &SolvedValues) -> Result<(), GreyjackError>;
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+    use crate::domain::DomainEntity;
+
+    #[derive(DomainEntity)]
+    struct Task {
+        name: String,
+        #[solved_value]
+        machine_id: i64,
+        #[solved_value(column = "start_time")]
+        start: f64,
+        #[solved_value]
+        is_urgent: Option<bool>,
+    }
+
+    #[test]
+    fn test_domain_entity_derive() {
+        let mut task = Task { name: "drilling".to_string(), machine_id: -1, start: 0.0, is_urgent: Some(false) };
+
+        let mut solved_values: SolvedValues = BTreeMap::new();
+        solved_values.insert("machine_id".to_string(), AnyValue::Int64(3));
+        solved_values.insert("start_time".to_string(), AnyValue::Float64(12.5));
+        solved_values.insert("is_urgent".to_string(), AnyValue::Null);
+        task.set_solved_values(&solved_values).unwrap();
+
+        assert_eq!(task.name, "drilling");
+        assert_eq!(task.machine_id, 3);
+        assert_eq!(task.start, 12.5);
+        assert_eq!(task.is_urgent, None);
+
+        solved_values.remove("start_time");
+        assert_eq!(task.set_solved_values(&solved_values).is_err(), true);
+    }
+}
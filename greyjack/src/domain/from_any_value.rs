@@ -0,0 +1,69 @@
+
+use polars::datatypes::AnyValue;
+use crate::errors::GreyjackError;
+
+// solved values -> fields of domain entities, used by #[derive(DomainEntity)]
+pub trait FromAnyValue: Sized {
+    fn from_any_value(value: &AnyValue) -> Result<Self, GreyjackError>;
+}
+
+macro_rules! impl_from_any_value {
+    ($($value_type:ty),* $(,)?) => {
+        $(
+            impl FromAnyValue for $value_type {
+                fn from_any_value(value: &AnyValue) -> Result<Self, GreyjackError> {
+                    match value.extract::<$value_type>() {
+                        Some(x) => return Ok(x),
+                        None => return Err(GreyjackError::Serialization(format!("can't convert {:?} to {}", value, stringify!($value_type)))),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_any_value!(i8, i16, i32, i64, u8, u16, u32, u64, usize, f32, f64);
+
+impl FromAnyValue for bool {
+    fn from_any_value(value: &AnyValue) -> Result<Self, GreyjackError> {
+        match value {
+            AnyValue::Boolean(x) => return Ok(*x),
+            _ => return Err(GreyjackError::Serialization(format!("can't convert {:?} to bool", value))),
+        }
+    }
+}
+
+impl FromAnyValue for String {
+    fn from_any_value(value: &AnyValue) -> Result<Self, GreyjackError> {
+        match value.get_str() {
+            Some(x) => return Ok(x.to_string()),
+            None => return Err(GreyjackError::Serialization(format!("can't convert {:?} to String", value))),
+        }
+    }
+}
+
+impl FromAnyValue for AnyValue<'static> {
+    fn from_any_value(value: &AnyValue) -> Result<Self, GreyjackError> {
+        return Ok(value.clone().into_static());
+    }
+}
+
+// unassigned planning values are Null
+impl<T: FromAnyValue> FromAnyValue for Option<T> {
+    fn from_any_value(value: &AnyValue) -> Result<Self, GreyjackError> {
+        if value.is_null() {
+            return Ok(None);
+        }
+        return Ok(Some(T::from_any_value(value)?));
+    }
+}
+
+// planned elements of GJList
+impl<T: FromAnyValue> FromAnyValue for Vec<T> {
+    fn from_any_value(value: &AnyValue) -> Result<Self, GreyjackError> {
+        match value {
+            AnyValue::List(elements) => return elements.iter().map(|x| T::from_any_value(&x)).collect(),
+            _ => return Err(GreyjackError::Serialization(format!("can't convert {:?} to Vec", value))),
+        }
+    }
+}
@@ -1,4 +1,13 @@
 
 pub mod domain_builder_trait;
+pub mod domain_entity_trait;
+pub mod from_any_value;
+pub mod solution_view;
 
-pub use domain_builder_trait::DomainBuilderTrait;
\ No newline at end of file
+pub use domain_builder_trait::DomainBuilderTrait;
+pub use domain_entity_trait::{DomainEntityTrait, SolvedValues};
+pub use from_any_value::FromAnyValue;
+pub use solution_view::SolutionView;
+
+#[cfg(feature = "derive")]
+pub use greyjack_derive::DomainEntity;
@@ -0,0 +1,151 @@
+
+use std::collections::BTreeMap;
+use polars::datatypes::AnyValue;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use crate::errors::GreyjackError;
+use super::{DomainEntityTrait, SolvedValues};
+
+/*
+    Structured view of a raw solution (Vec<(String, AnyValue)>, score) from Solver::solve().
+    Variable names are built by OOPScoreRequester as "entity_group: entity_index-->field_name",
+    where entity_index is the position of the entity inside its group in the cotwin.
+    The view keys values by (entity_group, entity_index, field_name), so domain builders
+    don't need to parse names or rely on the order of variables.
+*/
+#[derive(Debug, Clone)]
+pub struct SolutionView {
+    pub entity_groups: BTreeMap<String, BTreeMap<usize, SolvedValues>>,
+    pub score: Value,
+}
+
+impl SolutionView {
+
+    pub fn new(variable_values: Vec<(String, AnyValue<'static>)>, score: Value) -> Result<Self, GreyjackError> {
+
+        let mut entity_groups: BTreeMap<String, BTreeMap<usize, SolvedValues>> = BTreeMap::new();
+        for (variable_name, value) in variable_values {
+            let (entity_group, entity_index, field_name) = Self::parse_variable_name(&variable_name)?;
+            entity_groups.entry(entity_group).or_default()
+                         .entry(entity_index).or_default()
+                         .insert(field_name, value);
+        }
+
+        return Ok(Self {
+            entity_groups: entity_groups,
+            score: score,
+        });
+    }
+
+    // accepts the raw solution and a serialized SolverResult, like InitialSolutionVariants::CotwinValuesVector
+    pub fn from_raw_solution(solution: &Value) -> Result<Self, GreyjackError> {
+
+        let raw_solution;
+        match solution {
+            Value::Object(fields) if fields.contains_key("variable_values") && fields.contains_key("score") => {
+                raw_solution = Value::Array(vec![fields["variable_values"].clone(), fields["score"].clone()]);
+            }
+            _ => raw_solution = solution.clone(),
+        }
+
+        let (variable_values, score): (Vec<(String, AnyValue<'static>)>, Value) = serde_json::from_value(raw_solution)?;
+        return Self::new(variable_values, score);
+    }
+
+    pub fn parse_variable_name(variable_name: &str) -> Result<(String, usize, String), GreyjackError> {
+
+        let invalid_name_error = || GreyjackError::Serialization(format!("\"{}\" isn't a variable name of format \"entity_group: entity_index-->field_name\"", variable_name));
+
+        let (entity_part, field_name) = variable_name.rsplit_once("-->").ok_or_else(invalid_name_error)?;
+        let (entity_group, entity_index) = entity_part.rsplit_once(": ").ok_or_else(invalid_name_error)?;
+        let entity_index: usize = entity_index.parse().map_err(|_| invalid_name_error())?;
+
+        return Ok((entity_group.to_string(), entity_index, field_name.to_string()));
+    }
+
+    pub fn get_value(&self, entity_group: &str, entity_index: usize, field_name: &str) -> Option<&AnyValue<'static>> {
+        return self.entity_groups.get(entity_group)?.get(&entity_index)?.get(field_name);
+    }
+
+    // solved values of a group's entities, ordered by entity_index
+    pub fn get_entities(&self, entity_group: &str) -> Result<&BTreeMap<usize, SolvedValues>, GreyjackError> {
+        match self.entity_groups.get(entity_group) {
+            Some(entities) => return Ok(entities),
+            None => return Err(GreyjackError::Serialization(format!("solution doesn't contain entities group \"{}\"", entity_group))),
+        }
+    }
+
+    pub fn get_score<ScoreType: DeserializeOwned>(&self) -> Result<ScoreType, GreyjackError> {
+        let score = serde_json::from_value(self.score.clone())?;
+        return Ok(score);
+    }
+
+    /*
+        Writes solved values into domain entities, that correspond (by index) to cotwin entities of the group.
+        In a replanning scenario domain_entities must be in the same order as cotwin entities were built.
+    */
+    pub fn set_solved_values<DomainEntity>(&self, entity_group: &str, domain_entities: &mut [DomainEntity]) -> Result<(), GreyjackError>
+    where DomainEntity: DomainEntityTrait {
+
+        let entities = self.get_entities(entity_group)?;
+        for (entity_index, solved_values) in entities {
+            match domain_entities.get_mut(*entity_index) {
+                Some(domain_entity) => domain_entity.set_solved_values(solved_values)?,
+                None => return Err(GreyjackError::Serialization(
+                    format!("solution has entity {} of group \"{}\", but only {} domain entities were passed", entity_index, entity_group, domain_entities.len())
+                )),
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use polars::prelude::*;
+    use crate::domain::FromAnyValue;
+
+    struct Stop {
+        vehicle_id: i64,
+        customer_ids: Vec<i64>,
+    }
+
+    impl DomainEntityTrait for Stop {
+        fn set_solved_values(&mut self, solved_values: &SolvedValues) -> Result<(), GreyjackError> {
+            self.vehicle_id = FromAnyValue::from_any_value(&solved_values["vehicle_id"])?;
+            self.customer_ids = FromAnyValue::from_any_value(&solved_values["customer_ids"])?;
+            return Ok(());
+        }
+    }
+
+    #[test]
+    fn test_solution_view() {
+        let variable_values = vec![
+            ("planning_stops: 1-->vehicle_id".to_string(), AnyValue::Int64(2)),
+            ("planning_stops: 0-->customer_ids".to_string(), AnyValue::List(Series::new("".into(), vec![4i64, 5]))),
+            ("planning_stops: 0-->vehicle_id".to_string(), AnyValue::Int64(1)),
+            ("planning_stops: 1-->customer_ids".to_string(), AnyValue::List(Series::new("".into(), Vec::<i64>::new()))),
+        ];
+        let raw_solution = json!((variable_values, 12.5));
+
+        let solution_view = SolutionView::from_raw_solution(&raw_solution).unwrap();
+        assert_eq!(solution_view.get_value("planning_stops", 1, "vehicle_id"), Some(&AnyValue::Int64(2)));
+        assert_eq!(solution_view.get_value("planning_stops", 2, "vehicle_id").is_none(), true);
+        assert_eq!(solution_view.get_entities("planning_stops").unwrap().keys().cloned().collect::<Vec<usize>>(), vec![0, 1]);
+        assert_eq!(solution_view.get_score::<f64>().unwrap(), 12.5);
+        assert_eq!(solution_view.get_entities("vehicles").is_err(), true);
+
+        let mut stops: Vec<Stop> = (0..2).map(|_| Stop { vehicle_id: -1, customer_ids: Vec::new() }).collect();
+        solution_view.set_solved_values("planning_stops", &mut stops).unwrap();
+        assert_eq!(stops[0].vehicle_id, 1);
+        assert_eq!(stops[0].customer_ids, vec![4, 5]);
+        assert_eq!(stops[1].vehicle_id, 2);
+        assert_eq!(stops[1].customer_ids.len(), 0);
+
+        assert_eq!(solution_view.set_solved_values("planning_stops", &mut stops[..1]).is_err(), true);
+        assert_eq!(SolutionView::parse_variable_name("queens 3 row_id").is_err(), true);
+    }
+}
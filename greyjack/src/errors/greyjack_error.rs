@@ -0,0 +1,60 @@
+
+use std::fmt::{Display, Formatter};
+use std::error::Error;
+use polars::prelude::PolarsError;
+
+/*
+    Common error type of the crate. Everything that can fail because of user's input
+    (solver config, cotwin, planning variables, score calculators, DataFrame operations)
+    is returned as GreyjackError instead of panicking, so a long-lived service can reject
+    a bad request and keep working.
+*/
+#[derive(Debug)]
+pub enum GreyjackError {
+    InvalidConfig(String),
+    InvalidCotwin(String),
+    InvalidVariable(String),
+    NotEnoughObjects { requested: usize, available: usize },
+    ScoreCalculation(String),
+    MoveFailed(String),
+    Serialization(String),
+    Polars(PolarsError),
+}
+
+impl Display for GreyjackError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            GreyjackError::InvalidConfig(message) => write!(f, "Invalid solver config: {}", message),
+            GreyjackError::InvalidCotwin(message) => write!(f, "Invalid cotwin: {}", message),
+            GreyjackError::InvalidVariable(message) => write!(f, "Invalid planning variable: {}", message),
+            GreyjackError::NotEnoughObjects { requested, available } => {
+                write!(f, "Can't choose {} objects without replacement from collection of {} objects", requested, available)
+            },
+            GreyjackError::ScoreCalculation(message) => write!(f, "Score calculation failed: {}", message),
+            GreyjackError::MoveFailed(message) => write!(f, "Move failed: {}", message),
+            GreyjackError::Serialization(message) => write!(f, "Serialization failed: {}", message),
+            GreyjackError::Polars(polars_error) => write!(f, "Polars error: {}", polars_error),
+        }
+    }
+}
+
+impl Error for GreyjackError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GreyjackError::Polars(polars_error) => Some(polars_error),
+            _ => None,
+        }
+    }
+}
+
+impl From<PolarsError> for GreyjackError {
+    fn from(polars_error: PolarsError) -> Self {
+        GreyjackError::Polars(polars_error)
+    }
+}
+
+impl From<serde_json::Error> for GreyjackError {
+    fn from(serde_error: serde_json::Error) -> Self {
+        GreyjackError::Serialization(serde_error.to_string())
+    }
+}
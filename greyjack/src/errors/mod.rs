@@ -0,0 +1,4 @@
+
+pub mod greyjack_error;
+
+pub use greyjack_error::GreyjackError;
@@ -2,8 +2,14 @@
 
 pub mod solver;
 pub mod agents;
+pub mod construction_heuristics;
 pub mod domain;
 pub mod cotwin;
 pub mod variables;
 pub mod score_calculation;
-pub mod utils;
\ No newline at end of file
+pub mod utils;
+pub mod errors;
+
+// lets #[derive(CotwinEntity)] paths (::greyjack::...) resolve inside the crate itself
+#[cfg(feature = "derive")]
+extern crate self as greyjack;
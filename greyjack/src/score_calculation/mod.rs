@@ -2,4 +2,5 @@
 
 pub mod scores;
 pub mod score_requesters;
-pub mod score_calculators;
\ No newline at end of file
+pub mod score_calculators;
+pub mod score_explanations;
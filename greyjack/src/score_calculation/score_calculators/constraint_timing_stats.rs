@@ -0,0 +1,62 @@
+use serde::{Serialize, Deserialize};
+
+/*
+    Accumulated running time of one constraint (or prescoring function) of a score calculator.
+    Helps to find the constraint, which dominates the step time.
+    Stats of all agents are merged into SolverResult::constraint_timing_stats.
+*/
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConstraintTimingStats {
+    pub name: String,
+    pub is_prescoring_function: bool,
+    pub calls_count: u64,
+    pub total_nanoseconds: u64,
+}
+
+impl ConstraintTimingStats {
+
+    pub fn new(name: String, is_prescoring_function: bool) -> Self {
+        Self {
+            name: name,
+            is_prescoring_function: is_prescoring_function,
+            calls_count: 0,
+            total_nanoseconds: 0,
+        }
+    }
+
+    pub fn add_call(&mut self, elapsed_nanoseconds: u64) {
+        self.calls_count += 1;
+        self.total_nanoseconds += elapsed_nanoseconds;
+    }
+
+    pub fn get_total_milliseconds(&self) -> f64 {
+        return (self.total_nanoseconds as f64) / 1_000_000.0;
+    }
+
+    pub fn get_mean_milliseconds(&self) -> f64 {
+        if self.calls_count == 0 {
+            return 0.0;
+        }
+        return self.get_total_milliseconds() / (self.calls_count as f64);
+    }
+
+    // sums stats of the same functions from different agents, keeps order of the first occurrence
+    pub fn merge(stats_batches: Vec<Vec<ConstraintTimingStats>>) -> Vec<ConstraintTimingStats> {
+
+        let mut merged_stats: Vec<ConstraintTimingStats> = Vec::new();
+        for stats_batch in stats_batches {
+            for stats in stats_batch {
+                let existing_stats = merged_stats.iter_mut().find(|x| x.name == stats.name && x.is_prescoring_function == stats.is_prescoring_function);
+                match existing_stats {
+                    Some(existing_stats) => {
+                        existing_stats.calls_count += stats.calls_count;
+                        existing_stats.total_nanoseconds += stats.total_nanoseconds;
+                    }
+                    None => merged_stats.push(stats),
+                }
+            }
+        }
+
+        return merged_stats;
+    }
+}
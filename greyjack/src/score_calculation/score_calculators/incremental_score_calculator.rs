@@ -2,7 +2,12 @@
 use std::collections::{HashMap, HashSet};
 use polars::prelude::*;
 use crate::score_calculation::scores::ScoreTrait;
+use crate::score_calculation::score_explanations::{ConstraintExplanation, ScoreExplanation};
+use super::{ConstraintTimingStats, PrescoringOrder};
+use crate::errors::GreyjackError;
 use std::ops::{AddAssign, Sub};
+use std::time::Instant;
+use rayon::prelude::*;
 
 
 pub struct IncrementalScoreCalculator<UtilityObjectVariants, ScoreType>
@@ -10,8 +15,17 @@ where
     ScoreType: ScoreTrait + Clone + AddAssign {
     constraints: HashMap<String, Box<dyn (Fn(&HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &mut HashMap<String, UtilityObjectVariants>,) -> Vec<ScoreType>) + Send>>,
     constraint_weights: HashMap<String, f64>,
+    score_levels: Option<(usize, usize)>,
     utility_objects: HashMap<String, UtilityObjectVariants>,
     prescoring_functions: HashMap<String, Box<dyn Fn(&HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &mut HashMap<String, UtilityObjectVariants>,)>>,
+    constraint_matches: HashMap<String, Box<dyn (Fn(&HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &mut HashMap<String, UtilityObjectVariants>,) -> DataFrame) + Send>>,
+    parallel_constraints: HashMap<String, Box<dyn (Fn(&HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &HashMap<String, UtilityObjectVariants>,) -> Vec<ScoreType>) + Send + Sync>>,
+    // set only by set_parallel_constraints(), which requires Sync utility objects, so other methods stay unbounded
+    parallel_constraints_runner: Option<fn(&Self, &Vec<String>, &HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &HashMap<String, DataFrame>) -> Vec<(Vec<ScoreType>, u64)>>,
+    constraint_names: Vec<String>,
+    prescoring_order: PrescoringOrder,
+    constraint_timing_stats: HashMap<String, ConstraintTimingStats>,
+    prescoring_timing_stats: HashMap<String, ConstraintTimingStats>,
 }
 
 impl<UtilityObjectVariants, ScoreType> IncrementalScoreCalculator<UtilityObjectVariants, ScoreType>
@@ -21,26 +35,91 @@ where ScoreType: ScoreTrait + Clone + AddAssign {
         Self {
             constraints: HashMap::new(),
             constraint_weights: HashMap::new(),
+            score_levels: None,
             utility_objects: HashMap::new(),
             prescoring_functions: HashMap::new(),
+            constraint_matches: HashMap::new(),
+            parallel_constraints: HashMap::new(),
+            parallel_constraints_runner: None,
+            constraint_names: Vec::new(),
+            prescoring_order: PrescoringOrder::new(),
+            constraint_timing_stats: HashMap::new(),
+            prescoring_timing_stats: HashMap::new(),
         }
     }
 
     pub fn add_constraint(&mut self, constraint_name: String, constraint_function: Box<dyn (Fn(&HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &mut HashMap<String, UtilityObjectVariants>,) -> Vec<ScoreType>) + Send>) {
+        // constraints are evaluated and summed in registration order, re-adding keeps the original position
+        if self.constraint_names.contains(&constraint_name) == false {
+            self.constraint_names.push(constraint_name.clone());
+        }
+        self.parallel_constraints.remove(&constraint_name);
         self.constraints.insert(constraint_name.clone(), constraint_function);
         if self.constraint_weights.contains_key(&constraint_name) == false {
             self.constraint_weights.insert(constraint_name.to_string().clone(), 1.0);
         }
     }
 
+    /*
+        Constraint, which only reads utility objects. After set_parallel_constraints(true)
+        consecutive parallel constraints are evaluated concurrently, otherwise they run sequentially as usual ones.
+    */
+    pub fn add_parallel_constraint(&mut self, constraint_name: String, constraint_function: Box<dyn (Fn(&HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &HashMap<String, UtilityObjectVariants>,) -> Vec<ScoreType>) + Send + Sync>) {
+        if self.constraint_names.contains(&constraint_name) == false {
+            self.constraint_names.push(constraint_name.clone());
+        }
+        self.constraints.remove(&constraint_name);
+        self.parallel_constraints.insert(constraint_name.clone(), constraint_function);
+        if self.constraint_weights.contains_key(&constraint_name) == false {
+            self.constraint_weights.insert(constraint_name.to_string().clone(), 1.0);
+        }
+    }
+
     pub fn remove_constraint(&mut self, constraint_name: String) {
         self.constraints.remove(&constraint_name);
+        self.parallel_constraints.remove(&constraint_name);
+        self.constraint_names.retain(|name| *name != constraint_name);
+        self.constraint_timing_stats.remove(&constraint_name);
     }
 
     pub fn set_constraint_weights(&mut self, constraint_weigths: HashMap<String, f64>) {
         self.constraint_weights = constraint_weigths;
     }
 
+    // overrides from SolverConfig, names must belong to already added constraints
+    pub fn override_constraint_weights(&mut self, constraint_weight_overrides: &HashMap<String, f64>) -> Result<(), GreyjackError> {
+
+        let mut unknown_names: Vec<&String> = constraint_weight_overrides.keys().filter(|name| self.constraint_names.contains(*name) == false).collect();
+        if unknown_names.len() != 0 {
+            unknown_names.sort();
+            return Err(GreyjackError::InvalidConfig(format!("constraint_weight_overrides contain unknown constraints {:?}, known constraints: {:?}", unknown_names, self.constraint_names)));
+        }
+        for (constraint_name, constraint_weight) in constraint_weight_overrides {
+            self.constraint_weights.insert(constraint_name.clone(), *constraint_weight);
+        }
+
+        return Ok(());
+    }
+
+    // effective weights in registration order of constraints
+    pub fn get_constraint_weights(&self) -> Vec<(String, f64)> {
+        self.constraint_names.iter()
+        .filter(|constraint_name| self.constraint_weights.contains_key(*constraint_name))
+        .map(|constraint_name| (constraint_name.clone(), self.constraint_weights[constraint_name]))
+        .collect()
+    }
+
+    // expected (hard, soft) levels counts of constraint scores, only for scores with runtime levels (BendableScore)
+    pub fn set_score_levels(&mut self, hard_levels: usize, soft_levels: usize) -> Result<(), GreyjackError> {
+
+        match ScoreType::get_null_score().get_levels_counts() {
+            Some(_) => self.score_levels = Some((hard_levels, soft_levels)),
+            None => return Err(GreyjackError::InvalidConfig("score levels can be set only for scores with runtime levels (BendableScore)".to_string())),
+        }
+
+        return Ok(());
+    }
+
     pub fn add_utility_object(&mut self, utility_object_name: String, utility_object: UtilityObjectVariants) {
         self.utility_objects.insert(utility_object_name, utility_object);
     }
@@ -50,37 +129,157 @@ where ScoreType: ScoreTrait + Clone + AddAssign {
     }
 
     pub fn add_prescoring_function(&mut self, function_name: String, function: Box<dyn Fn(&HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &mut HashMap<String, UtilityObjectVariants>,)>) {
+        self.add_prescoring_function_with_dependencies(function_name, Vec::new(), function);
+    }
+
+    // function runs after all prescoring functions from dependencies (by their names)
+    pub fn add_prescoring_function_with_dependencies(&mut self, function_name: String, dependencies: Vec<String>, function: Box<dyn Fn(&HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &mut HashMap<String, UtilityObjectVariants>,)>) {
+        self.prescoring_order.add(function_name.clone(), dependencies);
         self.prescoring_functions.insert(function_name, function);
     }
 
     pub fn remove_prescoring_function(&mut self, function_name: String) {
         self.prescoring_functions.remove(&function_name);
+        self.prescoring_order.remove(&function_name);
+        self.prescoring_timing_stats.remove(&function_name);
+    }
+
+    // optional function, which returns rows violating the constraint, used only by explain_score()
+    pub fn add_constraint_matches(&mut self, constraint_name: String, matches_function: Box<dyn (Fn(&HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &HashMap<String, DataFrame>, &mut HashMap<String, UtilityObjectVariants>,) -> DataFrame) + Send>) {
+        self.constraint_matches.insert(constraint_name, matches_function);
+    }
+
+    pub fn remove_constraint_matches(&mut self, constraint_name: String) {
+        self.constraint_matches.remove(&constraint_name);
     }
     
-    pub fn get_score(
+    // prescoring functions in execution order, then constraints in registration order
+    pub fn get_timing_stats(&mut self) -> Vec<ConstraintTimingStats> {
+
+        let mut timing_stats: Vec<ConstraintTimingStats> = Vec::new();
+        let prescoring_function_names = self.prescoring_order.get_execution_order().unwrap_or(Vec::new());
+        for function_name in prescoring_function_names {
+            match self.prescoring_timing_stats.get(&function_name) {
+                Some(stats) => timing_stats.push(stats.clone()),
+                None => (),
+            }
+        }
+        for constraint_name in &self.constraint_names {
+            match self.constraint_timing_stats.get(constraint_name) {
+                Some(stats) => timing_stats.push(stats.clone()),
+                None => (),
+            }
+        }
+
+        return timing_stats;
+    }
+
+    pub fn reset_timing_stats(&mut self) {
+        self.constraint_timing_stats.clear();
+        self.prescoring_timing_stats.clear();
+    }
+
+    fn add_constraint_timing(&mut self, constraint_name: String, elapsed_nanoseconds: u64) {
+        self.constraint_timing_stats.entry(constraint_name.clone())
+            .or_insert(ConstraintTimingStats::new(constraint_name, false))
+            .add_call(elapsed_nanoseconds);
+    }
+
+    fn get_constraint_scores(
         &mut self, 
         planning_entity_dfs: &HashMap<String, DataFrame>, 
         problem_fact_dfs: &HashMap<String, DataFrame>,
         delta_dfs: &HashMap<String, DataFrame>,
-    ) -> Vec<ScoreType> {
+    ) -> Result<(Vec<String>, Vec<Vec<ScoreType>>), GreyjackError> {
 
-        for prescoring_function_name in self.prescoring_functions.keys() {
-            let prescoring_function = self.prescoring_functions.get(prescoring_function_name).unwrap();
+        for prescoring_function_name in self.prescoring_order.get_execution_order()? {
+            let prescoring_function = self.prescoring_functions.get(&prescoring_function_name).unwrap();
+            let start_time = Instant::now();
             prescoring_function(planning_entity_dfs, problem_fact_dfs, delta_dfs, &mut self.utility_objects);
+            let elapsed_nanoseconds = start_time.elapsed().as_nanos() as u64;
+            self.prescoring_timing_stats.entry(prescoring_function_name.clone())
+                .or_insert(ConstraintTimingStats::new(prescoring_function_name, true))
+                .add_call(elapsed_nanoseconds);
         }
 
-        let mut constraint_names: Vec<String> = Vec::new();
-        for constraint_name in self.constraints.keys() {
-            constraint_names.push(constraint_name.clone());
-        }
-
+        let constraint_names = self.constraint_names.clone();
         let mut scores_vec = Vec::new();
-        for constraint_name in constraint_names.clone() {
-            let current_constraint_function = self.constraints.get(&constraint_name).unwrap();
-            let current_score_vec = current_constraint_function(planning_entity_dfs, problem_fact_dfs, delta_dfs, &mut self.utility_objects);
-            scores_vec.push(current_score_vec);
+        let mut constraint_id = 0;
+        while constraint_id < constraint_names.len() {
+
+            if self.parallel_constraints.contains_key(&constraint_names[constraint_id]) == false {
+                let constraint_name = constraint_names[constraint_id].clone();
+                let current_constraint_function = self.constraints.get(&constraint_name).unwrap();
+                let start_time = Instant::now();
+                let current_score_vec = current_constraint_function(planning_entity_dfs, problem_fact_dfs, delta_dfs, &mut self.utility_objects);
+                let elapsed_nanoseconds = start_time.elapsed().as_nanos() as u64;
+                self.add_constraint_timing(constraint_name, elapsed_nanoseconds);
+                scores_vec.push(current_score_vec);
+                constraint_id += 1;
+                continue;
+            }
+
+            // usual constraints can change utility objects, so only consecutive parallel ones are evaluated together
+            let mut batch_end_id = constraint_id;
+            while batch_end_id < constraint_names.len() && self.parallel_constraints.contains_key(&constraint_names[batch_end_id]) {
+                batch_end_id += 1;
+            }
+            let batch_constraint_names = constraint_names[constraint_id..batch_end_id].to_vec();
+            let batch_results: Vec<(Vec<ScoreType>, u64)>;
+            match self.parallel_constraints_runner {
+                Some(parallel_constraints_runner) => batch_results = parallel_constraints_runner(self, &batch_constraint_names, planning_entity_dfs, problem_fact_dfs, delta_dfs),
+                None => {
+                    batch_results = batch_constraint_names.iter().map(|constraint_name| {
+                        let current_constraint_function = self.parallel_constraints.get(constraint_name).unwrap();
+                        let start_time = Instant::now();
+                        let current_score_vec = current_constraint_function(planning_entity_dfs, problem_fact_dfs, delta_dfs, &self.utility_objects);
+                        return (current_score_vec, start_time.elapsed().as_nanos() as u64);
+                    }).collect();
+                }
+            }
+            for (constraint_name, (current_score_vec, elapsed_nanoseconds)) in batch_constraint_names.into_iter().zip(batch_results.into_iter()) {
+                self.add_constraint_timing(constraint_name, elapsed_nanoseconds);
+                scores_vec.push(current_score_vec);
+            }
+            constraint_id = batch_end_id;
         }
         
+        let constraints_count = scores_vec.len();
+        if constraints_count == 0 {
+            return Err(GreyjackError::ScoreCalculation("score calculator doesn't contain any constraints".to_string()));
+        }
+        let samples_count = scores_vec[0].len();
+        for i in 0..constraints_count {
+            if scores_vec[i].len() != samples_count {
+                return Err(GreyjackError::ScoreCalculation(format!("constraint \"{}\" returned {} scores, expected {}", constraint_names[i], scores_vec[i].len(), samples_count)));
+            }
+            if self.constraint_weights.contains_key(&constraint_names[i]) == false {
+                return Err(GreyjackError::ScoreCalculation(format!("constraint \"{}\" has no weight", constraint_names[i])));
+            }
+            match self.score_levels {
+                None => (),
+                Some(score_levels) => {
+                    match scores_vec[i].iter().map(|score| score.get_levels_counts()).find(|levels_counts| *levels_counts != Some(score_levels)) {
+                        None => (),
+                        Some(levels_counts) => return Err(GreyjackError::ScoreCalculation(
+                            format!("constraint \"{}\" returned score with (hard, soft) levels {:?}, expected {:?}", constraint_names[i], levels_counts.unwrap(), score_levels)
+                        )),
+                    }
+                }
+            }
+        }
+
+        return Ok((constraint_names, scores_vec));
+    }
+
+    pub fn get_score(
+        &mut self, 
+        planning_entity_dfs: &HashMap<String, DataFrame>, 
+        problem_fact_dfs: &HashMap<String, DataFrame>,
+        delta_dfs: &HashMap<String, DataFrame>,
+    ) -> Result<Vec<ScoreType>, GreyjackError> {
+
+        let (constraint_names, scores_vec) = self.get_constraint_scores(planning_entity_dfs, problem_fact_dfs, delta_dfs)?;
         let constraints_count = scores_vec.len();
         let samples_count = scores_vec[0].len();
         let mut scores:Vec<ScoreType> = Vec::new();
@@ -94,11 +293,108 @@ where ScoreType: ScoreTrait + Clone + AddAssign {
             scores.push(sample_sum_score.clone());
         }
 
-        return scores;
+        return Ok(scores);
         
     }
 
+    pub fn explain_score(
+        &mut self, 
+        planning_entity_dfs: &HashMap<String, DataFrame>, 
+        problem_fact_dfs: &HashMap<String, DataFrame>,
+        delta_dfs: &HashMap<String, DataFrame>,
+    ) -> Result<ScoreExplanation<ScoreType>, GreyjackError> {
+
+        /*
+            Explains the first sample of dataframes (Solver::explain_score() passes a batch of only one solution).
+        */
+        let (constraint_names, scores_vec) = self.get_constraint_scores(planning_entity_dfs, problem_fact_dfs, delta_dfs)?;
+        let mut constraint_explanations: Vec<ConstraintExplanation<ScoreType>> = Vec::new();
+        for i in 0..constraint_names.len() {
+            let constraint_name = &constraint_names[i];
+            let unweighted_score;
+            match scores_vec[i].get(0) {
+                Some(score) => unweighted_score = score.clone(),
+                None => return Err(GreyjackError::ScoreCalculation(format!("constraint \"{}\" returned no scores", constraint_name))),
+            }
+            let matches;
+            match self.constraint_matches.get(constraint_name) {
+                None => matches = None,
+                Some(matches_function) => {
+                    let matches_df = matches_function(planning_entity_dfs, problem_fact_dfs, delta_dfs, &mut self.utility_objects);
+                    matches = Some(ConstraintExplanation::<ScoreType>::matches_to_json(&matches_df));
+                }
+            }
+            let constraint_weight = self.constraint_weights[constraint_name];
+            constraint_explanations.push(ConstraintExplanation::new(constraint_name.clone(), constraint_weight, unweighted_score, matches));
+        }
+
+        return Ok(ScoreExplanation::new(constraint_explanations));
+    }
+
+}
+
+impl<UtilityObjectVariants, ScoreType> IncrementalScoreCalculator<UtilityObjectVariants, ScoreType>
+where 
+    UtilityObjectVariants: Sync,
+    ScoreType: ScoreTrait + Clone + AddAssign + Send {
+
+    /*
+        Opt-in concurrent evaluation of constraints from add_parallel_constraint() on the rayon pool.
+        Useful for models with many heavy constraints, when n_jobs is smaller than CPUs count.
+        Scores are still summed in registration order, so results don't depend on the mode.
+    */
+    pub fn set_parallel_constraints(&mut self, is_enabled: bool) {
+        if is_enabled {
+            self.parallel_constraints_runner = Some(Self::run_parallel_constraints);
+        } else {
+            self.parallel_constraints_runner = None;
+        }
+    }
+
+    fn run_parallel_constraints(&self, constraint_names: &Vec<String>, planning_entity_dfs: &HashMap<String, DataFrame>, problem_fact_dfs: &HashMap<String, DataFrame>, delta_dfs: &HashMap<String, DataFrame>) -> Vec<(Vec<ScoreType>, u64)> {
+
+        let parallel_constraints = &self.parallel_constraints;
+        let utility_objects = &self.utility_objects;
+        let batch_results: Vec<(Vec<ScoreType>, u64)> = 
+        constraint_names.par_iter().map(|constraint_name| {
+            let current_constraint_function = parallel_constraints.get(constraint_name).unwrap();
+            let start_time = Instant::now();
+            let current_score_vec = current_constraint_function(planning_entity_dfs, problem_fact_dfs, delta_dfs, utility_objects);
+            return (current_score_vec, start_time.elapsed().as_nanos() as u64);
+        }).collect();
+
+        return batch_results;
+    }
 }
 
 unsafe impl<UtilityObjectVariants, ScoreType> Send for IncrementalScoreCalculator<UtilityObjectVariants, ScoreType>
-where ScoreType: ScoreTrait + Clone + AddAssign + Send {}
\ No newline at end of file
+where ScoreType: ScoreTrait + Clone + AddAssign + Send {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::score_calculation::scores::SimpleScore;
+
+    #[test]
+    fn test_override_constraint_weights() {
+
+        let mut planning_entity_dfs: HashMap<String, DataFrame> = HashMap::new();
+        planning_entity_dfs.insert("queens".to_string(), df!("row_id" => [1i64, 2]).unwrap());
+        let problem_fact_dfs: HashMap<String, DataFrame> = HashMap::new();
+        let mut delta_dfs: HashMap<String, DataFrame> = HashMap::new();
+        delta_dfs.insert("queens".to_string(), df!("sample_id" => [0u64, 1], "candidate_df_row_id" => [0u64, 1], "row_id" => [3i64, 4]).unwrap());
+
+        let mut score_calculator: IncrementalScoreCalculator<f64, SimpleScore> = IncrementalScoreCalculator::new();
+        score_calculator.add_constraint("changed_rows".to_string(), Box::new(|_, _, delta_dfs, _| vec![SimpleScore::new(1.0); delta_dfs["queens"].height()]));
+        score_calculator.add_parallel_constraint("rows_max".to_string(), Box::new(|_, _, delta_dfs, _| vec![SimpleScore::new(2.0); delta_dfs["queens"].height()]));
+
+        let weight_overrides = HashMap::from([("rows_max".to_string(), 3.0), ("rows_min".to_string(), 1.0)]);
+        assert_eq!(score_calculator.override_constraint_weights(&weight_overrides).err().unwrap().to_string().contains("rows_min"), true);
+        // failed overrides don't change weights
+        assert_eq!(score_calculator.get_constraint_weights(), vec![("changed_rows".to_string(), 1.0), ("rows_max".to_string(), 1.0)]);
+
+        score_calculator.override_constraint_weights(&HashMap::from([("rows_max".to_string(), 3.0)])).unwrap();
+        assert_eq!(score_calculator.get_constraint_weights(), vec![("changed_rows".to_string(), 1.0), ("rows_max".to_string(), 3.0)]);
+        assert_eq!(score_calculator.get_score(&planning_entity_dfs, &problem_fact_dfs, &delta_dfs).unwrap(), vec![SimpleScore::new(1.0 + 6.0); 2]);
+    }
+}
@@ -2,7 +2,11 @@
 pub mod plain_score_calculator;
 pub mod incremental_score_calculator;
 pub mod score_calculator_variants;
+pub mod constraint_timing_stats;
+pub mod prescoring_order;
 
 pub use plain_score_calculator::PlainScoreCalculator;
 pub use incremental_score_calculator::IncrementalScoreCalculator;
-pub use score_calculator_variants::ScoreCalculatorVariants;
\ No newline at end of file
+pub use score_calculator_variants::ScoreCalculatorVariants;
+pub use constraint_timing_stats::ConstraintTimingStats;
+pub use prescoring_order::PrescoringOrder;
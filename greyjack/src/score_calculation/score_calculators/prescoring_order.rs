@@ -0,0 +1,99 @@
+use std::collections::{HashMap, HashSet};
+use crate::errors::GreyjackError;
+
+/*
+    Execution order of prescoring functions.
+    Functions run in registration order, except that a function always runs after all functions
+    it depends on (prescoring functions fill utility objects, which later ones may read).
+    The order is rebuilt only after adding / removing functions.
+*/
+pub struct PrescoringOrder {
+    function_names: Vec<String>,
+    dependencies: HashMap<String, Vec<String>>,
+    execution_order: Option<Vec<String>>,
+}
+
+impl PrescoringOrder {
+
+    pub fn new() -> Self {
+        Self {
+            function_names: Vec::new(),
+            dependencies: HashMap::new(),
+            execution_order: None,
+        }
+    }
+
+    pub fn add(&mut self, function_name: String, dependencies: Vec<String>) {
+        if self.function_names.contains(&function_name) == false {
+            self.function_names.push(function_name.clone());
+        }
+        self.dependencies.insert(function_name, dependencies);
+        self.execution_order = None;
+    }
+
+    pub fn remove(&mut self, function_name: &String) {
+        self.function_names.retain(|name| name != function_name);
+        self.dependencies.remove(function_name);
+        self.execution_order = None;
+    }
+
+    pub fn get_execution_order(&mut self) -> Result<Vec<String>, GreyjackError> {
+
+        match &self.execution_order {
+            Some(execution_order) => return Ok(execution_order.clone()),
+            None => (),
+        }
+
+        for function_name in &self.function_names {
+            for dependency_name in &self.dependencies[function_name] {
+                if self.dependencies.contains_key(dependency_name) == false {
+                    return Err(GreyjackError::ScoreCalculation(format!("prescoring function \"{}\" depends on unknown prescoring function \"{}\"", function_name, dependency_name)));
+                }
+            }
+        }
+
+        // each pass takes the first (in registration order) function with all dependencies done
+        let mut execution_order: Vec<String> = Vec::new();
+        let mut done_functions: HashSet<String> = HashSet::new();
+        while execution_order.len() < self.function_names.len() {
+            let ready_function = self.function_names.iter().find(|name| {
+                done_functions.contains(*name) == false && self.dependencies[*name].iter().all(|dependency_name| done_functions.contains(dependency_name))
+            });
+            match ready_function {
+                Some(function_name) => {
+                    done_functions.insert(function_name.clone());
+                    execution_order.push(function_name.clone());
+                }
+                None => {
+                    let cycled_functions: Vec<String> = self.function_names.iter().filter(|name| done_functions.contains(*name) == false).cloned().collect();
+                    return Err(GreyjackError::ScoreCalculation(format!("prescoring functions have cyclic dependencies: {:?}", cycled_functions)));
+                }
+            }
+        }
+
+        self.execution_order = Some(execution_order.clone());
+        return Ok(execution_order);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prescoring_order() {
+
+        let mut prescoring_order = PrescoringOrder::new();
+        prescoring_order.add("c".to_string(), vec!["b".to_string()]);
+        prescoring_order.add("a".to_string(), vec![]);
+        prescoring_order.add("b".to_string(), vec!["a".to_string()]);
+        prescoring_order.add("d".to_string(), vec![]);
+        assert_eq!(prescoring_order.get_execution_order().unwrap(), vec!["a", "b", "c", "d"]);
+
+        prescoring_order.add("a".to_string(), vec!["c".to_string()]);
+        assert_eq!(prescoring_order.get_execution_order().is_err(), true);
+
+        prescoring_order.remove(&"a".to_string());
+        assert_eq!(prescoring_order.get_execution_order().err().unwrap().to_string().contains("unknown"), true);
+    }
+}
@@ -0,0 +1,81 @@
+
+use crate::score_calculation::scores::ScoreTrait;
+use std::ops::AddAssign;
+use polars::prelude::*;
+use serde::{Serialize, Deserialize};
+use serde_json::{json, Map, Value};
+
+/*
+    Contribution of one named constraint into the score of a solution.
+    matches - optional rows of entities, which violate the constraint (json array of row objects),
+    are filled only for constraints with a registered matches function (add_constraint_matches()).
+*/
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConstraintExplanation<ScoreType>
+where ScoreType: ScoreTrait + Clone + AddAssign {
+    pub constraint_name: String,
+    pub weight: f64,
+    pub unweighted_score: ScoreType,
+    pub weighted_score: ScoreType,
+    pub matches: Option<Value>,
+}
+
+impl<ScoreType> ConstraintExplanation<ScoreType>
+where ScoreType: ScoreTrait + Clone + AddAssign {
+
+    pub fn new(constraint_name: String, weight: f64, unweighted_score: ScoreType, matches: Option<Value>) -> Self {
+        let weighted_score = unweighted_score.mul(weight);
+        Self {
+            constraint_name: constraint_name,
+            weight: weight,
+            unweighted_score: unweighted_score,
+            weighted_score: weighted_score,
+            matches: matches,
+        }
+    }
+
+    pub fn matches_to_json(matches_df: &DataFrame) -> Value {
+
+        let column_names: Vec<String> = matches_df.get_column_names().iter().map(|name| name.to_string()).collect();
+        let mut rows: Vec<Value> = Vec::new();
+        for i in 0..matches_df.height() {
+            let mut row: Map<String, Value> = Map::new();
+            for column_name in &column_names {
+                let cell_value;
+                match matches_df.column(column_name) {
+                    Ok(column) => {
+                        match column.get(i) {
+                            Ok(any_value) => cell_value = Self::any_value_to_json(&any_value),
+                            Err(_) => cell_value = Value::Null,
+                        }
+                    }
+                    Err(_) => cell_value = Value::Null,
+                }
+                row.insert(column_name.clone(), cell_value);
+            }
+            rows.push(Value::Object(row));
+        }
+
+        return Value::Array(rows);
+    }
+
+    fn any_value_to_json(any_value: &AnyValue) -> Value {
+        match any_value {
+            AnyValue::Null => Value::Null,
+            AnyValue::Boolean(x) => json!(x),
+            AnyValue::String(x) => json!(x),
+            AnyValue::StringOwned(x) => json!(x.as_str()),
+            AnyValue::UInt8(x) => json!(x),
+            AnyValue::UInt16(x) => json!(x),
+            AnyValue::UInt32(x) => json!(x),
+            AnyValue::UInt64(x) => json!(x),
+            AnyValue::Int8(x) => json!(x),
+            AnyValue::Int16(x) => json!(x),
+            AnyValue::Int32(x) => json!(x),
+            AnyValue::Int64(x) => json!(x),
+            AnyValue::Float32(x) => json!(x),
+            AnyValue::Float64(x) => json!(x),
+            other => json!(other.to_string()),
+        }
+    }
+}
@@ -0,0 +1,6 @@
+
+pub mod constraint_explanation;
+pub mod score_explanation;
+
+pub use constraint_explanation::ConstraintExplanation;
+pub use score_explanation::ScoreExplanation;
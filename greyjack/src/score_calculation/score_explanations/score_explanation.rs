@@ -0,0 +1,94 @@
+
+use super::ConstraintExplanation;
+use crate::score_calculation::scores::ScoreTrait;
+use crate::errors::GreyjackError;
+use std::ops::AddAssign;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+
+/*
+    Per-constraint breakdown of a solution score, returned by Solver::explain_score().
+    score is the sum of weighted scores of all constraints (the same value, which solver optimizes).
+    Constraints are in registration order, the same order in which calculator evaluates them. to_json() gives json to send via http.
+*/
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScoreExplanation<ScoreType>
+where ScoreType: ScoreTrait + Clone + AddAssign {
+    pub score: ScoreType,
+    pub constraints: Vec<ConstraintExplanation<ScoreType>>,
+}
+
+impl<ScoreType> ScoreExplanation<ScoreType>
+where ScoreType: ScoreTrait + Clone + AddAssign {
+
+    pub fn new(constraints: Vec<ConstraintExplanation<ScoreType>>) -> Self {
+        let mut score = ScoreType::get_null_score();
+        for constraint in &constraints {
+            score += constraint.weighted_score.clone();
+        }
+        Self {
+            score: score,
+            constraints: constraints,
+        }
+    }
+
+    pub fn get_constraint(&self, constraint_name: &str) -> Option<&ConstraintExplanation<ScoreType>> {
+        self.constraints.iter().find(|constraint| constraint.constraint_name == constraint_name)
+    }
+
+}
+
+impl<ScoreType> ScoreExplanation<ScoreType>
+where ScoreType: ScoreTrait + Clone + AddAssign + Serialize {
+
+    pub fn to_json(&self) -> Result<Value, GreyjackError> {
+        let explanation_json = serde_json::to_value(self)?;
+        return Ok(explanation_json);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::score_calculation::score_calculators::PlainScoreCalculator;
+    use crate::score_calculation::scores::SimpleScore;
+    use polars::prelude::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_plain_score_explanation() {
+
+        let mut planning_entity_dfs: HashMap<String, DataFrame> = HashMap::new();
+        planning_entity_dfs.insert("queens".to_string(), df!("sample_id" => [0u64, 0, 0], "row_id" => [1i64, 1, 2]).unwrap());
+        let problem_fact_dfs: HashMap<String, DataFrame> = HashMap::new();
+
+        let mut score_calculator: PlainScoreCalculator<(), SimpleScore> = PlainScoreCalculator::new();
+        score_calculator.add_constraint("same_row".to_string(), Box::new(|planning_entity_dfs, _, _| {
+            let same_row_count = planning_entity_dfs["queens"].clone().lazy().filter(col("row_id").eq(lit(1))).collect().unwrap().height();
+            vec![SimpleScore::new(same_row_count as f64)]
+        }));
+        score_calculator.add_constraint("rows_sum".to_string(), Box::new(|planning_entity_dfs, _, _| {
+            let rows_sum: i64 = planning_entity_dfs["queens"].column("row_id").unwrap().as_materialized_series().sum().unwrap();
+            vec![SimpleScore::new(rows_sum as f64)]
+        }));
+        score_calculator.set_constraint_weights(HashMap::from([("same_row".to_string(), 10.0), ("rows_sum".to_string(), 0.5)]));
+        score_calculator.add_constraint_matches("same_row".to_string(), Box::new(|planning_entity_dfs, _, _| {
+            planning_entity_dfs["queens"].clone().lazy().filter(col("row_id").eq(lit(1))).collect().unwrap()
+        }));
+
+        let score_explanation = score_calculator.explain_score(&planning_entity_dfs, &problem_fact_dfs).unwrap();
+        let score = score_calculator.get_score(&planning_entity_dfs, &problem_fact_dfs).unwrap();
+        assert_eq!(score_explanation.score, score[0]);
+        assert_eq!(score_explanation.score, SimpleScore::new(22.0));
+
+        let same_row = score_explanation.get_constraint("same_row").unwrap();
+        assert_eq!(same_row.unweighted_score, SimpleScore::new(2.0));
+        assert_eq!(same_row.weighted_score, SimpleScore::new(20.0));
+        assert_eq!(same_row.matches.as_ref().unwrap().as_array().unwrap().len(), 2);
+        assert_eq!(score_explanation.get_constraint("rows_sum").unwrap().matches.is_none(), true);
+
+        let explanation_json = score_explanation.to_json().unwrap();
+        assert_eq!(explanation_json["constraints"][0]["constraint_name"], "same_row");
+        assert_eq!(explanation_json["constraints"][0]["matches"][0]["row_id"], 1);
+    }
+}
@@ -7,6 +7,9 @@ use crate::cotwin::CotwinValueTypes::*;
 use crate::variables::PlanningVariablesVariants;
 use crate::score_calculation::scores::ScoreTrait;
 use crate::score_calculation::score_requesters::VariablesManager;
+use crate::score_calculation::score_calculators::ScoreCalculatorVariants;
+use crate::score_calculation::score_explanations::ScoreExplanation;
+use crate::errors::GreyjackError;
 
 use std::ops::{AddAssign, Sub};
 use std:: collections::HashMap;
@@ -36,6 +39,9 @@ where
         pub planning_entity_dfs: HashMap<String, DataFrame>,
         pub problem_fact_dfs: HashMap<String, DataFrame>,
         pub raw_dfs: HashMap<String, DataFrame>,
+
+        // entities (rows of planning dfs), that score calculators see, used by construction heuristics for partial scores
+        pub partial_scoring_mask: Option<HashMap<String, Vec<bool>>>,
 }
 
 impl<EntityVariants, UtilityObjectVariants, ScoreType> 
@@ -44,15 +50,15 @@ where
     ScoreType: ScoreTrait + Clone + AddAssign + Send,
     EntityVariants: CotwinEntityTrait {
 
-        pub fn new(cotwin: Cotwin<EntityVariants, UtilityObjectVariants, ScoreType>) -> Self {
+        pub fn new(cotwin: Cotwin<EntityVariants, UtilityObjectVariants, ScoreType>) -> Result<Self, GreyjackError> {
 
             let (variables_vec, var_name_to_vec_id_map, vec_id_to_var_name_map) = Self::build_variables_info(&cotwin);
-            let variables_manager = VariablesManager::new(variables_vec);
+            let variables_manager = VariablesManager::new(variables_vec)?;
 
-            let planning_entities_column_map = Self::build_column_map(&cotwin.planning_entities);
-            let problem_facts_column_map = Self::build_column_map(&cotwin.problem_facts);
-            let planning_entity_dfs = Self::build_group_dfs(&cotwin.planning_entities, &planning_entities_column_map, true);
-            let problem_fact_dfs = Self::build_group_dfs(&cotwin.problem_facts, &problem_facts_column_map, false);
+            let planning_entities_column_map = Self::build_column_map(&cotwin.planning_entities)?;
+            let problem_facts_column_map = Self::build_column_map(&cotwin.problem_facts)?;
+            let planning_entity_dfs = Self::build_group_dfs(&cotwin.planning_entities, &planning_entities_column_map, true)?;
+            let problem_fact_dfs = Self::build_group_dfs(&cotwin.problem_facts, &problem_facts_column_map, false)?;
             let dfs_for_scoring = planning_entity_dfs.clone();
 
             
@@ -75,11 +81,13 @@ where
                 var_id_to_col_name: Vec::new(),
 
                 cached_sample_id_vectors: HashMap::new(),
-                cached_sample_size: 999_999_999
+                cached_sample_size: 999_999_999,
+
+                partial_scoring_mask: None,
 
             };
 
-            return score_requester;
+            return Ok(score_requester);
         }
 
         fn build_variables_info(cotwin: &Cotwin<EntityVariants, UtilityObjectVariants, ScoreType>) 
@@ -90,10 +98,14 @@ where
             let mut vec_id_to_var_name_map: HashMap<usize, String> = HashMap::new();
 
 
+            // sorted group names give the same variables order (and the same seeded run) for the same cotwin
+            let mut planning_entities_group_names: Vec<&String> = cotwin.planning_entities.keys().collect();
+            planning_entities_group_names.sort();
+
             let mut i:usize = 0;
-            for planning_entities_group_name in cotwin.planning_entities.keys() {
+            for planning_entities_group_name in planning_entities_group_names {
                 let current_planning_entities_group = &cotwin.planning_entities[planning_entities_group_name];
-                for entity in current_planning_entities_group {
+                for (entity_index, entity) in current_planning_entities_group.iter().enumerate() {
                     
                     let mut entity_attributes_map;
                     match entity {
@@ -101,7 +113,8 @@ where
                     }
 
                     for (attribute_name, attribute_value) in entity_attributes_map {
-                        let full_variable_name = planning_entities_group_name.to_owned() + ": " + &i.to_string() + "-->" + &attribute_name;
+                        // "group: entity_index-->column", parsed back by SolutionView
+                        let full_variable_name = planning_entities_group_name.to_owned() + ": " + &entity_index.to_string() + "-->" + &attribute_name;
                         let variable;
                         match attribute_value {
                             GJF(mut float_value) => {
@@ -112,6 +125,18 @@ where
                                 integer_value.set_name(full_variable_name.clone());
                                 variable = PlanningVariablesVariants::GJI(integer_value.clone())
                             },
+                            GJB(mut bool_value) => {
+                                bool_value.set_name(full_variable_name.clone());
+                                variable = PlanningVariablesVariants::GJB(bool_value.clone())
+                            },
+                            GJC(mut categorical_value) => {
+                                categorical_value.set_name(full_variable_name.clone());
+                                variable = PlanningVariablesVariants::GJC(categorical_value.clone())
+                            },
+                            GJL(mut list_value) => {
+                                list_value.set_name(full_variable_name.clone());
+                                variable = PlanningVariablesVariants::GJL(list_value.clone())
+                            },
                             PAV(_) => continue,
                         }
                         
@@ -126,12 +151,15 @@ where
             return (variables_vec, var_name_to_vec_id_map, vec_id_to_var_name_map);
         }
 
-        fn build_column_map(entity_groups: &HashMap<String, Vec<EntityVariants>>) -> HashMap<String, Vec<String>> {
+        fn build_column_map(entity_groups: &HashMap<String, Vec<EntityVariants>>) -> Result<HashMap<String, Vec<String>>, GreyjackError> {
             let mut column_map: HashMap<String, Vec<String>> = HashMap::new();
 
             for group_name in entity_groups.keys() {
                 let mut group_columns: Vec<String> = Vec::new();
                 let entity_objects = entity_groups.get(group_name).unwrap();
+                if entity_objects.len() == 0 {
+                    return Err(GreyjackError::InvalidCotwin(format!("entities group \"{}\" is empty", group_name)));
+                }
                 let sample_object = &entity_objects[0];
                 let entity_field_names = sample_object.to_vec();
                 for name_value in entity_field_names {
@@ -140,10 +168,10 @@ where
                 column_map.insert(group_name.clone(), group_columns);
             }
 
-            return column_map;
+            return Ok(column_map);
         }
     
-        fn build_group_dfs(entity_groups: &HashMap<String, Vec<EntityVariants>>, column_map: &HashMap<String, Vec<String>>, is_planning: bool) -> HashMap<String, DataFrame> {
+        fn build_group_dfs(entity_groups: &HashMap<String, Vec<EntityVariants>>, column_map: &HashMap<String, Vec<String>>, is_planning: bool) -> Result<HashMap<String, DataFrame>, GreyjackError> {
 
             let mut df_map: HashMap<String, DataFrame> = HashMap::new();
 
@@ -170,9 +198,15 @@ where
                         match field_cotwin_value {
                             CotwinValueTypes::GJF(x) => field_polars_value = AnyValue::Null,
                             CotwinValueTypes::GJI(x) => field_polars_value = AnyValue::Null,
+                            CotwinValueTypes::GJB(x) => field_polars_value = AnyValue::Null,
+                            CotwinValueTypes::GJC(x) => field_polars_value = AnyValue::Null,
+                            CotwinValueTypes::GJL(x) => field_polars_value = AnyValue::Null,
                             CotwinValueTypes::PAV(x) => field_polars_value = x.clone()
                         }
-                        entity_fields_data.get_mut(&name_value.0).unwrap().push( field_polars_value );
+                        match entity_fields_data.get_mut(&name_value.0) {
+                            Some(field_data) => field_data.push( field_polars_value ),
+                            None => return Err(GreyjackError::InvalidCotwin(format!("entities of group \"{}\" have different fields (unexpected field \"{}\")", df_name, name_value.0))),
+                        }
                     }
                 }
                 
@@ -190,14 +224,14 @@ where
                     columns_vec.push(sample_id_column);
                 }
 
-                let df = DataFrame::new(columns_vec).unwrap();
+                let df = DataFrame::new(columns_vec)?;
                 df_map.insert(df_name.clone(), df);
             }
 
-            return df_map;
+            return Ok(df_map);
         }
 
-        fn update_dfs_for_scoring(&mut self, group_data_map: &HashMap<String, HashMap<String, Vec<AnyValue>>>, samples_count: usize, add_row_index: bool) {
+        fn update_dfs_for_scoring(&mut self, group_data_map: &HashMap<String, HashMap<String, Vec<AnyValue>>>, samples_count: usize, add_row_index: bool) -> Result<(), GreyjackError> {
 
             for df_name in group_data_map.keys() {
                 let mut current_df = self.planning_entity_dfs[df_name].clone();
@@ -207,24 +241,179 @@ where
                     for i in 0..samples_count {
                         new_df_parts.push(self.raw_dfs[df_name].clone().lazy());
                     }
-                    current_df = concat(new_df_parts, UnionArgs::default()).unwrap().collect().unwrap();
+                    current_df = concat(new_df_parts, UnionArgs::default())?.collect()?;
                 }
 
                 for column_name in group_data_map[df_name].keys() {
-                    current_df.drop_in_place(column_name).unwrap();
+                    current_df.drop_in_place(column_name)?;
                     let updated_column_data = &group_data_map[df_name][column_name];
-                    let updated_column = Series::new(column_name.into(), updated_column_data);
-                    current_df.with_column(updated_column).unwrap();
+                    let updated_column = self.build_planning_series(df_name, column_name, updated_column_data)?;
+                    current_df.with_column(updated_column)?;
                 }
                 current_df.rechunk_mut();
 
                 if add_row_index == true {
-                    current_df = current_df.with_row_index("candidate_df_row_id".into(), None).unwrap();
+                    current_df = current_df.with_row_index("candidate_df_row_id".into(), None)?;
                 }
 
                 self.planning_entity_dfs.insert(df_name.clone(), current_df.clone());
             }
 
+            return Ok(());
+
+        }
+
+        /*
+            Drops rows of entities, that are hidden by the mask (entity's mask is false).
+            Row's entity is taken from candidate_df_row_id (incremental dfs and deltas)
+            or from the row position, plain dfs are samples_count copies of the group's entities.
+        */
+        fn apply_partial_scoring_mask(dfs: &HashMap<String, DataFrame>, partial_scoring_mask: &HashMap<String, Vec<bool>>) -> Result<HashMap<String, DataFrame>, GreyjackError> {
+
+            let mut partial_dfs: HashMap<String, DataFrame> = HashMap::new();
+            for (df_name, df) in dfs {
+                let entities_mask;
+                match partial_scoring_mask.get(df_name) {
+                    Some(x) => entities_mask = x,
+                    None => {
+                        partial_dfs.insert(df_name.clone(), df.clone());
+                        continue;
+                    }
+                }
+
+                let rows_mask: BooleanChunked;
+                match df.column("candidate_df_row_id") {
+                    Ok(row_ids) => rows_mask = row_ids.cast(&DataType::UInt64)?.u64()?.into_no_null_iter().map(|row_id| entities_mask[row_id as usize]).collect(),
+                    Err(_) => rows_mask = (0..df.height()).map(|row_id| entities_mask[row_id % entities_mask.len()]).collect(),
+                }
+                partial_dfs.insert(df_name.clone(), df.filter(&rows_mask)?);
+            }
+
+            return Ok(partial_dfs);
+        }
+
+        // a column of unassigned variables contains only nulls, its type is restored to keep constraints working
+        fn build_planning_series(&self, df_name: &String, column_name: &String, column_data: &Vec<AnyValue>) -> Result<Series, GreyjackError> {
+
+            let series = Series::new(column_name.into(), column_data);
+            if series.dtype() != &DataType::Null {
+                return Ok(series);
+            }
+            match self.df_column_to_var_ids_map.get(&(df_name.clone(), column_name.clone())) {
+                None => return Ok(series),
+                Some(var_ids) => return Ok(series.cast(&self.variables_manager.get_variable_dtype(var_ids[0]))?),
+            }
+        }
+
+        fn update_shadow_variables(&mut self) -> Result<(), GreyjackError> {
+
+            for (df_name, listeners) in self.cotwin.shadow_variables_listeners.iter() {
+                let mut current_df = self.planning_entity_dfs[df_name].clone();
+                for listener in listeners {
+                    let shadow_df = (listener.update_function)(&current_df, &self.problem_fact_dfs);
+                    Self::set_shadow_columns(&mut current_df, &shadow_df, &listener.shadow_variable_names)?;
+                }
+                self.planning_entity_dfs.insert(df_name.clone(), current_df);
+            }
+
+            return Ok(());
+        }
+
+        fn set_shadow_columns(df: &mut DataFrame, shadow_df: &DataFrame, shadow_variable_names: &Vec<String>) -> Result<(), GreyjackError> {
+
+            if shadow_df.height() != df.height() {
+                return Err(GreyjackError::ScoreCalculation(format!("shadow variables listener returned {} rows instead of {}", shadow_df.height(), df.height())));
+            }
+            for shadow_variable_name in shadow_variable_names {
+                df.with_column(shadow_df.column(shadow_variable_name)?.clone())?;
+            }
+
+            return Ok(());
+        }
+
+        /*
+            Applies deltas to the entities in scope of changed ones, recomputes their shadow variables
+            and puts into delta dfs changed entities and entities with changed shadow values.
+        */
+        fn update_shadow_delta_dfs(&mut self, delta_dfs: &mut HashMap<String, DataFrame>) -> Result<(), GreyjackError> {
+
+            for (df_name, listeners) in self.cotwin.shadow_variables_listeners.iter() {
+                if delta_dfs.contains_key(df_name) == false {
+                    continue;
+                }
+
+                let delta_df = &delta_dfs[df_name];
+                let delta_columns: Vec<String> = delta_df.get_column_names().iter().map(|x| x.to_string()).collect();
+                let planning_columns: Vec<&String> = delta_columns.iter().filter(|x| *x != "sample_id" && *x != "candidate_df_row_id").collect();
+                let shadow_variable_names: Vec<String> = listeners.iter().flat_map(|listener| listener.shadow_variable_names.clone()).collect();
+                let candidate_lf = self.planning_entity_dfs[df_name].clone().lazy()
+                    .with_column(col("candidate_df_row_id").cast(DataType::UInt64))
+                    .drop(["sample_id"]);
+                let changed_rows_lf = delta_df.clone().lazy().select([col("sample_id"), col("candidate_df_row_id")]);
+
+                // all listeners of a group share the scope, the first declared one is used
+                let affected_rows_lf: LazyFrame;
+                match listeners.iter().find_map(|listener| listener.scope_column.clone()) {
+                    Some(scope_column) => {
+                        let candidate_scopes_lf = candidate_lf.clone().select([col("candidate_df_row_id"), col(&scope_column)]);
+                        let mut scope_values_lfs = vec![
+                            changed_rows_lf.clone()
+                            .join(candidate_scopes_lf.clone(), [col("candidate_df_row_id")], [col("candidate_df_row_id")], JoinArgs::new(JoinType::Inner))
+                            .select([col("sample_id"), col(&scope_column)])
+                        ];
+                        if delta_columns.contains(&scope_column) {
+                            scope_values_lfs.push(delta_df.clone().lazy().select([col("sample_id"), col(&scope_column)]));
+                        }
+                        let scope_rows_lf = concat(scope_values_lfs, UnionArgs::default())?
+                            .join(candidate_scopes_lf, [col(&scope_column)], [col(&scope_column)], JoinArgs::new(JoinType::Inner))
+                            .select([col("sample_id"), col("candidate_df_row_id")]);
+                        affected_rows_lf = concat([scope_rows_lf, changed_rows_lf], UnionArgs::default())?.unique(None, UniqueKeepStrategy::Any);
+                    }
+                    None => {
+                        let entities_count = self.planning_entity_dfs[df_name].height() as u64;
+                        let mut sample_ids: Vec<u64> = delta_df.column("sample_id")?.u64()?.into_no_null_iter().collect();
+                        sample_ids.sort_unstable();
+                        sample_ids.dedup();
+                        let affected_sample_ids: Vec<u64> = sample_ids.iter().flat_map(|sample_id| vec![*sample_id; entities_count as usize]).collect();
+                        let affected_row_ids: Vec<u64> = sample_ids.iter().flat_map(|_| 0..entities_count).collect();
+                        affected_rows_lf = df!("sample_id" => affected_sample_ids, "candidate_df_row_id" => affected_row_ids)?.lazy();
+                    }
+                }
+
+                let mut changed_values_exprs: Vec<Expr> = vec![col("sample_id"), col("candidate_df_row_id"), lit(true).alias("__is_changed")];
+                planning_columns.iter().for_each(|column_name| changed_values_exprs.push(col(column_name.as_str()).alias(format!("{}__delta", column_name))));
+                let mut scope_lf = affected_rows_lf
+                    .join(candidate_lf, [col("candidate_df_row_id")], [col("candidate_df_row_id")], JoinArgs::new(JoinType::Inner))
+                    .join(delta_df.clone().lazy().select(changed_values_exprs), [col("sample_id"), col("candidate_df_row_id")], [col("sample_id"), col("candidate_df_row_id")], JoinArgs::new(JoinType::Left));
+                for column_name in &planning_columns {
+                    let delta_column_name = format!("{}__delta", column_name);
+                    scope_lf = scope_lf
+                        .with_column(when(col("__is_changed").is_not_null()).then(col(&delta_column_name)).otherwise(col(column_name.as_str())).alias(column_name.as_str()))
+                        .drop([delta_column_name]);
+                }
+                let mut scope_df = scope_lf
+                    .with_column(col("__is_changed").fill_null(lit(false)))
+                    .sort(["sample_id", "candidate_df_row_id"], SortMultipleOptions::default())
+                    .collect()?;
+                let mut is_changed = scope_df.drop_in_place("__is_changed")?.bool()?.clone();
+                let current_shadow_columns: Vec<Column> = shadow_variable_names.iter().map(|x| scope_df.column(x).cloned()).collect::<Result<Vec<Column>, PolarsError>>()?;
+
+                for listener in listeners {
+                    let shadow_df = (listener.update_function)(&scope_df, &self.problem_fact_dfs);
+                    Self::set_shadow_columns(&mut scope_df, &shadow_df, &listener.shadow_variable_names)?;
+                }
+                for current_shadow_column in current_shadow_columns {
+                    let updated_shadow_column = scope_df.column(current_shadow_column.name())?;
+                    is_changed = is_changed | updated_shadow_column.as_materialized_series().not_equal_missing(current_shadow_column.as_materialized_series())?;
+                }
+
+                let mut updated_delta_columns = delta_columns.clone();
+                updated_delta_columns.extend(shadow_variable_names.clone());
+                let updated_delta_df = scope_df.filter(&is_changed)?.select(updated_delta_columns)?;
+                delta_dfs.insert(df_name.clone(), updated_delta_df);
+            }
+
+            return Ok(());
         }
 
         fn get_df_column_name(variable_name: String) -> (String, String) {
@@ -333,7 +522,7 @@ where
 
         }
 
-        pub fn request_score_plain<'a>(&mut self, samples: &Vec<Vec<f64>>) -> Vec<ScoreType>{
+        pub fn request_score_plain<'a>(&mut self, samples: &Vec<Vec<f64>>) -> Result<Vec<ScoreType>, GreyjackError> {
 
             //let start_time = chrono::Utc::now().timestamp_millis();
             let candidates:Vec<Vec<(AnyValue<'a>)>> = samples.iter().map(|x| self.variables_manager.inverse_transform_variables(&x)).collect();
@@ -343,15 +532,22 @@ where
             //println!("build group data map time: {}", chrono::Utc::now().timestamp_millis() - start_time );
             let samples_count = candidates.len();
             //let start_time = chrono::Utc::now().timestamp_millis();
-            self.update_dfs_for_scoring(&group_data_map, samples_count, false);
+            self.update_dfs_for_scoring(&group_data_map, samples_count, false)?;
+            self.update_shadow_variables()?;
             //println!("updatimg dfs time: {}", chrono::Utc::now().timestamp_millis() - start_time );
 
             //let start_time = chrono::Utc::now().timestamp_millis();
-            let score_batch = &self.cotwin.get_score(&self.planning_entity_dfs, &self.problem_fact_dfs, None);
-            let score_batch = score_batch.to_owned();
+            let score_batch;
+            match &self.partial_scoring_mask {
+                None => score_batch = self.cotwin.get_score(&self.planning_entity_dfs, &self.problem_fact_dfs, None)?,
+                Some(partial_scoring_mask) => {
+                    let partial_dfs = Self::apply_partial_scoring_mask(&self.planning_entity_dfs, partial_scoring_mask)?;
+                    score_batch = self.cotwin.get_score(&partial_dfs, &self.problem_fact_dfs, None)?;
+                }
+            }
             //println!("query time: {}", chrono::Utc::now().timestamp_millis() - start_time );
 
-            return score_batch;
+            return Ok(score_batch);
         }
 
         pub fn build_var_id_to_df_column_index_map(&mut self) -> Vec<(String, String, usize)> {
@@ -385,7 +581,7 @@ where
             &mut self, 
             group_data_map: &HashMap<String, HashMap<String, Vec<AnyValue<'_>>>>, 
             inverted_deltas: Vec<Vec<(usize, AnyValue<'_>)>>
-        ) -> HashMap<String, DataFrame> {
+        ) -> Result<HashMap<String, DataFrame>, GreyjackError> {
 
             if self.var_id_to_df_column_index_map.len() == 0 {
                 self.var_id_to_df_column_index_map = self.build_var_id_to_df_column_index_map();
@@ -423,43 +619,76 @@ where
             });
 
             let mut delta_dfs: HashMap<String, DataFrame> = HashMap::new();
-            delta_data_map.keys().into_iter().for_each(|df_name| {
+            for df_name in delta_data_map.keys() {
 
                 let mut current_df = DataFrame::empty();
-                delta_data_map[df_name].keys().into_iter().for_each(|column_name| {
+                for column_name in delta_data_map[df_name].keys() {
 
                     let updated_column_data = &delta_data_map[df_name][column_name];
-                    let updated_column = Series::new(column_name.into(), updated_column_data);
-                    current_df.with_column(updated_column).unwrap();
-                });
-                current_df = current_df.sort(["sample_id", "candidate_df_row_id"], SortMultipleOptions::default()).unwrap();
+                    let updated_column = self.build_planning_series(df_name, column_name, updated_column_data)?;
+                    current_df.with_column(updated_column)?;
+                }
+                current_df = current_df.sort(["sample_id", "candidate_df_row_id"], SortMultipleOptions::default())?;
 
                 delta_dfs.insert(df_name.clone(), current_df);
-            });
+            }
 
-            return delta_dfs;
+            return Ok(delta_dfs);
         }
 
-        pub fn request_score_incremental<'a>(&mut self, sample: &Vec<f64>, deltas: &Vec<Vec<(usize, f64)>>) -> Vec<ScoreType> {
+        pub fn request_score_incremental<'a>(&mut self, sample: &Vec<f64>, deltas: &Vec<Vec<(usize, f64)>>) -> Result<Vec<ScoreType>, GreyjackError> {
 
             //let start_time = chrono::Utc::now().timestamp_millis();
             let candidate: Vec<(AnyValue<'a>)> = self.variables_manager.inverse_transform_variables(&sample);
             let group_data_map = self.build_group_data_map(&vec![candidate; 1], false);
-            self.update_dfs_for_scoring(&group_data_map, 1, true);
+            self.update_dfs_for_scoring(&group_data_map, 1, true)?;
+            self.update_shadow_variables()?;
             //println!("candidate df building time: {}", chrono::Utc::now().timestamp_millis() - start_time );
 
             //let start_time = chrono::Utc::now().timestamp_millis();
             let start_time = chrono::Utc::now().timestamp_millis();
-            let inverted_deltas: Vec<Vec<(usize, AnyValue<'a>)>> = self.variables_manager.inverse_transform_deltas(&deltas);
-            let delta_dfs = self.build_delta_dfs(&group_data_map, inverted_deltas);
+            let inverted_deltas: Vec<Vec<(usize, AnyValue<'a>)>> = self.variables_manager.inverse_transform_deltas(&sample, &deltas);
+            let mut delta_dfs = self.build_delta_dfs(&group_data_map, inverted_deltas)?;
+            self.update_shadow_delta_dfs(&mut delta_dfs)?;
             //println!("deltas df building time: {}", chrono::Utc::now().timestamp_millis() - start_time );
 
             //let start_time = chrono::Utc::now().timestamp_millis();
-            let score_batch = &self.cotwin.get_score(&self.planning_entity_dfs, &self.problem_fact_dfs, Some(&delta_dfs));
-            let score_batch = score_batch.to_owned();
+            let score_batch;
+            match &self.partial_scoring_mask {
+                None => score_batch = self.cotwin.get_score(&self.planning_entity_dfs, &self.problem_fact_dfs, Some(&delta_dfs))?,
+                Some(partial_scoring_mask) => {
+                    let partial_dfs = Self::apply_partial_scoring_mask(&self.planning_entity_dfs, partial_scoring_mask)?;
+                    let partial_delta_dfs = Self::apply_partial_scoring_mask(&delta_dfs, partial_scoring_mask)?;
+                    score_batch = self.cotwin.get_score(&partial_dfs, &self.problem_fact_dfs, Some(&partial_delta_dfs))?;
+                }
+            }
             //println!("scoring time: {}", chrono::Utc::now().timestamp_millis() - start_time );
 
-            return score_batch;
+            return Ok(score_batch);
+        }
+
+        pub fn explain_score<'a>(&mut self, sample: &Vec<f64>) -> Result<ScoreExplanation<ScoreType>, GreyjackError> {
+
+            let candidate: Vec<(AnyValue<'a>)> = self.variables_manager.inverse_transform_variables(&sample);
+            match &self.cotwin.score_calculator {
+                ScoreCalculatorVariants::ISC(_) => {
+                    // the whole solution is passed as deltas, the same way as agents score their initial population
+                    let group_data_map = self.build_group_data_map(&vec![candidate; 1], false);
+                    self.update_dfs_for_scoring(&group_data_map, 1, true)?;
+                    self.update_shadow_variables()?;
+                    let deltas: Vec<Vec<(usize, f64)>> = vec![sample.iter().enumerate().map(|(i, value)| (i, *value)).collect()];
+                    let inverted_deltas: Vec<Vec<(usize, AnyValue<'a>)>> = self.variables_manager.inverse_transform_deltas(&sample, &deltas);
+                    let mut delta_dfs = self.build_delta_dfs(&group_data_map, inverted_deltas)?;
+                    self.update_shadow_delta_dfs(&mut delta_dfs)?;
+                    return self.cotwin.explain_score(&self.planning_entity_dfs, &self.problem_fact_dfs, Some(&delta_dfs));
+                }
+                _ => {
+                    let group_data_map = self.build_group_data_map(&vec![candidate; 1], true);
+                    self.update_dfs_for_scoring(&group_data_map, 1, false)?;
+                    self.update_shadow_variables()?;
+                    return self.cotwin.explain_score(&self.planning_entity_dfs, &self.problem_fact_dfs, None);
+                }
+            }
         }
 
     }
@@ -467,4 +696,200 @@ where
 unsafe impl<EntityVariants, UtilityObjectVariants, ScoreType> Send for OOPScoreRequester<EntityVariants, UtilityObjectVariants, ScoreType>
 where 
     ScoreType: ScoreTrait + Clone + AddAssign + Send,
-    EntityVariants: CotwinEntityTrait {}
\ No newline at end of file
+    EntityVariants: CotwinEntityTrait {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::metaheuristic_bases::Mover;
+    use crate::score_calculation::score_calculators::{IncrementalScoreCalculator, PlainScoreCalculator};
+    use crate::score_calculation::scores::SimpleScore;
+    use crate::variables::{GJInteger, GJList};
+    use crate::cotwin::ShadowVariablesListener;
+
+    struct CotVehicle {
+        vehicle_id: i64,
+        stops: GJList,
+    }
+
+    impl CotwinEntityTrait for CotVehicle {
+        fn to_vec(&self) -> Vec<(String, CotwinValueTypes)> {
+            vec![
+                ("vehicle_id".to_string(), PAV(AnyValue::Int64(self.vehicle_id))),
+                ("stops".to_string(), GJL(self.stops.clone())),
+            ]
+        }
+    }
+
+    // sum of vehicle_id * stop for each sample of df, delta dfs contain only planning columns, so vehicle is got by row id there
+    fn weighted_stops_sum(df: &DataFrame) -> Vec<SimpleScore> {
+        let sample_ids: Vec<usize> = df["sample_id"].u64().unwrap().into_no_null_iter().map(|x| x as usize).collect();
+        let vehicle_ids: Vec<i64>;
+        match df.column("vehicle_id") {
+            Ok(vehicle_id_column) => vehicle_ids = vehicle_id_column.i64().unwrap().into_no_null_iter().collect(),
+            Err(_) => vehicle_ids = df["candidate_df_row_id"].u64().unwrap().into_no_null_iter().map(|x| x as i64).collect(),
+        }
+        let stops_sums: Vec<i64> = df["stops"].list().unwrap().into_iter().map(|stops| stops.unwrap().i64().unwrap().sum().unwrap_or(0)).collect();
+
+        let mut scores = vec![SimpleScore::new(0.0); sample_ids.iter().max().unwrap() + 1];
+        (0..sample_ids.len()).for_each(|i| scores[sample_ids[i]] += SimpleScore::new((vehicle_ids[i] * stops_sums[i]) as f64));
+        return scores;
+    }
+
+    fn expected_score(variables_manager: &VariablesManager, candidate: &Vec<f64>, list_ids: Vec<usize>) -> SimpleScore {
+        let weighted_sum: usize = list_ids.iter().map(|i| i * variables_manager.get_list_element_ids(candidate, *i).iter().map(|e| e + 1).sum::<usize>()).sum();
+        return SimpleScore::new(weighted_sum as f64);
+    }
+
+    fn build_cotwin(incremental: bool) -> Cotwin<CotVehicle, (), SimpleScore> {
+        let vehicles: Vec<CotVehicle> = (0..3).into_iter().map(|i| CotVehicle {
+            vehicle_id: i,
+            stops: GJList::new(None, (1..=8).collect(), "routes", false, None),
+        }).collect();
+
+        let mut cotwin = Cotwin::new();
+        cotwin.add_planning_entities("vehicles".to_string(), vehicles);
+        if incremental {
+            let mut score_calculator: IncrementalScoreCalculator<(), SimpleScore> = IncrementalScoreCalculator::new();
+            score_calculator.add_constraint("changed_stops".to_string(), Box::new(|_, _, delta_dfs, _| weighted_stops_sum(&delta_dfs["vehicles"])));
+            cotwin.add_score_calculator(ScoreCalculatorVariants::ISC(score_calculator));
+        } else {
+            let mut score_calculator: PlainScoreCalculator<(), SimpleScore> = PlainScoreCalculator::new();
+            score_calculator.add_constraint("stops".to_string(), Box::new(|planning_entity_dfs, _, _| weighted_stops_sum(&planning_entity_dfs["vehicles"])));
+            cotwin.add_score_calculator(ScoreCalculatorVariants::PSC(score_calculator));
+        }
+        return cotwin;
+    }
+
+    #[test]
+    fn test_list_variables_scoring() {
+        let mut mover = Mover::new(0.0, HashMap::new(), HashMap::new(), HashMap::new(), HashMap::new(), None).unwrap();
+        mover.set_random_seed(42);
+
+        let mut score_requester = OOPScoreRequester::new(build_cotwin(false)).unwrap();
+        score_requester.variables_manager.set_random_seed(42);
+        let sample = score_requester.variables_manager.sample_variables();
+        let variables_manager = &score_requester.variables_manager;
+
+        let mut samples: Vec<Vec<f64>> = vec![sample.clone()];
+        let mut expected_scores = vec![expected_score(variables_manager, &sample, vec![0, 1, 2])];
+        for _ in 0..5 {
+            let (candidate, _, _) = mover.do_move(&sample, variables_manager, false).unwrap();
+            let candidate = candidate.unwrap();
+            expected_scores.push(expected_score(variables_manager, &candidate, vec![0, 1, 2]));
+            samples.push(candidate);
+        }
+        let mut score_requester = score_requester;
+        assert_eq!(score_requester.request_score_plain(&samples).unwrap(), expected_scores);
+
+        let mut score_requester = OOPScoreRequester::new(build_cotwin(true)).unwrap();
+        let variables_manager = &score_requester.variables_manager;
+        let mut deltas: Vec<Vec<(usize, f64)>> = Vec::new();
+        let mut expected_scores = Vec::new();
+        for _ in 0..5 {
+            let (_, changed_columns, candidate_deltas) = mover.do_move(&sample, variables_manager, true).unwrap();
+            let current_deltas: Vec<(usize, f64)> = changed_columns.unwrap().into_iter().zip(candidate_deltas.unwrap().into_iter()).collect();
+            let mut candidate = sample.clone();
+            current_deltas.iter().for_each(|(column_id, value)| candidate[*column_id] = *value);
+            let mut changed_list_ids: Vec<usize> = current_deltas.iter().map(|(column_id, _)| variables_manager.column_variable_ids[*column_id]).collect();
+            changed_list_ids.dedup();
+            expected_scores.push(expected_score(variables_manager, &candidate, changed_list_ids));
+            deltas.push(current_deltas);
+        }
+        let mut score_requester = score_requester;
+        assert_eq!(score_requester.request_score_incremental(&sample, &deltas).unwrap(), expected_scores);
+    }
+
+    struct CotStop {
+        demand: i64,
+        vehicle_id: GJInteger,
+    }
+
+    impl CotwinEntityTrait for CotStop {
+        fn to_vec(&self) -> Vec<(String, CotwinValueTypes)> {
+            vec![
+                ("demand".to_string(), PAV(AnyValue::Int64(self.demand))),
+                ("vehicle_id".to_string(), GJI(self.vehicle_id.clone())),
+            ]
+        }
+    }
+
+    fn compute_vehicle_loads(stops_df: &DataFrame, _: &HashMap<String, DataFrame>) -> DataFrame {
+        let sample_ids: Vec<u64> = stops_df["sample_id"].u64().unwrap().into_no_null_iter().collect();
+        let vehicle_ids: Vec<i64> = stops_df["vehicle_id"].i64().unwrap().into_no_null_iter().collect();
+        let demands: Vec<i64> = stops_df["demand"].i64().unwrap().into_no_null_iter().collect();
+        let mut loads: HashMap<(u64, i64), i64> = HashMap::new();
+        (0..sample_ids.len()).for_each(|i| *loads.entry((sample_ids[i], vehicle_ids[i])).or_insert(0) += demands[i]);
+        let vehicle_loads: Vec<i64> = (0..sample_ids.len()).map(|i| loads[&(sample_ids[i], vehicle_ids[i])]).collect();
+        return df!("vehicle_load" => vehicle_loads).unwrap();
+    }
+
+    // sum of vehicle loads of stops in delta dfs, the score shows, which stops were recomputed
+    fn build_stops_cotwin(demands: &Vec<i64>, vehicle_ids: Vec<Option<i64>>) -> Cotwin<CotStop, (), SimpleScore> {
+        let stops: Vec<CotStop> = demands.iter().zip(vehicle_ids.into_iter())
+            .map(|(demand, vehicle_id)| CotStop {demand: *demand, vehicle_id: GJInteger::new(vehicle_id, 0, 2, false, None)})
+            .collect();
+        let mut cotwin: Cotwin<CotStop, (), SimpleScore> = Cotwin::new();
+        cotwin.add_planning_entities("stops".to_string(), stops);
+        cotwin.add_shadow_variables_listener("stops".to_string(), ShadowVariablesListener::new(
+            vec!["vehicle_load".to_string()], Some("vehicle_id".to_string()), Box::new(compute_vehicle_loads)));
+        let mut score_calculator: IncrementalScoreCalculator<(), SimpleScore> = IncrementalScoreCalculator::new();
+        score_calculator.add_constraint("changed_loads".to_string(), Box::new(|_, _, delta_dfs, _| {
+            let sample_ids: Vec<usize> = delta_dfs["stops"]["sample_id"].u64().unwrap().into_no_null_iter().map(|x| x as usize).collect();
+            let vehicle_loads: Vec<i64> = delta_dfs["stops"]["vehicle_load"].i64().unwrap().into_no_null_iter().collect();
+            let mut scores = vec![SimpleScore::new(0.0); sample_ids.iter().max().unwrap() + 1];
+            (0..sample_ids.len()).for_each(|i| scores[sample_ids[i]] += SimpleScore::new(vehicle_loads[i] as f64));
+            scores
+        }));
+        cotwin.add_score_calculator(ScoreCalculatorVariants::ISC(score_calculator));
+        return cotwin;
+    }
+
+    #[test]
+    fn test_shadow_variables() {
+        let demands: Vec<i64> = vec![1, 2, 4, 8, 16, 32];
+        let cotwin = build_stops_cotwin(&demands, vec![None; 6]);
+
+        let mut score_requester = OOPScoreRequester::new(cotwin).unwrap();
+        score_requester.variables_manager.set_random_seed(42);
+        let sample = score_requester.variables_manager.sample_variables();
+        let get_loads = |vehicle_ids: &Vec<f64>| -> Vec<i64> {
+            (0..3).into_iter().map(|v| vehicle_ids.iter().zip(demands.iter()).filter(|(x, _)| **x == v as f64).map(|(_, d)| *d).sum()).collect()
+        };
+
+        let deltas: Vec<Vec<(usize, f64)>> = vec![vec![(0, (sample[0] + 1.0) % 3.0)], vec![(2, (sample[2] + 2.0) % 3.0), (5, (sample[5] + 1.0) % 3.0)]];
+        let expected_scores: Vec<SimpleScore> = deltas.iter().map(|current_deltas| {
+            let mut candidate = sample.clone();
+            current_deltas.iter().for_each(|(i, x)| candidate[*i] = *x);
+            let (current_loads, new_loads) = (get_loads(&sample), get_loads(&candidate));
+            let changed_loads_sum: i64 = (0..candidate.len())
+                .filter(|i| current_deltas.iter().any(|(j, _)| i == j) || current_loads[candidate[*i] as usize] != new_loads[candidate[*i] as usize])
+                .map(|i| new_loads[candidate[i] as usize])
+                .sum();
+            SimpleScore::new(changed_loads_sum as f64)
+        }).collect();
+        assert_eq!(score_requester.request_score_incremental(&sample, &deltas).unwrap(), expected_scores);
+
+        let expected_loads: Vec<i64> = sample.iter().map(|x| get_loads(&sample)[*x as usize]).collect();
+        let candidate_loads: Vec<i64> = score_requester.planning_entity_dfs["stops"]["vehicle_load"].i64().unwrap().into_no_null_iter().collect();
+        assert_eq!(candidate_loads, expected_loads);
+    }
+
+    #[test]
+    fn test_shadow_variables_scope_change() {
+
+        // loads of vehicles: 0 -> 1 + 2, 1 -> 4 + 8, 2 -> 16 + 32
+        let demands: Vec<i64> = vec![1, 2, 4, 8, 16, 32];
+        let cotwin = build_stops_cotwin(&demands, vec![Some(0), Some(0), Some(1), Some(1), Some(2), Some(2)]);
+        let mut score_requester = OOPScoreRequester::new(cotwin).unwrap();
+        let sample = score_requester.variables_manager.sample_variables();
+        assert_eq!(sample, vec![0.0, 0.0, 1.0, 1.0, 2.0, 2.0]);
+
+        // stop 0 moves from vehicle 0 to 1, then stop 5 moves from vehicle 2 to 0
+        let deltas: Vec<Vec<(usize, f64)>> = vec![vec![(0, 1.0)], vec![(5, 0.0)]];
+        let scores = score_requester.request_score_incremental(&sample, &deltas).unwrap();
+
+        // moved stop and stops of both the old and the new vehicle are recomputed, others stay out of deltas
+        assert_eq!(scores[0], SimpleScore::new((13 + 2 + 13 + 13) as f64));
+        assert_eq!(scores[1], SimpleScore::new((35 + 35 + 35 + 16) as f64));
+    }
+}
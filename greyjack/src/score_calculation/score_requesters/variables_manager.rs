@@ -1,17 +1,22 @@
 
 
 use crate::{agents::base::Individual, variables::PlanningVariablesVariants};
+use crate::variables::GJList;
 use crate::variables::PlanningVariablesVariants::*;
+use crate::errors::GreyjackError;
 use polars::prelude::*;
 use std::collections::HashMap;
 
-use rand::SeedableRng;
+use rand::{seq::SliceRandom, Rng, SeedableRng};
 use rand::rngs::StdRng;
 use rand_distr::{Distribution, Uniform};
 
 pub struct VariablesManager {
     variables_vec: Vec<PlanningVariablesVariants>,
+    // columns of a sample, list variables take max_length columns each
     pub variables_count: usize,
+    pub variable_column_ranges: Vec<(usize, usize)>,
+    pub column_variable_ids: Vec<usize>,
     pub variable_ids: Vec<usize>,
     pub lower_bounds: Vec<f64>,
     pub upper_bounds: Vec<f64>,
@@ -19,37 +24,103 @@ pub struct VariablesManager {
     pub semantic_groups_map: HashMap<String, Vec<usize>>,
     pub semantic_group_keys: Vec<String>,
     pub n_semantic_groups: usize,
-    pub discrete_ids: Option<Vec<usize>>
+    pub discrete_ids: Option<Vec<usize>>,
+
+    pub list_groups_map: HashMap<String, Vec<usize>>,
+    pub list_group_keys: Vec<String>,
+    pub n_list_groups: usize,
 }
 
 impl VariablesManager {
     
-    pub fn new(variables_vec: Vec<PlanningVariablesVariants>) -> Self {
+    pub fn new(variables_vec: Vec<PlanningVariablesVariants>) -> Result<Self, GreyjackError> {
 
         let mut variable_ids: Vec<usize> = Vec::new();
         let mut lower_bounds: Vec<f64> = Vec::new();
         let mut upper_bounds: Vec<f64> = Vec::new();
         let mut discrete_ids: Vec<usize> = Vec::new();
+        let mut variable_column_ranges: Vec<(usize, usize)> = Vec::new();
+        let mut column_variable_ids: Vec<usize> = Vec::new();
 
-        let variables_count = variables_vec.len();
-        for i in 0..variables_count {
-            variable_ids.push(i);
+        if variables_vec.len() == 0 {
+            return Err(GreyjackError::InvalidCotwin("cotwin doesn't contain any planning variables".to_string()));
+        }
+        for i in 0..variables_vec.len() {
             let current_variable = variables_vec.get(i).unwrap();
+            let column_start = column_variable_ids.len();
+            let (variable_name, lower_bound, upper_bound, frozen, is_initialized, columns_count);
             match current_variable {
                 PlanningVariablesVariants::GJF(x) => {
-                    lower_bounds.push(x.lower_bound);
-                    upper_bounds.push(x.upper_bound);
+                    (variable_name, lower_bound, upper_bound, frozen, is_initialized, columns_count) = (&x.name, x.lower_bound, x.upper_bound, x.frozen, x.initial_value.is_some(), 1);
                 }
                 PlanningVariablesVariants::GJI(x) => {
-                    lower_bounds.push(x.lower_bound);
-                    upper_bounds.push(x.upper_bound);
-                    discrete_ids.push(i);
+                    (variable_name, lower_bound, upper_bound, frozen, is_initialized, columns_count) = (&x.name, x.lower_bound, x.upper_bound, x.frozen, x.initial_value.is_some(), 1);
+                    discrete_ids.push(column_start);
+                    if let Some(allowed_values) = &x.allowed_values {
+                        if allowed_values.len() == 0 {
+                            return Err(GreyjackError::InvalidVariable(format!("{}: allowed values must not be empty", variable_name)));
+                        }
+                        let out_of_bounds_values = x.get_out_of_bounds_allowed_values();
+                        if out_of_bounds_values.len() > 0 {
+                            return Err(GreyjackError::InvalidVariable(format!("{}: allowed values {:?} are outside bounds [{}, {}]", variable_name, out_of_bounds_values, lower_bound, upper_bound)));
+                        }
+                        if x.initial_value.is_some() && x.is_allowed_value(x.initial_value.unwrap()) == false {
+                            return Err(GreyjackError::InvalidVariable(format!("{}: initial value {} isn't allowed", variable_name, x.initial_value.unwrap())));
+                        }
+                    }
+                    if x.allow_unassigned && (x.unassigned_probability < 0.0 || x.unassigned_probability > 1.0) {
+                        return Err(GreyjackError::InvalidVariable(format!("{}: unassigned probability must be in [0.0, 1.0], got {}", variable_name, x.unassigned_probability)));
+                    }
+                }
+                PlanningVariablesVariants::GJB(x) => {
+                    (variable_name, lower_bound, upper_bound, frozen, is_initialized, columns_count) = (&x.name, x.lower_bound, x.upper_bound, x.frozen, x.initial_value.is_some(), 1);
+                    discrete_ids.push(column_start);
+                }
+                PlanningVariablesVariants::GJC(x) => {
+                    (variable_name, lower_bound, upper_bound, frozen, is_initialized, columns_count) = (&x.name, x.lower_bound, x.upper_bound, x.frozen, x.initial_value.is_some(), 1);
+                    discrete_ids.push(column_start);
+                    if x.categories.len() == 0 {
+                        return Err(GreyjackError::InvalidVariable(format!("{}: categorical variable must have at least one category", variable_name)));
+                    }
+                    if x.initial_category.is_some() && x.initial_value.is_none() {
+                        return Err(GreyjackError::InvalidVariable(format!("{}: initial value {:?} isn't one of the categories", variable_name, x.initial_category.as_ref().unwrap())));
+                    }
+                }
+                PlanningVariablesVariants::GJL(x) => {
+                    (variable_name, lower_bound, upper_bound, frozen, is_initialized, columns_count) = (&x.name, -1.0, x.elements.len() as f64 - 1.0, x.frozen, x.initial_value.is_some(), x.max_length);
+                    (column_start..(column_start + columns_count)).for_each(|column_id| discrete_ids.push(column_id));
                 }
             }
+
+            if lower_bound > upper_bound {
+                return Err(GreyjackError::InvalidVariable(format!("{}: lower bound {} is greater than upper bound {}", variable_name, lower_bound, upper_bound)));
+            }
+            if frozen && is_initialized == false {
+                return Err(GreyjackError::InvalidVariable(format!("{}: frozen value must be initialized", variable_name)));
+            }
+            for _ in 0..columns_count {
+                variable_ids.push(column_variable_ids.len());
+                column_variable_ids.push(i);
+                lower_bounds.push(lower_bound);
+                upper_bounds.push(upper_bound);
+            }
+            variable_column_ranges.push((column_start, columns_count));
+        }
+
+        let variables_count = column_variable_ids.len();
+        if variables_count == 0 {
+            return Err(GreyjackError::InvalidCotwin("cotwin doesn't contain any planning variables".to_string()));
         }
 
-        let semantic_groups_dict = Self::build_semantic_groups_dict(&variables_vec);
-        let semantic_group_keys: Vec<String> = semantic_groups_dict.keys().into_vec().iter().map(|x| x.to_string()).collect();
+        let list_groups_map = Self::build_list_groups_map(&variables_vec)?;
+        let mut list_group_keys: Vec<String> = list_groups_map.keys().map(|x| x.to_string()).collect();
+        list_group_keys.sort();
+        let n_list_groups = list_group_keys.len();
+
+        let semantic_groups_dict = Self::build_semantic_groups_dict(&variables_vec, &variable_column_ranges);
+        let mut semantic_group_keys: Vec<String> = semantic_groups_dict.keys().into_vec().iter().map(|x| x.to_string()).collect();
+        // HashMap keys order differs from run to run, random group choice must not depend on it
+        semantic_group_keys.sort();
         let n_semantic_groups = semantic_group_keys.len();
         let discrete_ids_option;
         if discrete_ids.len() != 0 {
@@ -58,9 +129,11 @@ impl VariablesManager {
             discrete_ids_option = None;
         }
 
-        Self {
+        Ok(Self {
             variables_vec: variables_vec,
             variables_count: variables_count,
+            variable_column_ranges: variable_column_ranges,
+            column_variable_ids: column_variable_ids,
             variable_ids: variable_ids,
             lower_bounds: lower_bounds,
             upper_bounds: upper_bounds,
@@ -68,12 +141,96 @@ impl VariablesManager {
             semantic_groups_map: semantic_groups_dict,
             semantic_group_keys: semantic_group_keys,
             n_semantic_groups: n_semantic_groups,
-            discrete_ids: discrete_ids_option
+            discrete_ids: discrete_ids_option,
+
+            list_groups_map: list_groups_map,
+            list_group_keys: list_group_keys,
+            n_list_groups: n_list_groups,
+        })
+
+    }
+
+    fn build_list_groups_map(variables_vec: &Vec<PlanningVariablesVariants>) -> Result<HashMap<String, Vec<usize>>, GreyjackError> {
+
+        let mut list_groups_map: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, variable) in variables_vec.iter().enumerate() {
+            if let GJL(x) = variable {
+                if list_groups_map.contains_key(&x.list_group) == false {
+                    list_groups_map.insert(x.list_group.clone(), Vec::new());
+                }
+                list_groups_map.get_mut(&x.list_group).unwrap().push(i);
+            }
+        }
+
+        /*
+            Every element of a group must be planned into exactly one list,
+            so lists of a group must share elements, initial lists mustn't intersect
+            and there must be enough free places for elements without initial list.
+        */
+        for (group_name, list_ids) in list_groups_map.iter() {
+            let first_list = Self::as_list(&variables_vec[list_ids[0]]);
+            let mut seen_elements: Vec<bool> = vec![false; first_list.elements.len()];
+            let mut free_places_count: usize = 0;
+            let mut assigned_count: usize = 0;
+            for list_id in list_ids {
+                let current_list = Self::as_list(&variables_vec[*list_id]);
+                if current_list.elements != first_list.elements {
+                    return Err(GreyjackError::InvalidVariable(format!("{}: all lists of group \"{}\" must have the same elements", current_list.name, group_name)));
+                }
+                if let Some(initial_list) = &current_list.initial_value {
+                    if initial_list.len() > current_list.max_length {
+                        return Err(GreyjackError::InvalidVariable(format!("{}: initial list is longer than max_length {}", current_list.name, current_list.max_length)));
+                    }
+                    for element in initial_list {
+                        match current_list.get_element_id(*element) {
+                            None => return Err(GreyjackError::InvalidVariable(format!("{}: initial element {} isn't an element of group \"{}\"", current_list.name, element, group_name))),
+                            Some(element_id) => {
+                                if seen_elements[element_id] {
+                                    return Err(GreyjackError::InvalidVariable(format!("{}: element {} is planned twice in group \"{}\"", current_list.name, element, group_name)));
+                                }
+                                seen_elements[element_id] = true;
+                                assigned_count += 1;
+                            }
+                        }
+                    }
+                }
+                if current_list.frozen == false {
+                    free_places_count += current_list.max_length - current_list.get_initial_element_ids().len();
+                }
+            }
+            if first_list.elements.len() - assigned_count > free_places_count {
+                return Err(GreyjackError::InvalidVariable(format!("lists of group \"{}\" can't hold all {} elements, increase max_length", group_name, first_list.elements.len())));
+            }
+        }
+
+        return Ok(list_groups_map);
+    }
+
+    fn as_list(variable: &PlanningVariablesVariants) -> &GJList {
+        match variable {
+            GJL(x) => return x,
+            _ => panic!("Variable isn't a list"),
         }
+    }
+
+    pub fn get_list(&self, variable_id: usize) -> &GJList {
+        return Self::as_list(&self.variables_vec[variable_id]);
+    }
 
+    pub fn get_list_element_ids(&self, values_array: &Vec<f64>, variable_id: usize) -> Vec<usize> {
+        let (column_start, columns_count) = self.variable_column_ranges[variable_id];
+        return self.get_list(variable_id).get_element_ids(&values_array[column_start..(column_start + columns_count)]);
     }
 
-    fn build_semantic_groups_dict(variables_vec: &Vec<PlanningVariablesVariants>) -> HashMap<String, Vec<usize>> {
+    pub fn get_random_list_group_ids(&self, random_generator: &mut StdRng) -> (&Vec<usize>, &String) {
+        let random_group_id = Uniform::new(0, self.n_list_groups).sample(random_generator);
+        let group_name = &self.list_group_keys[random_group_id];
+        let group_ids = self.list_groups_map.get(group_name).unwrap();
+        return (group_ids, group_name);
+    }
+
+    // groups keep column ids of variables (not variable ids), because lists before them take several columns
+    fn build_semantic_groups_dict(variables_vec: &Vec<PlanningVariablesVariants>, variable_column_ranges: &Vec<(usize, usize)>) -> HashMap<String, Vec<usize>> {
 
         let mut semantic_groups_dict: HashMap<String, Vec<usize>> = HashMap::new();
         for i in 0..variables_vec.len() {
@@ -89,6 +246,16 @@ impl VariablesManager {
                     variable_semantic_groups = &x.semantic_groups;
                     is_frozen_variable = x.frozen;
                 },
+                GJB(x) => {
+                    variable_semantic_groups = &x.semantic_groups;
+                    is_frozen_variable = x.frozen;
+                },
+                GJC(x) => {
+                    variable_semantic_groups = &x.semantic_groups;
+                    is_frozen_variable = x.frozen;
+                },
+                // lists are changed only by list moves, see Mover::list_move
+                GJL(_) => continue,
             }
 
             for group_name in variable_semantic_groups {
@@ -98,88 +265,309 @@ impl VariablesManager {
                 if is_frozen_variable {
                     continue;
                 }
-                semantic_groups_dict.get_mut(group_name).unwrap().push(i);
+                semantic_groups_dict.get_mut(group_name).unwrap().push(variable_column_ranges[i].0);
             }
         }
 
         return semantic_groups_dict;
     }
 
-    pub fn get_random_semantic_group_ids(&self) -> (&Vec<usize>, &String) {
-        let random_group_id = Uniform::new(0, self.n_semantic_groups).sample(&mut StdRng::from_entropy());
+    pub fn get_random_semantic_group_ids(&self, random_generator: &mut StdRng) -> (&Vec<usize>, &String) {
+        let random_group_id = Uniform::new(0, self.n_semantic_groups).sample(random_generator);
         let group_name = &self.semantic_group_keys[random_group_id];
         let group_ids = self.semantic_groups_map.get(group_name).unwrap();
         return (group_ids, group_name);
     }
 
-    pub fn get_column_random_value(&self, column_id: usize) -> f64{
-        Uniform::new(self.lower_bounds[column_id], self.upper_bounds[column_id]).sample(&mut StdRng::from_entropy())
+    pub fn get_column_random_value(&self, column_id: usize, random_generator: &mut StdRng) -> f64{
+        if let GJI(x) = &self.variables_vec[self.column_variable_ids[column_id]] {
+            if let Some(allowed_value) = x.get_random_value(random_generator) {
+                return allowed_value;
+            }
+        }
+        Uniform::new_inclusive(self.lower_bounds[column_id], self.upper_bounds[column_id]).sample(random_generator)
+    }
+
+    // value for change move: bools and categories always get another value, numeric variables get a random one
+    pub fn get_column_changed_value(&self, column_id: usize, current_value: f64, random_generator: &mut StdRng) -> f64 {
+        match &self.variables_vec[self.column_variable_ids[column_id]] {
+            GJB(x) => return x.get_changed_value(current_value),
+            GJC(x) => return x.get_changed_value(current_value, random_generator),
+            _ => return self.get_column_random_value(column_id, random_generator),
+        }
+    }
+
+    // variables, that construction heuristics assign: not frozen, without initial value, not lists
+    pub fn is_constructable(&self, variable_id: usize) -> bool {
+        match &self.variables_vec[variable_id] {
+            GJF(x) => return x.frozen == false && x.initial_value.is_none(),
+            GJI(x) => return x.frozen == false && x.initial_value.is_none(),
+            GJB(x) => return x.frozen == false && x.initial_value.is_none(),
+            GJC(x) => return x.frozen == false && x.initial_value.is_none(),
+            GJL(_) => return false,
+        }
+    }
+
+    /*
+        Values, that construction heuristics try for the column.
+        Discrete variables give all their values (and the unassigned one, if it's allowed),
+        floats give an evenly spaced grid between bounds. If there are more than max_values_count
+        values, max_values_count evenly spaced ones are taken (bounds are always included).
+    */
+    pub fn get_column_candidate_values(&self, column_id: usize, max_values_count: usize) -> Vec<f64> {
+
+        let max_values_count = max_values_count.max(1);
+        let (lower_bound, upper_bound) = (self.lower_bounds[column_id], self.upper_bounds[column_id]);
+        let mut candidate_values: Vec<f64>;
+        match &self.variables_vec[self.column_variable_ids[column_id]] {
+            GJF(_) => {
+                if max_values_count == 1 || lower_bound == upper_bound {
+                    return vec![(lower_bound + upper_bound) / 2.0];
+                }
+                let step = (upper_bound - lower_bound) / ((max_values_count - 1) as f64);
+                return (0..max_values_count).map(|i| lower_bound + (i as f64) * step).collect();
+            }
+            GJI(x) => {
+                match &x.allowed_values {
+                    Some(allowed_values) => candidate_values = Self::pick_evenly(allowed_values, max_values_count),
+                    None => {
+                        let values_count = (upper_bound - lower_bound) as usize + 1;
+                        let picked_count = values_count.min(max_values_count);
+                        candidate_values = (0..picked_count).map(|i| {
+                            if picked_count == 1 {
+                                return lower_bound;
+                            }
+                            return lower_bound + ((i * (values_count - 1)) as f64 / (picked_count - 1) as f64).round();
+                        }).collect();
+                        candidate_values.dedup();
+                    }
+                }
+                if x.allow_unassigned {
+                    candidate_values.push(x.get_unassigned_value());
+                }
+            }
+            _ => {
+                let all_values: Vec<f64> = ((lower_bound as usize)..=(upper_bound as usize)).map(|x| x as f64).collect();
+                candidate_values = Self::pick_evenly(&all_values, max_values_count);
+            }
+        }
+
+        return candidate_values;
+    }
+
+    fn pick_evenly(values: &Vec<f64>, count: usize) -> Vec<f64> {
+        if values.len() <= count {
+            return values.clone();
+        }
+        if count == 1 {
+            return vec![values[0]];
+        }
+        let mut picked_values: Vec<f64> = (0..count).map(|i| values[i * (values.len() - 1) / (count - 1)]).collect();
+        picked_values.dedup();
+        return picked_values;
+    }
+
+    pub fn set_random_seed(&mut self, random_seed: u64) {
+        /*
+            Each variable has its own generator for initial sampling.
+            Seeds of variables are derived from the manager's seed in the order of variables_vec,
+            so the same cotwin with the same seed always gives the same initial population.
+        */
+        let mut seeds_generator = StdRng::seed_from_u64(random_seed);
+        for variable in self.variables_vec.iter_mut() {
+            let variable_seed: u64 = seeds_generator.gen();
+            match variable {
+                GJF(x) => x.random_generator = StdRng::seed_from_u64(variable_seed),
+                GJI(x) => x.random_generator = StdRng::seed_from_u64(variable_seed),
+                GJB(x) => x.random_generator = StdRng::seed_from_u64(variable_seed),
+                GJC(x) => x.random_generator = StdRng::seed_from_u64(variable_seed),
+                GJL(x) => x.random_generator = StdRng::seed_from_u64(variable_seed),
+            }
+        }
     }
 
     pub fn sample_variables(&mut self) -> Vec<f64> {
 
         let mut values_array: Vec<f64> = vec![0.0; self.variables_count];
-        for i in 0..self.variables_count {
+        for i in 0..self.variables_vec.len() {
 
+            let column_id = self.variable_column_ranges[i].0;
             let variable = &mut self.variables_vec[i];
-            let generated_value: f64;
             match variable {
-                PlanningVariablesVariants::GJF(x) => generated_value = x.get_initial_value(),
-                PlanningVariablesVariants::GJI(x) => generated_value = x.get_initial_value()
+                PlanningVariablesVariants::GJF(x) => values_array[column_id] = x.get_initial_value(),
+                PlanningVariablesVariants::GJI(x) => values_array[column_id] = x.get_initial_value(),
+                PlanningVariablesVariants::GJB(x) => values_array[column_id] = x.get_initial_value(),
+                PlanningVariablesVariants::GJC(x) => values_array[column_id] = x.get_initial_value(),
+                PlanningVariablesVariants::GJL(_) => continue,
             }
-            values_array[i] = generated_value;
+        }
+
+        for group_name in self.list_group_keys.clone() {
+            self.sample_list_group(&mut values_array, &group_name);
         }
 
         return values_array;
     }
 
-    pub fn inverse_transform_variables<'a>(&self, values_array: &Vec<f64>) -> Vec<(AnyValue<'a>)> {
+    fn sample_list_group(&mut self, values_array: &mut Vec<f64>, group_name: &String) {
 
+        let list_ids = self.list_groups_map[group_name].clone();
+        let mut lists_element_ids: Vec<Vec<usize>> = list_ids.iter().map(|list_id| self.get_list(*list_id).get_initial_element_ids()).collect();
+        let mut is_planned: Vec<bool> = vec![false; self.get_list(list_ids[0]).elements.len()];
+        lists_element_ids.iter().for_each(|element_ids| element_ids.iter().for_each(|element_id| is_planned[*element_id] = true));
+        let mut unplanned_element_ids: Vec<usize> = (0..is_planned.len()).filter(|element_id| is_planned[*element_id] == false).collect();
 
-        let values_map: Vec<AnyValue<'a>> =
-        self.variables_vec.iter().zip(values_array.iter()).map(|(variable, x)| {
-            match variable {
-                PlanningVariablesVariants::GJF(float_var) => {
-                    AnyValue::Float64(float_var.inverse_transform(*x))
-                }
-                PlanningVariablesVariants::GJI(int_var) => {
-                    AnyValue::Int64(int_var.inverse_transform(*x))
+        // the group's elements are shuffled by the generator of its first list to keep seeded runs repeatable
+        let random_generator;
+        match &mut self.variables_vec[list_ids[0]] {
+            GJL(x) => random_generator = &mut x.random_generator,
+            _ => panic!("Variable isn't a list"),
+        }
+        unplanned_element_ids.shuffle(random_generator);
+        for element_id in unplanned_element_ids {
+            let open_list_positions: Vec<usize> = (0..list_ids.len()).filter(|i| {
+                let current_list = Self::as_list(&self.variables_vec[list_ids[*i]]);
+                current_list.frozen == false && lists_element_ids[*i].len() < current_list.max_length
+            }).collect();
+            let random_generator;
+            match &mut self.variables_vec[list_ids[0]] {
+                GJL(x) => random_generator = &mut x.random_generator,
+                _ => panic!("Variable isn't a list"),
+            }
+            let chosen_position = open_list_positions[Uniform::new(0, open_list_positions.len()).sample(random_generator)];
+            lists_element_ids[chosen_position].push(element_id);
+        }
+
+        list_ids.iter().zip(lists_element_ids.iter()).for_each(|(list_id, element_ids)| self.write_list(values_array, *list_id, element_ids));
+    }
+
+    pub fn write_list(&self, values_array: &mut Vec<f64>, variable_id: usize, element_ids: &Vec<usize>) {
+        let column_start = self.variable_column_ranges[variable_id].0;
+        let slot_values = self.get_list(variable_id).to_slot_values(element_ids);
+        slot_values.iter().enumerate().for_each(|(i, x)| values_array[column_start + i] = *x);
+    }
+
+    fn inverse_transform_variable<'a>(&self, variable_id: usize, values_array: &Vec<f64>) -> AnyValue<'a> {
+
+        let (column_start, columns_count) = self.variable_column_ranges[variable_id];
+        match &self.variables_vec[variable_id] {
+            PlanningVariablesVariants::GJF(float_var) => {
+                return AnyValue::Float64(float_var.inverse_transform(values_array[column_start]));
+            }
+            PlanningVariablesVariants::GJI(int_var) => {
+                if int_var.is_unassigned(values_array[column_start]) {
+                    return AnyValue::Null;
                 }
+                return AnyValue::Int64(int_var.inverse_transform(values_array[column_start]));
             }
-        }).collect();
+            PlanningVariablesVariants::GJB(bool_var) => {
+                return AnyValue::Boolean(bool_var.inverse_transform(values_array[column_start]));
+            }
+            PlanningVariablesVariants::GJC(categorical_var) => {
+                return categorical_var.inverse_transform(values_array[column_start]);
+            }
+            PlanningVariablesVariants::GJL(list_var) => {
+                let planned_elements = list_var.inverse_transform(&values_array[column_start..(column_start + columns_count)]);
+                return AnyValue::List(Series::new("".into(), planned_elements));
+            }
+        }
+    }
+
+    // one value per planning variable (not per column), lists become AnyValue::List
+    pub fn inverse_transform_variables<'a>(&self, values_array: &Vec<f64>) -> Vec<(AnyValue<'a>)> {
+
+        let values_map: Vec<AnyValue<'a>> =
+        (0..self.variables_vec.len()).into_iter()
+        .map(|variable_id| self.inverse_transform_variable(variable_id, values_array))
+        .collect();
 
         return values_map;
     }
 
-    pub fn inverse_transform_deltas<'a>(&self, deltas: &Vec<Vec<(usize, f64)>>) -> Vec<Vec<(usize, AnyValue<'a>)>> {
+    /*
+        Deltas are set by columns, but inverted deltas are set by planning variables.
+        A changed slot of a list changes the whole list value, so list is rebuilt
+        from the candidate sample with its changed slots applied.
+    */
+    pub fn inverse_transform_deltas<'a>(&self, values_array: &Vec<f64>, deltas: &Vec<Vec<(usize, f64)>>) -> Vec<Vec<(usize, AnyValue<'a>)>> {
 
 
         let inverted_deltas: Vec<Vec<(usize, AnyValue<'a>)>> =
         deltas.iter().map(|current_deltas| {
-            let current_inverted_deltas: Vec<(usize, AnyValue<'a>)> =
-            current_deltas.iter().map(|id_value_tuple| {
-                let inverted_value;
-                match &self.variables_vec[id_value_tuple.0] {
+            let mut current_inverted_deltas: Vec<(usize, AnyValue<'a>)> = Vec::new();
+            let mut changed_lists: Vec<usize> = Vec::new();
+            current_deltas.iter().for_each(|id_value_tuple| {
+                let variable_id = self.column_variable_ids[id_value_tuple.0];
+                match &self.variables_vec[variable_id] {
                     PlanningVariablesVariants::GJF(float_var) => {
-                        inverted_value = AnyValue::Float64(float_var.inverse_transform(id_value_tuple.1))
+                        current_inverted_deltas.push((variable_id, AnyValue::Float64(float_var.inverse_transform(id_value_tuple.1))));
                     }
                     PlanningVariablesVariants::GJI(int_var) => {
-                        inverted_value = AnyValue::Int64(int_var.inverse_transform(id_value_tuple.1))
+                        if int_var.is_unassigned(id_value_tuple.1) {
+                            current_inverted_deltas.push((variable_id, AnyValue::Null));
+                        } else {
+                            current_inverted_deltas.push((variable_id, AnyValue::Int64(int_var.inverse_transform(id_value_tuple.1))));
+                        }
+                    }
+                    PlanningVariablesVariants::GJB(bool_var) => {
+                        current_inverted_deltas.push((variable_id, AnyValue::Boolean(bool_var.inverse_transform(id_value_tuple.1))));
+                    }
+                    PlanningVariablesVariants::GJC(categorical_var) => {
+                        current_inverted_deltas.push((variable_id, categorical_var.inverse_transform(id_value_tuple.1)));
+                    }
+                    PlanningVariablesVariants::GJL(_) => {
+                        if changed_lists.contains(&variable_id) == false {
+                            changed_lists.push(variable_id);
+                        }
                     }
                 }
-                return (id_value_tuple.0, inverted_value);
-            }).collect();
+            });
+
+            if changed_lists.len() != 0 {
+                let mut changed_values_array = values_array.clone();
+                current_deltas.iter().for_each(|(column_id, value)| changed_values_array[*column_id] = *value);
+                changed_lists.iter().for_each(|variable_id| {
+                    current_inverted_deltas.push((*variable_id, self.inverse_transform_variable(*variable_id, &changed_values_array)));
+                });
+            }
+
             return current_inverted_deltas;
         }).collect();
 
         return inverted_deltas;
     }
 
+    // polars type of the variable's column, for columns where all values turned out to be null
+    pub fn get_variable_dtype(&self, variable_id: usize) -> DataType {
+        match &self.variables_vec[variable_id] {
+            GJF(_) => return DataType::Float64,
+            GJI(_) => return DataType::Int64,
+            GJB(_) => return DataType::Boolean,
+            GJC(x) => return x.categories[0].dtype(),
+            GJL(_) => return DataType::List(Box::new(DataType::Int64)),
+        }
+    }
+
+    pub fn get_named_variables<'a>(&self, values_array: &Vec<f64>) -> Vec<(String, AnyValue<'a>)> {
+
+        let inverse_transformed_variables = self.inverse_transform_variables(values_array);
+        let variables_names = self.get_variables_names_vec();
+        let named_variables: Vec<(String, AnyValue<'a>)> = 
+        variables_names.into_iter()
+        .zip(inverse_transformed_variables.into_iter())
+        .collect();
+
+        return named_variables;
+    }
+
     pub fn get_variables_names_vec(&self) -> Vec<String> {
         self.variables_vec.iter().map(|variable| {
             match variable {
                 PlanningVariablesVariants::GJF(float_var) => float_var.name.clone(),
-                PlanningVariablesVariants::GJI(int_var) => int_var.name.clone()
+                PlanningVariablesVariants::GJI(int_var) => int_var.name.clone(),
+                PlanningVariablesVariants::GJB(bool_var) => bool_var.name.clone(),
+                PlanningVariablesVariants::GJC(categorical_var) => categorical_var.name.clone(),
+                PlanningVariablesVariants::GJL(list_var) => list_var.name.clone(),
             }
         }).collect()
     }
@@ -192,12 +580,65 @@ impl VariablesManager {
             None => range_ids = Vec::from_iter( (0..self.variables_count).into_iter() )
         }
 
+        let mut list_groups_to_repair: Vec<&String> = Vec::new();
         let stub_collection: () = range_ids.iter().map(|i| {
-            match &self.variables_vec[*i] {
+            match &self.variables_vec[self.column_variable_ids[*i]] {
                 GJF(x) => values_array[*i] = x.fix(values_array[*i]),
                 GJI(x) => values_array[*i] = x.fix(values_array[*i]),
+                GJB(x) => values_array[*i] = x.fix(values_array[*i]),
+                GJC(x) => values_array[*i] = x.fix(values_array[*i]),
+                GJL(x) => {
+                    if list_groups_to_repair.contains(&&x.list_group) == false {
+                        list_groups_to_repair.push(&x.list_group);
+                    }
+                }
             }
         }).collect();
+
+        list_groups_to_repair.iter().for_each(|group_name| self.repair_list_group(values_array, group_name));
+    }
+
+    /*
+        Crossovers and continuous mutations (GA, LSHADE) don't know anything about lists,
+        so after them the group can contain duplicates, lost elements or gaps.
+        Frozen lists get their initial value back, first occurrences of elements are kept,
+        lost elements are appended to the shortest non-frozen lists.
+    */
+    pub fn repair_list_group(&self, values_array: &mut Vec<f64>, group_name: &String) {
+
+        let list_ids = &self.list_groups_map[group_name];
+        let elements_count = self.get_list(list_ids[0]).elements.len();
+        let mut is_planned: Vec<bool> = vec![false; elements_count];
+        let mut lists_element_ids: Vec<Vec<usize>> = vec![Vec::new(); list_ids.len()];
+
+        list_ids.iter().enumerate().filter(|(_, list_id)| self.get_list(**list_id).frozen).for_each(|(i, list_id)| {
+            lists_element_ids[i] = self.get_list(*list_id).get_initial_element_ids();
+            lists_element_ids[i].iter().for_each(|element_id| is_planned[*element_id] = true);
+        });
+        list_ids.iter().enumerate().filter(|(_, list_id)| self.get_list(**list_id).frozen == false).for_each(|(i, list_id)| {
+            for element_id in self.get_list_element_ids(values_array, *list_id) {
+                if is_planned[element_id] == false && lists_element_ids[i].len() < self.get_list(*list_id).max_length {
+                    is_planned[element_id] = true;
+                    lists_element_ids[i].push(element_id);
+                }
+            }
+        });
+
+        for element_id in 0..elements_count {
+            if is_planned[element_id] {
+                continue;
+            }
+            let shortest_list_position = (0..list_ids.len())
+                .filter(|i| {
+                    let current_list = self.get_list(list_ids[*i]);
+                    current_list.frozen == false && lists_element_ids[*i].len() < current_list.max_length
+                })
+                .min_by_key(|i| lists_element_ids[*i].len())
+                .unwrap();
+            lists_element_ids[shortest_list_position].push(element_id);
+        }
+
+        list_ids.iter().zip(lists_element_ids.iter()).for_each(|(list_id, element_ids)| self.write_list(values_array, *list_id, element_ids));
     }
 
     pub fn fix_deltas(&self, deltas: &mut Vec<f64>, ids_to_fix: Option<Vec<usize>>) {
@@ -212,9 +653,13 @@ impl VariablesManager {
         range_ids.iter()
         .enumerate()
         .map(|(delta_id, var_id)| {
-            match &self.variables_vec[*var_id] {
+            match &self.variables_vec[self.column_variable_ids[*var_id]] {
                 GJF(x) => deltas[delta_id] = x.fix(deltas[delta_id]),
                 GJI(x) => deltas[delta_id] = x.fix(deltas[delta_id]),
+                GJB(x) => deltas[delta_id] = x.fix(deltas[delta_id]),
+                GJC(x) => deltas[delta_id] = x.fix(deltas[delta_id]),
+                // list moves keep lists valid, deltas only need to be casted to slot values
+                GJL(x) => deltas[delta_id] = x.fix(deltas[delta_id]),
             }
         }).collect();
     }
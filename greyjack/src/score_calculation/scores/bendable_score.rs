@@ -0,0 +1,219 @@
+use crate::score_calculation::scores::ScoreTrait;
+use crate::utils::math_utils::round;
+use std::cmp::Ordering;
+use std::cmp::Ordering::*;
+use std::ops::{Add, AddAssign};
+use std::fmt::{Display, Formatter};
+use serde::{Serialize, Deserialize};
+use smallvec::SmallVec;
+
+/*
+    Score with any count of hard and soft levels, set at runtime by constraints
+    (e.g. BendableScore::new(vec![0.0, 1.0, 0.0], vec![5.0, 2.0]) for 3 hard and 2 soft levels).
+    Levels are compared lexicographically: all hard levels first, then soft ones.
+    Null and stub scores have no levels, missing levels are treated as zeros,
+    so constraints can return scores, which fill only their own levels.
+    To catch constraints, which return wrong levels, set levels counts of the model in the score calculator
+    (set_score_levels()), then each constraint score must have exactly these counts of levels.
+    score_precision is [hard precision, soft precision], it's applied to all levels of the part.
+*/
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BendableScore {
+    pub hard_scores: SmallVec<[f64; 4]>,
+    pub soft_scores: SmallVec<[f64; 4]>,
+}
+
+impl BendableScore {
+    pub fn new(hard_scores: Vec<f64>, soft_scores: Vec<f64>) -> Self{
+        BendableScore{
+            hard_scores: SmallVec::from_vec(hard_scores),
+            soft_scores: SmallVec::from_vec(soft_scores)
+        }
+    }
+
+    pub fn hard_levels(&self) -> usize {
+        self.hard_scores.len()
+    }
+
+    pub fn soft_levels(&self) -> usize {
+        self.soft_scores.len()
+    }
+
+    fn compare_levels(levels: &SmallVec<[f64; 4]>, other_levels: &SmallVec<[f64; 4]>) -> Ordering {
+        for i in 0..levels.len().max(other_levels.len()) {
+            let level_score = *levels.get(i).unwrap_or(&0.0);
+            let other_level_score = *other_levels.get(i).unwrap_or(&0.0);
+            let level_ordering = level_score.total_cmp(&other_level_score);
+            if level_ordering != Equal {
+                return level_ordering;
+            }
+        }
+        return Equal;
+    }
+
+    fn add_levels(levels: &mut SmallVec<[f64; 4]>, other_levels: &SmallVec<[f64; 4]>) {
+        if levels.len() < other_levels.len() {
+            levels.resize(other_levels.len(), 0.0);
+        }
+        for (i, level_score) in other_levels.iter().enumerate() {
+            levels[i] += level_score;
+        }
+    }
+}
+
+impl ScoreTrait for BendableScore {
+    fn get_sum_abs(&self) -> f64 {
+        self.hard_scores.iter().chain(self.soft_scores.iter()).map(|x| x.abs()).sum()
+    }
+
+    fn get_priority_score(&self) -> f64 {
+        for level_score in self.hard_scores.iter().chain(self.soft_scores.iter()) {
+            if *level_score > 0.0 {
+                return *level_score;
+            }
+        }
+        match self.soft_scores.last() {
+            Some(soft_score) => return *soft_score,
+            None => return 0.0
+        }
+    }
+
+    fn get_fitness_value(&self) -> f64 {
+        let levels_count = self.hard_levels() + self.soft_levels();
+        if levels_count == 0 {
+            return 0.0;
+        }
+        let fitness_sum: f64 = self.hard_scores.iter().chain(self.soft_scores.iter()).map(|x| 1.0 - (1.0 / (x + 1.0))).sum();
+        let fitness_value = fitness_sum / (levels_count as f64);
+        
+        return fitness_value;
+    }
+
+    fn get_null_score() -> Self {
+        BendableScore {
+            hard_scores: SmallVec::new(),
+            soft_scores: SmallVec::new()
+        }
+    }
+
+    fn get_stub_score() -> Self {
+        BendableScore {
+            hard_scores: SmallVec::from_elem(f64::MAX - 1.0, 1),
+            soft_scores: SmallVec::from_elem(f64::MAX - 1.0, 1)
+        }
+    }
+
+    fn as_vec(&self) -> Vec<f64> {
+        self.hard_scores.iter().chain(self.soft_scores.iter()).cloned().collect()
+    }
+
+    fn mul(&self, scalar: f64) -> Self {
+        BendableScore {
+            hard_scores: self.hard_scores.iter().map(|x| scalar * x).collect(),
+            soft_scores: self.soft_scores.iter().map(|x| scalar * x).collect()
+        }
+    }
+
+    fn precision_len() -> usize {
+        2
+    }
+
+    fn round(&mut self, precision: &Vec<u64>) {
+        self.hard_scores.iter_mut().for_each(|x| *x = round(*x, precision[0]));
+        self.soft_scores.iter_mut().for_each(|x| *x = round(*x, precision[1]));
+    }
+
+    fn get_levels_counts(&self) -> Option<(usize, usize)> {
+        return Some((self.hard_levels(), self.soft_levels()));
+    }
+}
+
+impl PartialEq for BendableScore {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Equal
+    }
+}
+
+impl Eq for BendableScore {}
+
+impl Ord for BendableScore {
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        let hard_scores_ordering = Self::compare_levels(&self.hard_scores, &other.hard_scores);
+
+        match hard_scores_ordering {
+            Less => return hard_scores_ordering,
+            Greater => return hard_scores_ordering,
+            Equal => return Self::compare_levels(&self.soft_scores, &other.soft_scores)
+        }
+    }
+    
+}
+
+impl PartialOrd for BendableScore {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for BendableScore {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        return self;
+    }
+}
+
+impl AddAssign for BendableScore {
+    fn add_assign(&mut self, rhs: Self) {
+        Self::add_levels(&mut self.hard_scores, &rhs.hard_scores);
+        Self::add_levels(&mut self.soft_scores, &rhs.soft_scores);
+    }
+}
+
+impl Display for BendableScore {
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let hard_scores: Vec<String> = self.hard_scores.iter().map(|x| x.to_string()).collect();
+        let soft_scores: Vec<String> = self.soft_scores.iter().map(|x| x.to_string()).collect();
+        write!(f, "[{}] | [{}]", hard_scores.join(", "), soft_scores.join(", "))
+    }
+    
+}
+
+unsafe impl Send for BendableScore {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bendable_score_comparison() {
+
+        let small_score = BendableScore::new(vec![0.0, 0.0, 1.0], vec![0.0, 0.0]);
+        let large_score = BendableScore::new(vec![0.0, 1.0, 0.0], vec![0.0, 0.0]);
+        let soft_score = BendableScore::new(vec![0.0, 0.0, 0.0], vec![3.0, -2.0]);
+
+        assert_eq!(small_score < large_score, true);
+        assert_eq!(soft_score < small_score, true);
+        assert_eq!(BendableScore::get_null_score() == BendableScore::new(vec![0.0; 3], vec![0.0; 2]), true);
+        assert_eq!(BendableScore::get_stub_score() > large_score, true);
+        assert_eq!(large_score.get_priority_score(), 1.0);
+        assert_eq!(soft_score.get_priority_score(), 3.0);
+    }
+
+    #[test]
+    fn test_bendable_score_add() {
+        let mut score = BendableScore::get_null_score();
+        score += BendableScore::new(vec![1.0], vec![]);
+        score += BendableScore::new(vec![0.0, 0.0, 2.0], vec![0.5, 1.0]).mul(2.0);
+        assert_eq!(score.hard_scores.to_vec(), vec![1.0, 0.0, 4.0]);
+        assert_eq!(score.soft_levels(), 2);
+        assert_eq!(score.to_string(), "[1, 0, 4] | [1, 2]");
+
+        let score_json = serde_json::to_value(&score).unwrap();
+        let deserialized_score: BendableScore = serde_json::from_value(score_json).unwrap();
+        assert_eq!(deserialized_score, score);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::score_calculation::scores::ScoreTrait;
+use std::cmp::Ordering;
+use std::cmp::Ordering::*;
+use std::ops::{Add, AddAssign};
+use std::fmt::{Display, Formatter};
+use serde::{Serialize, Deserialize};
+
+pub const DECIMAL_PLACES: u32 = 6;
+const DECIMAL_SCALE: i64 = 10_i64.pow(DECIMAL_PLACES);
+
+/*
+    Fixed-point counterpart of HardSoftScore with DECIMAL_PLACES digits after the point.
+    Values are stored as integer counts of 10^-DECIMAL_PLACES units (hard_units, soft_units),
+    so sums and comparisons are exact and score_precision isn't needed.
+    Floats are rounded to the nearest unit only once: in new() and mul().
+*/
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct HardSoftDecimalScore {
+    pub hard_units: i64,
+    pub soft_units: i64
+}
+
+impl HardSoftDecimalScore {
+    pub fn new(hard_score: f64, soft_score: f64) -> Self{
+        HardSoftDecimalScore{
+            hard_units: Self::to_units(hard_score),
+            soft_units: Self::to_units(soft_score)
+        }
+    }
+
+    pub fn from_units(hard_units: i64, soft_units: i64) -> Self{
+        HardSoftDecimalScore{
+            hard_units: hard_units,
+            soft_units: soft_units
+        }
+    }
+
+    pub fn get_hard_score(&self) -> f64 {
+        (self.hard_units as f64) / (DECIMAL_SCALE as f64)
+    }
+
+    pub fn get_soft_score(&self) -> f64 {
+        (self.soft_units as f64) / (DECIMAL_SCALE as f64)
+    }
+
+    fn to_units(value: f64) -> i64 {
+        (value * (DECIMAL_SCALE as f64)).round() as i64
+    }
+
+    fn format_units(units: i64) -> String {
+        let sign = if units < 0 { "-" } else { "" };
+        let integer_part = units.unsigned_abs() / (DECIMAL_SCALE as u64);
+        let fractional_part = units.unsigned_abs() % (DECIMAL_SCALE as u64);
+        if fractional_part == 0 {
+            return format!("{}{}", sign, integer_part);
+        }
+        let fractional_digits = format!("{:0width$}", fractional_part, width = DECIMAL_PLACES as usize);
+        return format!("{}{}.{}", sign, integer_part, fractional_digits.trim_end_matches('0'));
+    }
+}
+
+impl ScoreTrait for HardSoftDecimalScore {
+    fn get_sum_abs(&self) -> f64 {
+        self.get_hard_score().abs() + self.get_soft_score().abs()
+    }
+
+    fn get_priority_score(&self) -> f64 {
+        if self.hard_units > 0 {
+            return self.get_hard_score();
+        } else {
+            return self.get_soft_score();
+        }
+    }
+
+    fn get_fitness_value(&self) -> f64 {
+        let hard_fitness = 1.0 - (1.0 / (self.get_hard_score() + 1.0));
+        let soft_fitness = 1.0 - (1.0 / (self.get_soft_score() + 1.0));
+        let fitness_value = 0.5 * hard_fitness + 0.5 * soft_fitness;
+        
+        return fitness_value;
+    }
+
+    fn get_null_score() -> Self {
+        HardSoftDecimalScore {
+            hard_units: 0,
+            soft_units: 0
+        }
+    }
+
+    fn get_stub_score() -> Self {
+        HardSoftDecimalScore {
+            hard_units: i64::MAX - 1,
+            soft_units: i64::MAX - 1
+        }
+    }
+
+    fn as_vec(&self) -> Vec<f64> {
+        vec![self.get_hard_score(), self.get_soft_score()]
+    }
+
+    fn mul(&self, scalar: f64) -> Self {
+        HardSoftDecimalScore {
+            hard_units: (scalar * (self.hard_units as f64)).round() as i64,
+            soft_units: (scalar * (self.soft_units as f64)).round() as i64
+        }
+    }
+
+    fn precision_len() -> usize {
+        2
+    }
+
+    // fixed-point values are already exact
+    fn round(&mut self, precision: &Vec<u64>) {}
+}
+
+impl Eq for HardSoftDecimalScore {}
+
+impl Ord for HardSoftDecimalScore {
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        let hard_score_ordering = self.hard_units.cmp(&other.hard_units);
+
+        match hard_score_ordering {
+            Less => return hard_score_ordering,
+            Greater => return hard_score_ordering,
+            Equal => return self.soft_units.cmp(&other.soft_units)
+        }
+    }
+    
+}
+
+impl Add for HardSoftDecimalScore {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        HardSoftDecimalScore {
+            hard_units: self.hard_units.saturating_add(rhs.hard_units),
+            soft_units: self.soft_units.saturating_add(rhs.soft_units),
+        }
+    }
+}
+
+impl AddAssign for HardSoftDecimalScore {
+    fn add_assign(&mut self, rhs: Self) {
+        self.hard_units = self.hard_units.saturating_add(rhs.hard_units);
+        self.soft_units = self.soft_units.saturating_add(rhs.soft_units);
+    }
+}
+
+impl Display for HardSoftDecimalScore {
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} | {}", Self::format_units(self.hard_units), Self::format_units(self.soft_units))
+    }
+    
+}
+
+unsafe impl Send for HardSoftDecimalScore {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hard_soft_decimal_score_exact_sum() {
+
+        let mut score = HardSoftDecimalScore::get_null_score();
+        for i in 0..10 {
+            score += HardSoftDecimalScore::new(0.0, 0.1);
+        }
+        assert_eq!(score == HardSoftDecimalScore::new(0.0, 1.0), true);
+        assert_eq!(score.get_soft_score(), 1.0);
+        assert_eq!(HardSoftDecimalScore::new(0.0, 0.000001) > HardSoftDecimalScore::get_null_score(), true);
+        assert_eq!(HardSoftDecimalScore::new(-1.5, 2.25).mul(2.0).to_string(), "-3 | 4.5");
+        assert_eq!(HardSoftDecimalScore::new(0.0, -0.05).to_string(), "0 | -0.05");
+    }
+}
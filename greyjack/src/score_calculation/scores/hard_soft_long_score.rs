@@ -0,0 +1,143 @@
+use crate::score_calculation::scores::ScoreTrait;
+use std::cmp::Ordering;
+use std::cmp::Ordering::*;
+use std::ops::{Add, AddAssign};
+use std::fmt::{Display, Formatter};
+use serde::{Serialize, Deserialize};
+
+/*
+    Integer counterpart of HardSoftScore: sums and comparisons are exact,
+    so score_precision isn't needed (round() does nothing) and ScoreLimit targets are hit exactly.
+    Constraint weights are applied by mul() with rounding to the nearest integer.
+*/
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct HardSoftLongScore {
+    pub hard_score: i64,
+    pub soft_score: i64
+}
+
+impl HardSoftLongScore {
+    pub fn new(hard_score: i64, soft_score: i64) -> Self{
+        HardSoftLongScore{
+            hard_score: hard_score,
+            soft_score: soft_score
+        }
+    }
+}
+
+impl ScoreTrait for HardSoftLongScore {
+    fn get_sum_abs(&self) -> f64 {
+        (self.hard_score.unsigned_abs() as f64) + (self.soft_score.unsigned_abs() as f64)
+    }
+
+    fn get_priority_score(&self) -> f64 {
+        if self.hard_score > 0 {
+            return self.hard_score as f64;
+        } else {
+            return self.soft_score as f64;
+        }
+    }
+
+    fn get_fitness_value(&self) -> f64 {
+        let hard_fitness = 1.0 - (1.0 / ((self.hard_score as f64) + 1.0));
+        let soft_fitness = 1.0 - (1.0 / ((self.soft_score as f64) + 1.0));
+        let fitness_value = 0.5 * hard_fitness + 0.5 * soft_fitness;
+        
+        return fitness_value;
+    }
+
+    fn get_null_score() -> Self {
+        HardSoftLongScore {
+            hard_score: 0,
+            soft_score: 0
+        }
+    }
+
+    fn get_stub_score() -> Self {
+        HardSoftLongScore {
+            hard_score: i64::MAX - 1,
+            soft_score: i64::MAX - 1
+        }
+    }
+
+    fn as_vec(&self) -> Vec<f64> {
+        vec![self.hard_score as f64, self.soft_score as f64]
+    }
+
+    fn mul(&self, scalar: f64) -> Self {
+        HardSoftLongScore {
+            hard_score: (scalar * (self.hard_score as f64)).round() as i64,
+            soft_score: (scalar * (self.soft_score as f64)).round() as i64
+        }
+    }
+
+    fn precision_len() -> usize {
+        2
+    }
+
+    // integer scores don't accumulate rounding errors
+    fn round(&mut self, precision: &Vec<u64>) {}
+}
+
+impl Eq for HardSoftLongScore {}
+
+impl Ord for HardSoftLongScore {
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        let hard_score_ordering = self.hard_score.cmp(&other.hard_score);
+
+        match hard_score_ordering {
+            Less => return hard_score_ordering,
+            Greater => return hard_score_ordering,
+            Equal => return self.soft_score.cmp(&other.soft_score)
+        }
+    }
+    
+}
+
+impl Add for HardSoftLongScore {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        HardSoftLongScore {
+            hard_score: self.hard_score.saturating_add(rhs.hard_score),
+            soft_score: self.soft_score.saturating_add(rhs.soft_score),
+        }
+    }
+}
+
+impl AddAssign for HardSoftLongScore {
+    fn add_assign(&mut self, rhs: Self) {
+        self.hard_score = self.hard_score.saturating_add(rhs.hard_score);
+        self.soft_score = self.soft_score.saturating_add(rhs.soft_score);
+    }
+}
+
+impl Display for HardSoftLongScore {
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} | {}", self.hard_score, self.soft_score)
+    }
+    
+}
+
+unsafe impl Send for HardSoftLongScore {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hard_soft_long_score_exact_sum() {
+
+        let mut score = HardSoftLongScore::get_null_score();
+        for i in 0..1000 {
+            score += HardSoftLongScore::new(0, 3).mul(0.1 * 10.0);
+            score += HardSoftLongScore::new(0, -3);
+        }
+        assert_eq!(score, HardSoftLongScore::get_null_score());
+        assert_eq!(HardSoftLongScore::new(0, 1) > HardSoftLongScore::new(0, 0), true);
+        assert_eq!(HardSoftLongScore::new(-1, 100) < HardSoftLongScore::new(0, 0), true);
+        assert_eq!(HardSoftLongScore::new(1, 5).to_string(), "1 | 5");
+    }
+}
@@ -171,6 +171,18 @@ mod tests {
         
     }
 
+    #[test]
+    fn test_hard_soft_score_dominance() {
+
+        // lexicographically better, but worse in the soft component
+        assert_eq!(HardSoftScore::new(-2.0, 0.0) < HardSoftScore::new(-1.0, -100.0), true);
+        assert_eq!(HardSoftScore::new(-2.0, 0.0).dominates(&HardSoftScore::new(-1.0, -100.0)), false);
+        assert_eq!(HardSoftScore::new(-1.0, -100.0).dominates(&HardSoftScore::new(-2.0, 0.0)), false);
+
+        assert_eq!(HardSoftScore::new(-2.0, 0.0).dominates(&HardSoftScore::new(-1.0, 0.0)), true);
+        assert_eq!(HardSoftScore::new(-1.0, 0.0).dominates(&HardSoftScore::new(-1.0, 0.0)), false);
+    }
+
     #[test]
     fn test_simple_score_add() {
         let mut score_1 = HardSoftScore::new(-1.0, -1.0);
@@ -4,8 +4,16 @@ pub mod score_trait;
 pub mod simple_score;
 pub mod hard_soft_score;
 pub mod hard_medium_soft_score;
+pub mod multi_objective_score;
+pub mod bendable_score;
+pub mod hard_soft_long_score;
+pub mod hard_soft_decimal_score;
 
 pub use score_trait::ScoreTrait;
 pub use simple_score::SimpleScore;
 pub use hard_soft_score::HardSoftScore;
-pub use hard_medium_soft_score::HardMediumSoftScore;
\ No newline at end of file
+pub use hard_medium_soft_score::HardMediumSoftScore;
+pub use multi_objective_score::MultiObjectiveScore;
+pub use bendable_score::BendableScore;
+pub use hard_soft_long_score::HardSoftLongScore;
+pub use hard_soft_decimal_score::HardSoftDecimalScore;
\ No newline at end of file
@@ -0,0 +1,235 @@
+use crate::score_calculation::scores::ScoreTrait;
+use crate::utils::math_utils::round;
+use std::cmp::Ordering;
+use std::cmp::Ordering::*;
+use std::ops::{Add, AddAssign};
+use std::fmt::{Display, Formatter};
+use serde::{Serialize, Deserialize};
+
+/*
+    Hard part plus any count of objectives (all are minimized).
+    Ord (used by metaheuristics to sort populations) compares the hard part first, then the sum of objectives,
+    trade-offs between objectives are kept by ParetoArchive (SolverConfig::with_pareto_archive()), which uses dominates().
+    Null and stub scores have no objectives, missing objectives are treated as zeros.
+*/
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MultiObjectiveScore {
+    pub hard_score: f64,
+    pub objectives: Vec<f64>
+}
+
+impl MultiObjectiveScore {
+    pub fn new(hard_score: f64, objectives: Vec<f64>) -> Self{
+        MultiObjectiveScore{
+            hard_score: hard_score,
+            objectives: objectives
+        }
+    }
+
+    fn get_objective(&self, objective_id: usize) -> f64 {
+        match self.objectives.get(objective_id) {
+            Some(objective) => *objective,
+            None => 0.0
+        }
+    }
+
+    // fold from 0.0: sum() of no objectives is -0.0, which total_cmp() orders before 0.0
+    fn get_objectives_sum(&self) -> f64 {
+        self.objectives.iter().fold(0.0, |objectives_sum, objective| objectives_sum + objective)
+    }
+}
+
+impl ScoreTrait for MultiObjectiveScore {
+    fn get_sum_abs(&self) -> f64 {
+        self.hard_score.abs() + self.objectives.iter().map(|x| x.abs()).sum::<f64>()
+    }
+
+    fn get_priority_score(&self) -> f64 {
+        if self.hard_score > 0.0 {
+            return self.hard_score;
+        } else {
+            return self.get_objectives_sum();
+        }
+    }
+
+    fn get_fitness_value(&self) -> f64 {
+        let hard_fitness = 1.0 - (1.0 / (self.hard_score + 1.0));
+        let objectives_fitness = 1.0 - (1.0 / (self.get_objectives_sum() + 1.0));
+        let fitness_value = 0.5 * hard_fitness + 0.5 * objectives_fitness;
+        
+        return fitness_value;
+    }
+
+    fn get_null_score() -> Self {
+        MultiObjectiveScore {
+            hard_score: 0.0,
+            objectives: Vec::new()
+        }
+    }
+
+    fn get_stub_score() -> Self {
+        MultiObjectiveScore {
+            hard_score: f64::MAX - 1.0,
+            objectives: Vec::new()
+        }
+    }
+
+    fn as_vec(&self) -> Vec<f64> {
+        let mut score_vec = vec![self.hard_score];
+        score_vec.extend(self.objectives.iter());
+        return score_vec;
+    }
+
+    fn mul(&self, scalar: f64) -> Self {
+        MultiObjectiveScore {
+            hard_score: scalar * self.hard_score,
+            objectives: self.objectives.iter().map(|x| scalar * x).collect()
+        }
+    }
+
+    // [hard precision, precision of all objectives]
+    fn precision_len() -> usize {
+        2
+    }
+
+    fn round(&mut self, precision: &Vec<u64>) {
+        self.hard_score = round(self.hard_score, precision[0]);
+        self.objectives.iter_mut().for_each(|x| *x = round(*x, precision[1]));
+    }
+
+    fn dominates(&self, other: &Self) -> bool {
+
+        let hard_score_ordering = self.hard_score.total_cmp(&other.hard_score);
+        match hard_score_ordering {
+            Less => return true,
+            Greater => return false,
+            Equal => ()
+        }
+
+        let mut is_strictly_better = false;
+        for i in 0..self.objectives.len().max(other.objectives.len()) {
+            match self.get_objective(i).total_cmp(&other.get_objective(i)) {
+                Less => is_strictly_better = true,
+                Greater => return false,
+                Equal => ()
+            }
+        }
+
+        return is_strictly_better;
+    }
+}
+
+// consistent with Ord, scores with and without trailing zero objectives are equal
+impl PartialEq for MultiObjectiveScore {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Equal
+    }
+}
+
+impl Eq for MultiObjectiveScore {}
+
+impl Ord for MultiObjectiveScore {
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        let hard_score_ordering = self.hard_score.total_cmp(&other.hard_score);
+        if hard_score_ordering != Equal {
+            return hard_score_ordering;
+        }
+
+        let objectives_sum_ordering = self.get_objectives_sum().total_cmp(&other.get_objectives_sum());
+        if objectives_sum_ordering != Equal {
+            return objectives_sum_ordering;
+        }
+
+        for i in 0..self.objectives.len().max(other.objectives.len()) {
+            let objective_ordering = self.get_objective(i).total_cmp(&other.get_objective(i));
+            if objective_ordering != Equal {
+                return objective_ordering;
+            }
+        }
+        return Equal;
+    }
+    
+}
+
+impl PartialOrd for MultiObjectiveScore {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for MultiObjectiveScore {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        return self;
+    }
+}
+
+impl AddAssign for MultiObjectiveScore {
+    fn add_assign(&mut self, rhs: Self) {
+        self.hard_score += rhs.hard_score;
+        if self.objectives.len() < rhs.objectives.len() {
+            self.objectives.resize(rhs.objectives.len(), 0.0);
+        }
+        for (i, objective) in rhs.objectives.iter().enumerate() {
+            self.objectives[i] += objective;
+        }
+    }
+}
+
+impl Display for MultiObjectiveScore {
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let objectives: Vec<String> = self.objectives.iter().map(|x| x.to_string()).collect();
+        write!(f, "{} | {}", self.hard_score, objectives.join(" | "))
+    }
+    
+}
+
+unsafe impl Send for MultiObjectiveScore {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multi_objective_score_dominance() {
+
+        let distance_score = MultiObjectiveScore::new(0.0, vec![10.0, 5.0]);
+        let vehicles_score = MultiObjectiveScore::new(0.0, vec![12.0, 3.0]);
+        let worse_score = MultiObjectiveScore::new(0.0, vec![12.0, 5.0]);
+        let infeasible_score = MultiObjectiveScore::new(1.0, vec![0.0, 0.0]);
+
+        assert_eq!(distance_score.dominates(&vehicles_score), false);
+        assert_eq!(vehicles_score.dominates(&distance_score), false);
+        assert_eq!(distance_score.dominates(&worse_score), true);
+        assert_eq!(vehicles_score.dominates(&worse_score), true);
+        assert_eq!(worse_score.dominates(&worse_score), false);
+        assert_eq!(worse_score.dominates(&infeasible_score), true);
+        assert_eq!(distance_score.dominates(&MultiObjectiveScore::get_stub_score()), true);
+
+        assert_eq!(distance_score < vehicles_score, true);
+        assert_eq!(infeasible_score > worse_score, true);
+    }
+
+    #[test]
+    fn test_multi_objective_score_add() {
+        let mut score = MultiObjectiveScore::get_null_score();
+        score += MultiObjectiveScore::new(1.0, vec![2.0]);
+        score += MultiObjectiveScore::new(0.0, vec![0.0, 3.0]).mul(2.0);
+        assert_eq!(score, MultiObjectiveScore::new(1.0, vec![2.0, 6.0]));
+        assert_eq!(score.to_string(), "1 | 2 | 6");
+    }
+
+    #[test]
+    fn test_multi_objective_score_eq_with_missing_objectives() {
+        let short_score = MultiObjectiveScore::new(0.0, vec![1.0]);
+        let long_score = MultiObjectiveScore::new(0.0, vec![1.0, 0.0]);
+        assert_eq!(short_score.cmp(&long_score), Equal);
+        assert_eq!(short_score == long_score, true);
+        assert_eq!(short_score != MultiObjectiveScore::new(0.0, vec![0.0, 1.0]), true);
+        assert_eq!(MultiObjectiveScore::get_null_score() == MultiObjectiveScore::new(0.0, vec![0.0, 0.0]), true);
+    }
+}
@@ -18,4 +18,25 @@ pub trait ScoreTrait {
     fn precision_len() -> usize;
 
     fn round(&mut self, precision: &Vec<u64>);
+
+    // (hard, soft) counts of levels for scores with runtime levels (BendableScore), None for scores with fixed levels
+    fn get_levels_counts(&self) -> Option<(usize, usize)> {
+        return None;
+    }
+
+    /*
+        Component-wise Pareto dominance over as_vec(): self is not worse (not greater) in every component
+        and strictly better in at least one. Unlike Ord, it doesn't prioritize hard components,
+        so HardSoftScore(-1, -100) doesn't dominate HardSoftScore(-2, 0).
+    */
+    fn dominates(&self, other: &Self) -> bool {
+
+        let self_vec = self.as_vec();
+        let other_vec = other.as_vec();
+
+        let is_not_worse = self_vec.iter().zip(other_vec.iter()).all(|(x, y)| x <= y);
+        let is_strictly_better = self_vec.iter().zip(other_vec.iter()).any(|(x, y)| x < y);
+
+        return is_not_worse && is_strictly_better;
+    }
 }
\ No newline at end of file
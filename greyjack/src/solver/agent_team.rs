@@ -0,0 +1,54 @@
+
+use crate::agents::AgentBuildersVariants;
+use crate::score_calculation::scores::ScoreTrait;
+use crate::errors::GreyjackError;
+
+use std::ops::AddAssign;
+use std::fmt::{Debug, Display};
+use serde::{Serialize, Deserialize};
+
+/*
+    n_jobs agents of another metaheuristic, that solve together with agents of the main agent_builder
+    (SolverConfig::with_agent_team(), Phase::with_agent_team()), e.g. 4 x TabuSearch + 2 x LSHADE + 2 x LateAcceptance.
+    Agents take places in the migration ring in the order of adding, after agents of the main agent_builder.
+    Termination strategy and migration frequency of each agent are taken from its own builder.
+*/
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound(deserialize = "ScoreType: Deserialize<'de>"))]
+pub struct AgentTeam<ScoreType>
+where
+    ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
+    pub agent_builder: AgentBuildersVariants<ScoreType>,
+    #[serde(default = "default_n_jobs")]
+    pub n_jobs: usize,
+}
+
+fn default_n_jobs() -> usize {
+    1
+}
+
+impl<ScoreType> AgentTeam<ScoreType>
+where
+    ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
+
+    pub fn new(agent_builder: AgentBuildersVariants<ScoreType>, n_jobs: usize) -> Self {
+        Self {
+            agent_builder: agent_builder,
+            n_jobs: n_jobs,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+
+        if self.n_jobs == 0 {
+            return Err(GreyjackError::InvalidConfig("AgentTeam: n_jobs must be greater than 0".to_string()));
+        }
+
+        return self.agent_builder.validate();
+    }
+
+    // builders of all agents of the team
+    pub fn get_agent_builders(&self) -> Vec<AgentBuildersVariants<ScoreType>> {
+        return vec![self.agent_builder.clone(); self.n_jobs];
+    }
+}
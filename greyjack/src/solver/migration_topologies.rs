@@ -0,0 +1,104 @@
+
+use crate::errors::GreyjackError;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::IteratorRandom;
+use serde::{Serialize, Deserialize};
+
+/*
+    Graph of migrations between agents (SolverConfig::with_migration_topology()).
+    Ring - each agent sends migrants to the next one (default);
+    BidirectionalRing - to the next and the previous ones;
+    Star - agent 0 is the master, it exchanges migrants with all other agents, they don't exchange with each other;
+    FullyConnected - each agent sends migrants to all other agents;
+    RandomK(k) - each agent sends migrants to k random other agents, neighbours are chosen once per solving phase.
+    Denser topologies spread good solutions faster, that helps against stagnation with large n_jobs.
+*/
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum MigrationTopologies {
+    Ring,
+    BidirectionalRing,
+    Star,
+    FullyConnected,
+    RandomK(usize),
+}
+
+impl MigrationTopologies {
+
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+        match self {
+            MigrationTopologies::RandomK(0) => Err(GreyjackError::InvalidConfig("RandomK migration topology: k must be greater than 0".to_string())),
+            _ => Ok(()),
+        }
+    }
+
+    // ids of agents, that receive migrants of each agent
+    pub fn get_neighbours(&self, agents_count: usize, random_seed: Option<u64>) -> Vec<Vec<usize>> {
+
+        let mut neighbours: Vec<Vec<usize>> = vec![Vec::new(); agents_count];
+        if agents_count < 2 {
+            return neighbours;
+        }
+
+        match self {
+            MigrationTopologies::Ring => {
+                (0..agents_count).for_each(|i| neighbours[i].push((i + 1) % agents_count));
+            }
+            MigrationTopologies::BidirectionalRing => {
+                for i in 0..agents_count {
+                    neighbours[i].push((i + 1) % agents_count);
+                    let previous_id = (i + agents_count - 1) % agents_count;
+                    if neighbours[i].contains(&previous_id) == false {
+                        neighbours[i].push(previous_id);
+                    }
+                }
+            }
+            MigrationTopologies::Star => {
+                for i in 1..agents_count {
+                    neighbours[0].push(i);
+                    neighbours[i].push(0);
+                }
+            }
+            MigrationTopologies::FullyConnected => {
+                (0..agents_count).for_each(|i| neighbours[i] = (0..agents_count).filter(|j| *j != i).collect());
+            }
+            MigrationTopologies::RandomK(k) => {
+                let mut random_generator;
+                match random_seed {
+                    Some(seed) => random_generator = StdRng::seed_from_u64(seed),
+                    None => random_generator = StdRng::from_entropy(),
+                }
+                let neighbours_count = (*k).min(agents_count - 1);
+                for i in 0..agents_count {
+                    let mut chosen_ids = (0..agents_count).filter(|j| *j != i).choose_multiple(&mut random_generator, neighbours_count);
+                    chosen_ids.sort();
+                    neighbours[i] = chosen_ids;
+                }
+            }
+        }
+
+        return neighbours;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migration_topologies_neighbours() {
+
+        assert_eq!(MigrationTopologies::Ring.get_neighbours(3, None), vec![vec![1], vec![2], vec![0]]);
+        assert_eq!(MigrationTopologies::BidirectionalRing.get_neighbours(2, None), vec![vec![1], vec![0]]);
+        assert_eq!(MigrationTopologies::Star.get_neighbours(3, None), vec![vec![1, 2], vec![0], vec![0]]);
+        assert_eq!(MigrationTopologies::FullyConnected.get_neighbours(3, None), vec![vec![1, 2], vec![0, 2], vec![0, 1]]);
+        assert_eq!(MigrationTopologies::FullyConnected.get_neighbours(1, None), vec![Vec::<usize>::new()]);
+
+        let random_neighbours = MigrationTopologies::RandomK(2).get_neighbours(10, Some(45));
+        assert_eq!(random_neighbours, MigrationTopologies::RandomK(2).get_neighbours(10, Some(45)));
+        assert_eq!(random_neighbours.iter().all(|agent_neighbours| agent_neighbours.len() == 2), true);
+        assert_eq!((0..10).all(|i| random_neighbours[i].contains(&i) == false), true);
+        assert_eq!(MigrationTopologies::RandomK(0).validate().is_err(), true);
+    }
+}
@@ -5,9 +5,23 @@ pub mod observer_trait;
 pub mod observable_trait;
 pub mod solver_logging_levels;
 pub mod initial_solution_variants;
+pub mod solver_handle;
+pub mod solver_config;
+pub mod solver_result;
+pub mod termination_reason;
+pub mod phase;
+pub mod agent_team;
+pub mod migration_topologies;
 
 pub use solver::Solver;
 pub use observer_trait::ObserverTrait;
 pub use observable_trait::ObservableTrait;
 pub use solver_logging_levels::SolverLoggingLevels;
-pub use initial_solution_variants::InitialSolutionVariants;
\ No newline at end of file
+pub use initial_solution_variants::InitialSolutionVariants;
+pub use solver_handle::SolverHandle;
+pub use solver_config::SolverConfig;
+pub use solver_result::SolverResult;
+pub use termination_reason::TerminationReason;
+pub use phase::Phase;
+pub use agent_team::AgentTeam;
+pub use migration_topologies::MigrationTopologies;
@@ -0,0 +1,89 @@
+
+use crate::agents::AgentBuildersVariants;
+use crate::agents::termination_strategies::TerminationStrategiesVariants;
+use super::AgentTeam;
+use crate::score_calculation::scores::ScoreTrait;
+use crate::errors::GreyjackError;
+
+use std::ops::AddAssign;
+use std::fmt::{Debug, Display};
+use serde::{Serialize, Deserialize};
+
+/*
+    One stage of multi-phase solving (SolverConfig::with_phase()).
+    Agents of a phase start from the global best solution of the previous phases
+    and reuse score requesters (cotwins) of the previous agents.
+    Optional termination_strategy is phase-wide: it observes the global best individual
+    and stops all agents of the phase at once, then the next phase starts.
+*/
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound(deserialize = "ScoreType: Deserialize<'de>"))]
+pub struct Phase<ScoreType>
+where
+    ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
+    pub agent_builder: AgentBuildersVariants<ScoreType>,
+    #[serde(default = "default_n_jobs")]
+    pub n_jobs: usize,
+    #[serde(default)]
+    pub agent_teams: Vec<AgentTeam<ScoreType>>,
+    #[serde(default)]
+    pub termination_strategy: Option<TerminationStrategiesVariants<ScoreType>>,
+}
+
+fn default_n_jobs() -> usize {
+    1
+}
+
+impl<ScoreType> Phase<ScoreType>
+where
+    ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
+
+    pub fn new(agent_builder: AgentBuildersVariants<ScoreType>) -> Self {
+        Self {
+            agent_builder: agent_builder,
+            n_jobs: default_n_jobs(),
+            agent_teams: Vec::new(),
+            termination_strategy: None,
+        }
+    }
+
+    pub fn with_n_jobs(mut self, n_jobs: usize) -> Self {
+        self.n_jobs = n_jobs;
+        return self;
+    }
+
+    pub fn with_agent_team(mut self, agent_builder: AgentBuildersVariants<ScoreType>, n_jobs: usize) -> Self {
+        self.agent_teams.push(AgentTeam::new(agent_builder, n_jobs));
+        return self;
+    }
+
+    pub fn with_termination_strategy(mut self, termination_strategy: TerminationStrategiesVariants<ScoreType>) -> Self {
+        self.termination_strategy = Some(termination_strategy);
+        return self;
+    }
+
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+
+        if self.n_jobs == 0 {
+            return Err(GreyjackError::InvalidConfig("Phase: n_jobs must be greater than 0".to_string()));
+        }
+
+        for agent_team in &self.agent_teams {
+            agent_team.validate()?;
+        }
+
+        match &self.termination_strategy {
+            Some(termination_strategy) => termination_strategy.validate()?,
+            None => ()
+        }
+
+        return self.agent_builder.validate();
+    }
+
+    // builders of all agents of the phase in the order of their places in the migration ring
+    pub fn get_agent_builders(&self) -> Vec<AgentBuildersVariants<ScoreType>> {
+        let mut agent_builders = vec![self.agent_builder.clone(); self.n_jobs];
+        self.agent_teams.iter().for_each(|agent_team| agent_builders.append(&mut agent_team.get_agent_builders()));
+        return agent_builders;
+    }
+}
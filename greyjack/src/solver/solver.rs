@@ -1,22 +1,36 @@
 
 
-use crate::agents::base::{Agent, AgentStatuses, AgentToAgentUpdate, Individual};
+use crate::agents::base::{Agent, AgentStatuses, AgentToAgentUpdate, Individual, ParetoArchive};
 use crate::agents::AgentBuildersVariants;
+use crate::agents::termination_strategies::TerminationStrategyTrait;
 use crate::domain::DomainBuilderTrait;
-use crate::cotwin::{CotwinBuilderTrait, CotwinEntityTrait};
+use crate::cotwin::{Cotwin, CotwinBuilderTrait, CotwinEntityTrait};
 use crate::score_calculation::scores::ScoreTrait;
+use crate::score_calculation::score_requesters::OOPScoreRequester;
+use crate::score_calculation::score_explanations::ScoreExplanation;
+use crate::score_calculation::score_calculators::ConstraintTimingStats;
 use super::ObserverTrait;
 use super::SolverLoggingLevels;
 use super::InitialSolutionVariants;
+use super::SolverConfig;
+use super::SolverHandle;
+use super::SolverResult;
+use super::TerminationReason;
+use super::Phase;
+use crate::errors::GreyjackError;
 
 use std::ops::{AddAssign, Sub};
+use std::collections::HashMap;
 use std::fmt::{Debug, Display};
 use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
 use crossbeam_channel::*;
-use rayon::prelude::*;
 use std::env;
 use serde::Serialize;
 use serde_json::Value;
+use polars::datatypes::AnyValue;
+use chrono::prelude::*;
 
 pub struct Solver {}
 
@@ -25,13 +39,8 @@ impl Solver {
     pub fn solve<DomainType, DomainBuilder, CotwinBuilder, EntityVariants, UtilityObjectVariants, ScoreType> (
         domain_builder: DomainBuilder,
         cotwin_builder: CotwinBuilder,
-        agent_builder: AgentBuildersVariants<ScoreType>,
-        n_jobs: usize,
-        score_precision: Option<Vec<u64>>,
-        logging_level: SolverLoggingLevels,
-        observers: Option<Vec<Box<dyn ObserverTrait + Send>>>,
-        initial_solution: Option<InitialSolutionVariants<DomainType>>
-    ) -> Value
+        solver_config: SolverConfig<DomainType, ScoreType>,
+    ) -> Result<SolverResult<ScoreType>, GreyjackError>
     where
     DomainType: Clone + Send,
     DomainBuilder: DomainBuilderTrait<DomainType> + Clone + Send + Sync,
@@ -40,111 +49,583 @@ impl Solver {
     ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
 
         /* 
-            Returns serde_json::Value to use it as json string (solution.to_string()) to send via http or
-            parse it directly by serde_json::from_value(solution).unwrap() and use it in DomainUpdater.
-            Note: serde_json::from_value needs type annotation. For solution it's (Vec<(String, AnyValue)>, ScoreType),
-            where ScoreType is current score type used for task.
+            Returns SolverResult with the best individual, its named variable values and score, steps counts of agents,
+            solving time and termination reason. solution.to_json() gives json string to send via http,
+            solution.get_raw_solution() gives (Vec<(String, AnyValue)>, ScoreType) json for DomainBuilderTrait::build_from_solution().
+            Invalid config, cotwin or failure of any agent is returned as GreyjackError instead of panic.
         */
 
-        match &score_precision {
-            Some(precision) => {
-                if precision.len() != ScoreType::precision_len() {
-                    panic!("Invalid score_precision. Suggest: vec![a] for SimpleScore, vec![a, b] for HardSoft, vec![a, b, c] for HardMediumSoft.");
+        solver_config.validate()?;
+
+        let global_top_json = Arc::new(Mutex::new(Value::Null));
+        let termination_flag = Arc::new(AtomicBool::new(false));
+
+        return Self::run_agents(
+            domain_builder, cotwin_builder, solver_config,
+            global_top_json, termination_flag
+        );
+    }
+
+    pub fn start<DomainType, DomainBuilder, CotwinBuilder, EntityVariants, UtilityObjectVariants, ScoreType> (
+        domain_builder: DomainBuilder,
+        cotwin_builder: CotwinBuilder,
+        solver_config: SolverConfig<DomainType, ScoreType>,
+    ) -> Result<SolverHandle<ScoreType>, GreyjackError>
+    where
+    DomainType: Clone + Send + 'static,
+    DomainBuilder: DomainBuilderTrait<DomainType> + Clone + Send + Sync + 'static,
+    CotwinBuilder: CotwinBuilderTrait<DomainType, EntityVariants, UtilityObjectVariants, ScoreType> + Clone + Send + 'static,
+    EntityVariants: CotwinEntityTrait + Send + 'static,
+    UtilityObjectVariants: 'static,
+    ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize + 'static {
+
+        /*
+            Non-blocking version of solve(). Solving runs in a separate thread, the returned SolverHandle
+            allows to take snapshots of the current best solution, to stop solving before termination
+            strategies are accomplished and to wait for the final SolverResult (the same as solve() returns).
+            Config is validated before spawning, so invalid config is returned immediately.
+        */
+
+        solver_config.validate()?;
+
+        let global_top_json = Arc::new(Mutex::new(Value::Null));
+        let termination_flag = Arc::new(AtomicBool::new(false));
+
+        let thread_global_top_json = Arc::clone(&global_top_json);
+        let thread_termination_flag = Arc::clone(&termination_flag);
+        let solving_thread = thread::spawn(move || {
+            Self::run_agents(
+                domain_builder, cotwin_builder, solver_config,
+                thread_global_top_json, thread_termination_flag
+            )
+        });
+
+        return Ok(SolverHandle::new(termination_flag, global_top_json, solving_thread));
+    }
+
+    pub fn explain_score<DomainType, DomainBuilder, CotwinBuilder, EntityVariants, UtilityObjectVariants, ScoreType> (
+        domain_builder: DomainBuilder,
+        cotwin_builder: CotwinBuilder,
+        solution: &Value,
+        constraint_weight_overrides: &HashMap<String, f64>,
+    ) -> Result<ScoreExplanation<ScoreType>, GreyjackError>
+    where
+    DomainType: Clone + Send,
+    DomainBuilder: DomainBuilderTrait<DomainType> + Clone + Send + Sync,
+    CotwinBuilder: CotwinBuilderTrait<DomainType, EntityVariants, UtilityObjectVariants, ScoreType> + Clone + Send,
+    EntityVariants: CotwinEntityTrait + Send,
+    ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
+
+        /*
+            Per-constraint breakdown (weighted and unweighted scores, optional violating rows) of a solution.
+            solution is a serialized SolverResult (parsed SolverResult::to_json()), SolverResult::get_raw_solution() or SolverHandle::best_solution().
+            Cotwin is built from the solution's domain the same way as for solving from an initial solution.
+            Pass SolverConfig::constraint_weight_overrides of the solving to get the same weighted score as SolverResult::score.
+        */
+
+        let initial_solution = Some(InitialSolutionVariants::CotwinValuesVector(solution.clone()));
+        let cotwin = Self::build_cotwin(&domain_builder, &cotwin_builder, initial_solution, constraint_weight_overrides)?;
+        let mut score_requester = OOPScoreRequester::new(cotwin)?;
+        let sample = score_requester.variables_manager.sample_variables();
+        let score_explanation = score_requester.explain_score(&sample)?;
+
+        return Ok(score_explanation);
+    }
+
+    fn build_cotwin<DomainType, DomainBuilder, CotwinBuilder, EntityVariants, UtilityObjectVariants, ScoreType> (
+        domain_builder: &DomainBuilder,
+        cotwin_builder: &CotwinBuilder,
+        initial_solution: Option<InitialSolutionVariants<DomainType>>,
+        constraint_weight_overrides: &HashMap<String, f64>,
+    ) -> Result<Cotwin<EntityVariants, UtilityObjectVariants, ScoreType>, GreyjackError>
+    where
+    DomainType: Clone + Send,
+    DomainBuilder: DomainBuilderTrait<DomainType> + Clone + Send + Sync,
+    CotwinBuilder: CotwinBuilderTrait<DomainType, EntityVariants, UtilityObjectVariants, ScoreType> + Clone + Send,
+    EntityVariants: CotwinEntityTrait + Send,
+    ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
+
+        let domain;
+        let mut is_already_initialized = true;
+        match initial_solution {
+            None => {
+                is_already_initialized = false;
+                domain = domain_builder.build_domain_from_scratch();
+            },
+            Some(initial_solution) => {
+                match initial_solution {
+                    InitialSolutionVariants::CotwinValuesVector(raw_solution) => {
+                        let raw_solution = SolverResult::<ScoreType>::normalize_raw_solution(&raw_solution);
+                        domain = domain_builder.build_from_solution(&raw_solution, None);
+                    }
+                    InitialSolutionVariants::DomainObject(existing_domain) => domain = domain_builder.build_from_domain(&existing_domain),
                 }
             }
-            None => ()
         }
 
-        let agent_ids:Vec<usize> = (0..n_jobs).collect();
-        let domain_builders: Vec<DomainBuilder> = vec![domain_builder.clone(); n_jobs];
-        let cotwin_builders: Vec<CotwinBuilder> = vec![cotwin_builder.clone(); n_jobs];
-        let agent_builders: Vec<AgentBuildersVariants<ScoreType>> = vec![agent_builder.clone(); n_jobs];
-        let score_precisions = vec![score_precision; n_jobs];
-        let logging_levels = vec![logging_level; n_jobs];
-        let initial_solutions: Vec<Option<InitialSolutionVariants<DomainType>>> = vec![initial_solution.clone(); n_jobs];
-        let mut round_robin_status_vec: Vec<AgentStatuses> = Vec::new();
-        let mut agents_updates_senders: Vec<Sender<AgentToAgentUpdate<ScoreType>>> = Vec::new();
-        let mut agents_updates_receivers: Vec<Receiver<AgentToAgentUpdate<ScoreType>>> = Vec::new();
+        let mut cotwin = cotwin_builder.build_cotwin(domain, is_already_initialized)?;
+        cotwin.override_constraint_weights(constraint_weight_overrides)?;
+
+        return Ok(cotwin);
+    }
+
+    fn run_agents<DomainType, DomainBuilder, CotwinBuilder, EntityVariants, UtilityObjectVariants, ScoreType> (
+        domain_builder: DomainBuilder,
+        cotwin_builder: CotwinBuilder,
+        solver_config: SolverConfig<DomainType, ScoreType>,
+        global_top_json: Arc<Mutex<Value>>,
+        termination_flag: Arc<AtomicBool>,
+    ) -> Result<SolverResult<ScoreType>, GreyjackError>
+    where
+    DomainType: Clone + Send,
+    DomainBuilder: DomainBuilderTrait<DomainType> + Clone + Send + Sync,
+    CotwinBuilder: CotwinBuilderTrait<DomainType, EntityVariants, UtilityObjectVariants, ScoreType> + Clone + Send,
+    EntityVariants: CotwinEntityTrait + Send,
+    ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
+
+        let solving_start = Utc::now().timestamp_millis();
+
+        let SolverConfig {agent_builder, n_jobs, agent_teams, score_precision, logging_level, random_seed, migration_topology, termination_strategy, pareto_archive_size, constraint_weight_overrides, construction_heuristic, phases, observers, initial_solution} = solver_config;
+
+        // score requesters of finished agents, agents of the next phases are built on them without rebuilding cotwins
+        let mut score_requesters_pool: Vec<OOPScoreRequester<EntityVariants, UtilityObjectVariants, ScoreType>> = Vec::new();
+
+        let mut carried_individual;
+        match &construction_heuristic {
+            None => carried_individual = None,
+            Some(construction_heuristic) => {
+                let cotwin = Self::build_cotwin(&domain_builder, &cotwin_builder, initial_solution.clone(), &constraint_weight_overrides)?;
+                let mut score_requester = OOPScoreRequester::new(cotwin)?;
+                match random_seed {
+                    Some(seed) => score_requester.variables_manager.set_random_seed(seed),
+                    None => (),
+                }
+                let construction_start = Utc::now().timestamp_millis();
+                let individual = construction_heuristic.construct(&mut score_requester)?;
+                match logging_level {
+                    SolverLoggingLevels::Silent => (),
+                    _ => {
+                        let construction_time = ((Utc::now().timestamp_millis() - construction_start) as f64) / 1000.0;
+                        println!("{}, {} constructed the initial solution, Score: {}, Construction time: {}", 
+                            Local::now().format("%Y-%m-%d %H:%M:%S"), construction_heuristic.get_name(), individual.score, construction_time);
+                    }
+                }
+                score_requesters_pool.push(score_requester);
+                carried_individual = Some(individual);
+            }
+        }
+
+        // agent_builder, n_jobs and agent_teams of the config are the first phase
+        let mut phases = phases;
+        phases.insert(0, Phase {agent_builder: agent_builder, n_jobs: n_jobs, agent_teams: agent_teams, termination_strategy: None});
+
         let global_top_individual: Individual<ScoreType> = Individual::new(vec![1.0], ScoreType::get_stub_score());
+        // the first update starts clocks of time-based strategies at the solving start
+        let mut termination_strategy = termination_strategy;
+        match &mut termination_strategy {
+            Some(strategy) => strategy.update(&global_top_individual),
+            None => (),
+        }
+        let solver_termination_strategy = Arc::new(Mutex::new(termination_strategy));
         let global_top_individual = Arc::new(Mutex::new(global_top_individual));
-        let global_top_json = Arc::new(Mutex::new(Value::Null));
         
-        let observers_counts: Vec<usize>;
+        let observers_count: usize;
         let observers_arc:Arc<Mutex<Option<Vec<Box<dyn ObserverTrait + Send>>>>>;
         match observers {
             None => {
-                observers_counts = vec![0; n_jobs];
+                observers_count = 0;
                 observers_arc = Arc::new(Mutex::new(None));
             }
             Some(observers) => {
-                observers_counts = vec![observers.len(); n_jobs];
+                observers_count = observers.len();
                 observers_arc = Arc::new(Mutex::new(Some(observers)));
             }
         }
 
-        for i in 0..n_jobs {
-            round_robin_status_vec.insert(i, AgentStatuses::Alive);
-            let (agent_i_updates_sender, agent_i_updates_receiver): (Sender<AgentToAgentUpdate<ScoreType>>, Receiver<AgentToAgentUpdate<ScoreType>>) = bounded(1);
-            agents_updates_senders.push(agent_i_updates_sender);
-            agents_updates_receivers.push(agent_i_updates_receiver);
+        let mut agents_steps_counts: Vec<u64> = Vec::new();
+        let mut merged_archive: Option<ParetoArchive<ScoreType>> = None;
+        match pareto_archive_size {
+            Some(archive_size) => merged_archive = Some(ParetoArchive::new(archive_size)),
+            None => (),
         }
-        let agents_round_robin_status_clones = vec![round_robin_status_vec.clone(); n_jobs];
-        agents_updates_receivers.rotate_right(1);
-
-        domain_builders.into_par_iter()
-        .zip(cotwin_builders.into_par_iter())
-        .zip(agent_builders.into_par_iter())
-        .zip(agent_ids.into_par_iter())
-        .zip(agents_round_robin_status_clones.into_par_iter())
-        .zip(agents_updates_senders.into_par_iter())
-        .zip(agents_updates_receivers.into_par_iter())
-        .zip(score_precisions.into_par_iter())
-        .zip(logging_levels.into_par_iter())
-        .zip(observers_counts.into_par_iter())
-        .zip(initial_solutions.into_par_iter())
-        .for_each(|((((((((((db_i, cb_i), ab_i), ai_i), rrs_i), us_i), rc_i), sp_i), ll_i), oc_i), is_i)| {
-            let domain_i;
-            let mut is_already_initialized = true;
-            match is_i {
-                None => {
-                    is_already_initialized = false;
-                    domain_i = db_i.build_domain_from_scratch();
-                },
-                Some(isv) => {
-                    match isv {
-                        InitialSolutionVariants::CotwinValuesVector(raw_solution) => domain_i = db_i.build_from_solution(&raw_solution, None),
-                        InitialSolutionVariants::DomainObject(existing_domain) => domain_i = db_i.build_from_domain(&existing_domain),
+
+        for (phase_id, phase) in phases.into_iter().enumerate() {
+
+            let agent_builders: Vec<AgentBuildersVariants<ScoreType>> = phase.get_agent_builders();
+            // agents of teams are counted too
+            let n_jobs = agent_builders.len();
+
+            if phase_id > 0 {
+                // early termination and solver-wide strategy stop the remaining phases too
+                if termination_flag.load(Ordering::SeqCst) {
+                    break;
+                }
+                let global_top = global_top_individual.lock().unwrap().clone();
+                match &logging_level {
+                    SolverLoggingLevels::Silent => (),
+                    _ => println!("{}, Phase {} started from the global best, Score: {}, n_jobs: {}", 
+                            Local::now().format("%Y-%m-%d %H:%M:%S"), phase_id, global_top.score, n_jobs),
+                }
+                carried_individual = Some(global_top);
+            }
+
+            // agents of all phases get different seeds
+            let agent_ids_offset = agents_steps_counts.len();
+
+            let mut phase_termination_strategy = phase.termination_strategy;
+            match &mut phase_termination_strategy {
+                Some(strategy) => strategy.update(&global_top_individual.lock().unwrap()),
+                None => (),
+            }
+            let phase_termination_strategy = Arc::new(Mutex::new(phase_termination_strategy));
+            let phase_termination_flag = Arc::new(AtomicBool::new(false));
+
+            let agent_ids:Vec<usize> = (0..n_jobs).collect();
+            let domain_builders: Vec<DomainBuilder> = vec![domain_builder.clone(); n_jobs];
+            let cotwin_builders: Vec<CotwinBuilder> = vec![cotwin_builder.clone(); n_jobs];
+            let score_precisions = vec![score_precision.clone(); n_jobs];
+            let logging_levels = vec![logging_level.clone(); n_jobs];
+            let initial_solutions: Vec<Option<InitialSolutionVariants<DomainType>>> = vec![initial_solution.clone(); n_jobs];
+            let carried_individuals: Vec<Option<Individual<ScoreType>>> = vec![carried_individual.clone(); n_jobs];
+            let observers_counts = vec![observers_count; n_jobs];
+            let score_requesters: Vec<Option<OOPScoreRequester<EntityVariants, UtilityObjectVariants, ScoreType>>> = 
+                (0..n_jobs).map(|_| score_requesters_pool.pop()).collect();
+            let agents_statuses = Arc::new(Mutex::new(vec![AgentStatuses::Alive; n_jobs]));
+
+            /*
+                Each agent has its own inbox, neighbours get its senders. Inbox capacity is the count of sending neighbours,
+                but sending doesn't block, so updates are dropped while the inbox is full (the agent is slower than its neighbours).
+            */
+            let agents_neighbours = migration_topology.get_neighbours(n_jobs, random_seed.map(|seed| seed.wrapping_add(agent_ids_offset as u64)));
+            let mut inbox_senders: Vec<Sender<AgentToAgentUpdate<ScoreType>>> = Vec::new();
+            let mut agents_updates_receivers: Vec<Receiver<AgentToAgentUpdate<ScoreType>>> = Vec::new();
+            for i in 0..n_jobs {
+                let senders_count = agents_neighbours.iter().filter(|neighbours| neighbours.contains(&i)).count();
+                let (agent_i_updates_sender, agent_i_updates_receiver): (Sender<AgentToAgentUpdate<ScoreType>>, Receiver<AgentToAgentUpdate<ScoreType>>) = bounded(senders_count.max(1));
+                inbox_senders.push(agent_i_updates_sender);
+                agents_updates_receivers.push(agent_i_updates_receiver);
+            }
+            let agents_updates_senders: Vec<Vec<Sender<AgentToAgentUpdate<ScoreType>>>> = agents_neighbours.iter()
+                .map(|neighbours| neighbours.iter().map(|neighbour_id| inbox_senders[*neighbour_id].clone()).collect())
+                .collect();
+
+            /*
+                Each agent gets its own thread. Agents of a phase finish only together (dead agents keep transmitting migrants),
+                so an agent can't wait for a free thread of a pool, n_jobs can be greater than the rayon pool size.
+                Polars operations of agents still run on the polars pool.
+            */
+            let agents_results: Vec<(u64, OOPScoreRequester<EntityVariants, UtilityObjectVariants, ScoreType>, Option<ParetoArchive<ScoreType>>)> = 
+            thread::scope(|agents_scope| {
+            // shared state is borrowed by threads of agents, per-agent values are moved into them
+            let (constraint_weight_overrides, agents_statuses, global_top_individual, global_top_json, termination_flag) = 
+                (&constraint_weight_overrides, &agents_statuses, &global_top_individual, &global_top_json, &termination_flag);
+            let (solver_termination_strategy, phase_termination_flag, phase_termination_strategy, observers_arc) = 
+                (&solver_termination_strategy, &phase_termination_flag, &phase_termination_strategy, &observers_arc);
+
+            let agents_handles: Vec<_> = 
+            domain_builders.into_iter()
+            .zip(cotwin_builders.into_iter())
+            .zip(agent_builders.into_iter())
+            .zip(agent_ids.into_iter())
+            .zip(agents_updates_senders.into_iter())
+            .zip(agents_updates_receivers.into_iter())
+            .zip(score_precisions.into_iter())
+            .zip(logging_levels.into_iter())
+            .zip(observers_counts.into_iter())
+            .zip(initial_solutions.into_iter())
+            .zip(carried_individuals.into_iter())
+            .zip(score_requesters.into_iter())
+            .map(|(((((((((((db_i, cb_i), ab_i), ai_i), us_i), rc_i), sp_i), ll_i), oc_i), is_i), ci_i), sr_i)| agents_scope.spawn(move || {
+                let agent_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+                    let score_requester_i;
+                    match sr_i {
+                        Some(score_requester) => score_requester_i = Ok(score_requester),
+                        None => score_requester_i = Self::build_cotwin(&db_i, &cb_i, is_i, &constraint_weight_overrides).and_then(|cotwin_i| OOPScoreRequester::new(cotwin_i)),
+                    }
+                    let agent_i = score_requester_i.and_then(|score_requester_i| ab_i.build_agent(score_requester_i));
+                    let mut agent_i = match agent_i {
+                        Ok(agent_i) => agent_i,
+                        Err(build_error) => {
+                            /* 
+                                Status of the failed agent stays alive, so other agents would never finish
+                                without this one, the whole solving is stopped.
+                            */
+                            termination_flag.store(true, Ordering::SeqCst);
+                            return Err(build_error);
+                        }
+                    };
+                    agent_i.agent_id = ai_i;
+                    agent_i.score_precision = sp_i;
+                    agent_i.agents_statuses = Arc::clone(&agents_statuses);
+                    agent_i.alive_agents_count = n_jobs;
+                    agent_i.updates_to_agents_senders = us_i;
+                    agent_i.updates_for_agent_receiver = Some(rc_i);
+                    agent_i.global_top_individual = Arc::clone(&global_top_individual);
+                    agent_i.global_top_json = Arc::clone(&global_top_json);
+                    agent_i.termination_flag = Arc::clone(&termination_flag);
+                    agent_i.solver_termination_strategy = Arc::clone(&solver_termination_strategy);
+                    agent_i.phase_termination_flag = Arc::clone(&phase_termination_flag);
+                    agent_i.phase_termination_strategy = Arc::clone(&phase_termination_strategy);
+                    agent_i.logging_level = ll_i;
+                    agent_i.observers = observers_arc.clone();
+                    agent_i.observers_count = oc_i;
+                    agent_i.initial_individual = ci_i;
+                    match pareto_archive_size {
+                        Some(archive_size) => agent_i.pareto_archive = Some(ParetoArchive::new(archive_size)),
+                        None => (),
+                    }
+                    match random_seed {
+                        Some(seed) => agent_i.set_random_seed(seed.wrapping_add((agent_ids_offset + ai_i) as u64)),
+                        None => (),
+                    }
+                
+                    //env::set_var("POLARS_MAX_THREADS",  (24 * n_jobs).to_string());
+                    match agent_i.solve() {
+                        Ok(_) => (),
+                        Err(solving_error) => {
+                            termination_flag.store(true, Ordering::SeqCst);
+                            return Err(solving_error);
+                        }
+                    }
+
+                    return Ok((agent_i.step_id, agent_i.score_requester, agent_i.pareto_archive));
+                }));
+                match agent_result {
+                    Ok(agent_result) => return agent_result,
+                    Err(agent_panic) => {
+                        // status of the panicked agent stays alive too, neighbours must not wait for it
+                        termination_flag.store(true, Ordering::SeqCst);
+                        std::panic::resume_unwind(agent_panic);
                     }
                 }
+            })).collect();
+
+            return agents_handles.into_iter()
+                .map(|agent_handle| agent_handle.join().unwrap_or_else(|agent_panic| std::panic::resume_unwind(agent_panic)))
+                .collect::<Result<Vec<(u64, OOPScoreRequester<EntityVariants, UtilityObjectVariants, ScoreType>, Option<ParetoArchive<ScoreType>>)>, GreyjackError>>();
+            })?;
+
+            for (steps_count, score_requester, agent_archive) in agents_results {
+                agents_steps_counts.push(steps_count);
+                score_requesters_pool.push(score_requester);
+                match &mut merged_archive {
+                    Some(merged_archive) => merged_archive.merge(agent_archive.unwrap()),
+                    None => (),
+                }
             }
-            let cotwin_i = cb_i.build_cotwin(domain_i, is_already_initialized);
-            let mut agent_i;
-            match ab_i {
-                AgentBuildersVariants::GA(ga_builder) => agent_i = ga_builder.build_agent(cotwin_i),
-                AgentBuildersVariants::LA(la_builder) => agent_i = la_builder.build_agent(cotwin_i),
-                AgentBuildersVariants::TS(ts_builder) => agent_i = ts_builder.build_agent(cotwin_i),
-                AgentBuildersVariants::SA(sa_builder) => agent_i = sa_builder.build_agent(cotwin_i),
-                AgentBuildersVariants::LSH(lsd_builder) => agent_i = lsd_builder.build_agent(cotwin_i),
+        }
+
+        let global_top_individual = global_top_individual.lock().unwrap().clone();
+        // all agents share the same variables layout, so any manager can decode the global top
+        let variables_manager = &score_requesters_pool[0].variables_manager;
+        let variable_values = variables_manager.get_named_variables(&global_top_individual.variable_values);
+
+        let is_solver_strategy_accomplished;
+        match &*solver_termination_strategy.lock().unwrap() {
+            Some(strategy) => is_solver_strategy_accomplished = strategy.is_accomplish(),
+            None => is_solver_strategy_accomplished = false,
+        }
+
+        let termination_reason;
+        if is_solver_strategy_accomplished {
+            termination_reason = TerminationReason::SolverTerminationStrategy;
+        } else if termination_flag.load(Ordering::SeqCst) {
+            termination_reason = TerminationReason::TerminatedEarly;
+        } else {
+            termination_reason = TerminationReason::AgentsTerminationStrategies;
+        }
+
+        let solving_time_milliseconds = Utc::now().timestamp_millis() - solving_start;
+        let mut pareto_front: Vec<(Vec<(String, AnyValue<'static>)>, ScoreType)> = Vec::new();
+        match &merged_archive {
+            Some(merged_archive) => {
+                pareto_front = merged_archive.get_front().into_iter()
+                                .map(|individual| (variables_manager.get_named_variables(&individual.variable_values), individual.score))
+                                .collect();
             }
-            agent_i.agent_id = ai_i;
-            agent_i.score_precision = sp_i;
-            agent_i.round_robin_status_vec = rrs_i;
-            agent_i.alive_agents_count = n_jobs;
-            agent_i.updates_to_agent_sender = Some(us_i);
-            agent_i.updates_for_agent_receiver = Some(rc_i);
-            agent_i.global_top_individual = Arc::clone(&global_top_individual);
-            agent_i.global_top_json = Arc::clone(&global_top_json);
-            agent_i.logging_level = ll_i;
-            agent_i.observers = observers_arc.clone();
-            agent_i.observers_count = oc_i;
-            
-            //env::set_var("POLARS_MAX_THREADS",  (24 * n_jobs).to_string());
-            agent_i.solve();
+            None => ()
+        }
+
+        // overrides are the same for all agents
+        let constraint_weights = score_requesters_pool[0].cotwin.get_constraint_weights();
+        // cotwins are reused by phases, so their stats already cover the whole solving
+        let constraint_timing_stats = ConstraintTimingStats::merge(score_requesters_pool.iter_mut().map(|score_requester| score_requester.cotwin.get_timing_stats()).collect());
+        let solver_result = SolverResult::new(global_top_individual, variable_values, agents_steps_counts, solving_time_milliseconds, termination_reason)
+                            .with_constraint_timing_stats(constraint_timing_stats)
+                            .with_pareto_front(pareto_front)
+                            .with_constraint_weights(constraint_weights);
+
+        return Ok(solver_result);
+
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::MigrationTopologies;
+    use crate::agents::{LateAcceptance, TabuSearch};
+    use crate::agents::AgentBuildersVariants::*;
+    use crate::agents::termination_strategies::StepsLimit;
+    use crate::agents::termination_strategies::TerminationStrategiesVariants::*;
+    use crate::cotwin::CotwinValueTypes;
+    use crate::domain::SolutionView;
+    use crate::score_calculation::score_calculators::{PlainScoreCalculator, ScoreCalculatorVariants};
+    use crate::score_calculation::scores::SimpleScore;
+    use crate::variables::GJInteger;
+    use polars::prelude::*;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    struct CotItem {
+        size: i64,
+        bin_id: GJInteger,
+    }
+
+    impl CotwinEntityTrait for CotItem {
+        fn to_vec(&self) -> Vec<(String, CotwinValueTypes)> {
+            vec![
+                ("size".to_string(), CotwinValueTypes::PAV(AnyValue::Int64(self.size))),
+                ("bin_id".to_string(), CotwinValueTypes::GJI(self.bin_id.clone())),
+            ]
+        }
+    }
+
+    // (size, bin_id) of items, that are packed into 3 bins with capacity 10
+    #[derive(Clone)]
+    struct ItemsDomainBuilder {}
+
+    impl DomainBuilderTrait<Vec<(i64, Option<i64>)>> for ItemsDomainBuilder {
+        fn build_domain_from_scratch(&self) -> Vec<(i64, Option<i64>)> {
+            vec![3, 3, 4, 4, 5, 5, 6].into_iter().map(|size| (size, None)).collect()
+        }
+
+        fn build_from_solution(&self, solution: &Value, _initial_domain: Option<Vec<(i64, Option<i64>)>>) -> Vec<(i64, Option<i64>)> {
+            let solution_view = SolutionView::from_raw_solution(solution).unwrap();
+            let mut domain = self.build_domain_from_scratch();
+            domain.iter_mut().enumerate().for_each(|(i, (_, bin_id))| {
+                match solution_view.get_value("items", i, "bin_id") {
+                    Some(AnyValue::Int64(solved_bin_id)) => *bin_id = Some(*solved_bin_id),
+                    _ => panic!("Solution doesn't contain bin_id of item {}", i),
+                }
+            });
+            return domain;
+        }
+    }
+
+    #[derive(Clone)]
+    struct ItemsCotwinBuilder {}
+
+    impl CotwinBuilderTrait<Vec<(i64, Option<i64>)>, CotItem, (), SimpleScore> for ItemsCotwinBuilder {
+        fn build_cotwin(&self, domain: Vec<(i64, Option<i64>)>, _is_already_initialized: bool) -> Result<Cotwin<CotItem, (), SimpleScore>, GreyjackError> {
+            let items: Vec<CotItem> = domain.into_iter()
+                .map(|(size, bin_id)| CotItem { size: size, bin_id: GJInteger::new(bin_id, 0, 2, false, None) })
+                .collect();
+
+            let mut cotwin = Cotwin::new();
+            cotwin.add_planning_entities("items".to_string(), items);
+            let mut score_calculator: PlainScoreCalculator<(), SimpleScore> = PlainScoreCalculator::new();
+            score_calculator.add_constraint("bins_overflow".to_string(), Box::new(|planning_entity_dfs, _, _| {
+                let items_df = &planning_entity_dfs["items"];
+                let sample_ids: Vec<usize> = items_df["sample_id"].u64().unwrap().into_no_null_iter().map(|x| x as usize).collect();
+                let bin_ids: Vec<i64> = items_df["bin_id"].i64().unwrap().into_no_null_iter().collect();
+                let sizes: Vec<i64> = items_df["size"].i64().unwrap().into_no_null_iter().collect();
+
+                let mut loads: HashMap<(usize, i64), i64> = HashMap::new();
+                (0..sample_ids.len()).for_each(|i| *loads.entry((sample_ids[i], bin_ids[i])).or_insert(0) += sizes[i]);
+                let mut scores = vec![SimpleScore::new(0.0); sample_ids.iter().max().unwrap() + 1];
+                loads.iter().for_each(|((sample_id, _), load)| scores[*sample_id] += SimpleScore::new((load - 10).max(0) as f64));
+                return scores;
+            }));
+            cotwin.add_score_calculator(ScoreCalculatorVariants::PSC(score_calculator));
+
+            return Ok(cotwin);
+        }
+    }
+
+    // solving runs inside a rayon pool of pool_size threads, hanging solving fails the test instead of blocking it
+    fn solve_in_pool(solver_config: SolverConfig<Vec<(i64, Option<i64>)>, SimpleScore>, pool_size: usize) -> Result<SolverResult<SimpleScore>, GreyjackError> {
+        let (result_sender, result_receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let rayon_pool = rayon::ThreadPoolBuilder::new().num_threads(pool_size).build().unwrap();
+            let solver_result = rayon_pool.install(|| Solver::solve(ItemsDomainBuilder {}, ItemsCotwinBuilder {}, solver_config));
+            result_sender.send(solver_result).ok();
         });
+        return result_receiver.recv_timeout(Duration::from_secs(120)).expect("Solving hangs");
+    }
+
+    #[test]
+    fn test_solve_with_more_agents_than_pool_threads() {
+
+        let topologies = vec![MigrationTopologies::Ring, MigrationTopologies::BidirectionalRing, MigrationTopologies::Star,
+                              MigrationTopologies::FullyConnected, MigrationTopologies::RandomK(2)];
+        for migration_topology in topologies {
+            let agent_builder = TS(TabuSearch::new(8, 0.0, true, None, None, 2, StL(StepsLimit::new(30))));
+            let solver_config = SolverConfig::new(agent_builder)
+                                .with_n_jobs(4)
+                                .with_agent_team(LA(LateAcceptance::new(8, 0.0, None, None, 3, StL(StepsLimit::new(60)))), 2)
+                                .with_migration_topology(migration_topology)
+                                .with_logging_level(SolverLoggingLevels::Silent);
+
+            let solver_result = solve_in_pool(solver_config, 1).unwrap();
+            assert_eq!(solver_result.agents_steps_counts.len(), 6);
+            assert_eq!(solver_result.termination_reason, TerminationReason::AgentsTerminationStrategies);
+            assert_eq!(solver_result.score <= SimpleScore::new(6.0), true);
+        }
+    }
+
+    #[test]
+    fn test_solver_steps_limit_counts_steps_of_agents() {
+
+        // agents of the team die after the first step, their idle loops mustn't be counted by the solver-wide limit
+        let agent_builder = TS(TabuSearch::new(64, 0.0, true, None, None, 1, StL(StepsLimit::new(100000))));
+        let solver_config = SolverConfig::new(agent_builder)
+                            .with_agent_team(LA(LateAcceptance::new(8, 0.0, None, None, 1, StL(StepsLimit::new(1)))), 6)
+                            .with_migration_topology(MigrationTopologies::FullyConnected)
+                            .with_termination_strategy(StL(StepsLimit::new(60)))
+                            .with_logging_level(SolverLoggingLevels::Silent);
+
+        let solver_result = solve_in_pool(solver_config, 4).unwrap();
+        assert_eq!(solver_result.termination_reason, TerminationReason::SolverTerminationStrategy);
+        // agents, that are in the middle of a step, when the limit is reached, finish their steps
+        let total_steps_count = solver_result.get_total_steps_count();
+        assert_eq!(total_steps_count >= 60 && total_steps_count < 60 + 7, true);
+    }
+
+    #[test]
+    fn test_explain_score_with_constraint_weight_overrides() {
+
+        let agent_builder = LA(LateAcceptance::new(8, 0.0, None, None, 1, StL(StepsLimit::new(1))));
+        let solver_config = SolverConfig::new(agent_builder)
+                            .with_n_jobs(1)
+                            .with_random_seed(45)
+                            .with_constraint_weight_overrides(HashMap::from([("bins_overflow".to_string(), 3.0)]))
+                            .with_logging_level(SolverLoggingLevels::Silent);
+        let constraint_weight_overrides = solver_config.constraint_weight_overrides.clone();
+
+        let solver_result = solve_in_pool(solver_config, 1).unwrap();
+        let raw_solution = solver_result.get_raw_solution();
+        let score_explanation = Solver::explain_score(ItemsDomainBuilder {}, ItemsCotwinBuilder {}, &raw_solution, &constraint_weight_overrides).unwrap();
+        assert_eq!(score_explanation.score, solver_result.score);
+
+        // without overrides the explained solution has the default weight
+        let score_explanation = Solver::explain_score(ItemsDomainBuilder {}, ItemsCotwinBuilder {}, &raw_solution, &HashMap::new()).unwrap();
+        assert_eq!(score_explanation.score.mul(3.0), solver_result.score);
+    }
 
+    #[test]
+    fn test_solve_with_random_seed_is_repeatable() {
 
-        let solution_json =  global_top_json.lock().unwrap().clone();
-        return solution_json;
+        let build_solver_config = || {
+            let agent_builder = TS(TabuSearch::new(8, 0.0, true, None, None, 5, StL(StepsLimit::new(50))));
+            return SolverConfig::new(agent_builder)
+                   .with_n_jobs(1)
+                   .with_random_seed(45)
+                   .with_logging_level(SolverLoggingLevels::Silent);
+        };
 
+        let solver_result_1 = solve_in_pool(build_solver_config(), 1).unwrap();
+        let solver_result_2 = solve_in_pool(build_solver_config(), 1).unwrap();
+        assert_eq!(solver_result_1.score, solver_result_2.score);
+        assert_eq!(solver_result_1.variable_values, solver_result_2.variable_values);
     }
-}
\ No newline at end of file
+}
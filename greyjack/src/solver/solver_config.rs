@@ -0,0 +1,451 @@
+
+use crate::agents::AgentBuildersVariants;
+use crate::agents::termination_strategies::TerminationStrategiesVariants;
+use crate::construction_heuristics::ConstructionHeuristicsVariants;
+use crate::score_calculation::scores::ScoreTrait;
+use super::ObserverTrait;
+use super::SolverLoggingLevels;
+use super::InitialSolutionVariants;
+use super::Phase;
+use super::AgentTeam;
+use super::MigrationTopologies;
+use crate::errors::GreyjackError;
+
+use std::ops::AddAssign;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/*
+    Settings of Solver::solve() / Solver::start().
+    Can be built in code:
+        SolverConfig::new(agent_builder).with_n_jobs(10).with_score_precision(vec![0, 0, 3])
+    or read from JSON / TOML, where agent_builder holds its termination_strategy:
+        n_jobs = 10
+        score_precision = [0, 0, 3]
+        logging_level = "FreshOnly"
+        random_seed = 42
+        migration_topology = "BidirectionalRing"
+        pareto_archive_size = 50
+        [agent_builder.TS]
+        neighbours_count = 128
+        ...
+        [agent_builder.TS.termination_strategy.SNI]
+        limit_milliseconds = 5000
+        [[agent_teams]]
+        n_jobs = 2
+        [agent_teams.agent_builder.LSH]
+        ...
+        [termination_strategy.TSL]
+        limit_milliseconds = 60000
+        [constraint_weight_overrides]
+        minimize_distance = 2.0
+        [construction_heuristic.FF]
+        max_values_count = 128
+        [[phases]]
+        n_jobs = 4
+        [phases.agent_builder.LA]
+        ...
+        [phases.termination_strategy.SNI]
+        limit_milliseconds = 10000
+    Optional termination_strategy is solver-wide: it observes the global best individual and the global clock
+    and stops all agents at once (agents' own strategies keep working too).
+    migration_topology sets, which agents exchange migrants (see MigrationTopologies), it's the same for all phases.
+    Optional pareto_archive_size turns on collecting of non-dominated solutions (SolverResult::pareto_front),
+    it makes sense for MultiObjectiveScore.
+    constraint_weight_overrides replace weights, set in cotwin builders, in score calculators of all agents.
+    Optional construction_heuristic builds one solution before agents start, it becomes the first individual of all agents.
+    agent_teams add agents of other metaheuristics to agents of agent_builder (see AgentTeam).
+    agent_builder, n_jobs and agent_teams set the first phase of solving, phases are run after it one by one,
+    each next phase continues from the global best solution (see Phase).
+    Observers and initial solution are runtime objects, so they can be set only in code.
+*/
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "ScoreType: Deserialize<'de>"))]
+pub struct SolverConfig<DomainType, ScoreType>
+where
+    DomainType: Clone + Send,
+    ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
+    pub agent_builder: AgentBuildersVariants<ScoreType>,
+    #[serde(default = "default_n_jobs")]
+    pub n_jobs: usize,
+    #[serde(default)]
+    pub agent_teams: Vec<AgentTeam<ScoreType>>,
+    #[serde(default)]
+    pub score_precision: Option<Vec<u64>>,
+    #[serde(default = "default_logging_level")]
+    pub logging_level: SolverLoggingLevels,
+    #[serde(default)]
+    pub random_seed: Option<u64>,
+    #[serde(default = "default_migration_topology")]
+    pub migration_topology: MigrationTopologies,
+    #[serde(default)]
+    pub termination_strategy: Option<TerminationStrategiesVariants<ScoreType>>,
+    #[serde(default)]
+    pub pareto_archive_size: Option<usize>,
+    #[serde(default)]
+    pub constraint_weight_overrides: HashMap<String, f64>,
+    #[serde(default)]
+    pub construction_heuristic: Option<ConstructionHeuristicsVariants>,
+    #[serde(default)]
+    pub phases: Vec<Phase<ScoreType>>,
+    #[serde(skip)]
+    pub observers: Option<Vec<Box<dyn ObserverTrait + Send>>>,
+    #[serde(skip)]
+    pub initial_solution: Option<InitialSolutionVariants<DomainType>>,
+}
+
+fn default_n_jobs() -> usize {
+    1
+}
+
+fn default_logging_level() -> SolverLoggingLevels {
+    SolverLoggingLevels::Info
+}
+
+fn default_migration_topology() -> MigrationTopologies {
+    MigrationTopologies::Ring
+}
+
+impl<DomainType, ScoreType> SolverConfig<DomainType, ScoreType>
+where
+    DomainType: Clone + Send,
+    ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize {
+
+    pub fn new(agent_builder: AgentBuildersVariants<ScoreType>) -> Self {
+        Self {
+            agent_builder: agent_builder,
+            n_jobs: default_n_jobs(),
+            agent_teams: Vec::new(),
+            score_precision: None,
+            logging_level: default_logging_level(),
+            random_seed: None,
+            migration_topology: default_migration_topology(),
+            termination_strategy: None,
+            pareto_archive_size: None,
+            constraint_weight_overrides: HashMap::new(),
+            construction_heuristic: None,
+            phases: Vec::new(),
+            observers: None,
+            initial_solution: None,
+        }
+    }
+
+    pub fn with_n_jobs(mut self, n_jobs: usize) -> Self {
+        self.n_jobs = n_jobs;
+        return self;
+    }
+
+    // agents of the team solve together with agents of agent_builder, e.g. with_n_jobs(4).with_agent_team(LSH(...), 2)
+    pub fn with_agent_team(mut self, agent_builder: AgentBuildersVariants<ScoreType>, n_jobs: usize) -> Self {
+        self.agent_teams.push(AgentTeam::new(agent_builder, n_jobs));
+        return self;
+    }
+
+    pub fn with_score_precision(mut self, score_precision: Vec<u64>) -> Self {
+        self.score_precision = Some(score_precision);
+        return self;
+    }
+
+    pub fn with_logging_level(mut self, logging_level: SolverLoggingLevels) -> Self {
+        self.logging_level = logging_level;
+        return self;
+    }
+
+    // each agent gets its own generator seeded by random_seed + agent_id, with n_jobs = 1 runs are fully repeatable
+    pub fn with_random_seed(mut self, random_seed: u64) -> Self {
+        self.random_seed = Some(random_seed);
+        return self;
+    }
+
+    pub fn with_migration_topology(mut self, migration_topology: MigrationTopologies) -> Self {
+        self.migration_topology = migration_topology;
+        return self;
+    }
+
+    // StepsLimit as solver-wide strategy counts steps of all agents
+    pub fn with_termination_strategy(mut self, termination_strategy: TerminationStrategiesVariants<ScoreType>) -> Self {
+        self.termination_strategy = Some(termination_strategy);
+        return self;
+    }
+
+    // each agent keeps up to pareto_archive_size non-dominated solutions, archives are merged into the same size
+    pub fn with_pareto_archive(mut self, pareto_archive_size: usize) -> Self {
+        self.pareto_archive_size = Some(pareto_archive_size);
+        return self;
+    }
+
+    // unknown constraint names are reported by Solver, when cotwins are built
+    pub fn with_constraint_weight_overrides(mut self, constraint_weight_overrides: HashMap<String, f64>) -> Self {
+        self.constraint_weight_overrides = constraint_weight_overrides;
+        return self;
+    }
+
+    pub fn with_constraint_weight(mut self, constraint_name: &str, constraint_weight: f64) -> Self {
+        self.constraint_weight_overrides.insert(constraint_name.to_string(), constraint_weight);
+        return self;
+    }
+
+    pub fn with_construction_heuristic(mut self, construction_heuristic: ConstructionHeuristicsVariants) -> Self {
+        self.construction_heuristic = Some(construction_heuristic);
+        return self;
+    }
+
+    pub fn with_phase(mut self, phase: Phase<ScoreType>) -> Self {
+        self.phases.push(phase);
+        return self;
+    }
+
+    pub fn with_phases(mut self, phases: Vec<Phase<ScoreType>>) -> Self {
+        self.phases = phases;
+        return self;
+    }
+
+    pub fn with_observer(mut self, observer: Box<dyn ObserverTrait + Send>) -> Self {
+        match &mut self.observers {
+            Some(observers) => observers.push(observer),
+            None => self.observers = Some(vec![observer]),
+        }
+        return self;
+    }
+
+    pub fn with_observers(mut self, observers: Vec<Box<dyn ObserverTrait + Send>>) -> Self {
+        self.observers = Some(observers);
+        return self;
+    }
+
+    pub fn with_initial_solution(mut self, initial_solution: InitialSolutionVariants<DomainType>) -> Self {
+        self.initial_solution = Some(initial_solution);
+        return self;
+    }
+
+    pub fn validate(&self) -> Result<(), GreyjackError> {
+
+        if self.n_jobs == 0 {
+            return Err(GreyjackError::InvalidConfig("n_jobs must be greater than 0".to_string()));
+        }
+
+        match &self.score_precision {
+            Some(precision) => {
+                if precision.len() != ScoreType::precision_len() {
+                    return Err(GreyjackError::InvalidConfig(format!("Invalid score_precision: expected {} values for current score type, got {}. \
+                                        Suggest: vec![a] for SimpleScore, vec![a, b] for HardSoft, vec![a, b, c] for HardMediumSoft, \
+                                        vec![hard, soft] for BendableScore (applied to all levels), vec![hard, objectives] for MultiObjectiveScore.", 
+                                        ScoreType::precision_len(), precision.len())));
+                }
+            }
+            None => ()
+        }
+
+        match &self.termination_strategy {
+            Some(termination_strategy) => termination_strategy.validate()?,
+            None => ()
+        }
+
+        self.migration_topology.validate()?;
+
+        for (constraint_name, constraint_weight) in &self.constraint_weight_overrides {
+            if constraint_weight.is_finite() == false {
+                return Err(GreyjackError::InvalidConfig(format!("weight of constraint \"{}\" must be a finite number, got {}", constraint_name, constraint_weight)));
+            }
+        }
+
+        match &self.construction_heuristic {
+            Some(construction_heuristic) => construction_heuristic.validate()?,
+            None => ()
+        }
+
+        for agent_team in &self.agent_teams {
+            agent_team.validate()?;
+        }
+
+        for phase in &self.phases {
+            phase.validate()?;
+        }
+
+        if self.pareto_archive_size == Some(0) {
+            return Err(GreyjackError::InvalidConfig("pareto_archive_size must be greater than 0".to_string()));
+        }
+
+        return self.agent_builder.validate();
+    }
+}
+
+impl<DomainType, ScoreType> SolverConfig<DomainType, ScoreType>
+where
+    DomainType: Clone + Send,
+    ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Display + Send + Serialize + DeserializeOwned {
+
+    pub fn from_json(config_json: &Value) -> Result<Self, GreyjackError> {
+        let config: Self = serde_json::from_value(config_json.clone()).map_err(|e| GreyjackError::InvalidConfig(format!("Failed to parse solver config: {}", e)))?;
+        config.validate()?;
+        return Ok(config);
+    }
+
+    pub fn from_json_str(config_string: &str) -> Result<Self, GreyjackError> {
+        let config: Self = serde_json::from_str(config_string).map_err(|e| GreyjackError::InvalidConfig(format!("Failed to parse solver config: {}", e)))?;
+        config.validate()?;
+        return Ok(config);
+    }
+
+    pub fn from_toml_str(config_string: &str) -> Result<Self, GreyjackError> {
+        let config: Self = toml::from_str(config_string).map_err(|e| GreyjackError::InvalidConfig(format!("Failed to parse solver config: {}", e)))?;
+        config.validate()?;
+        return Ok(config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::score_calculation::scores::{BendableScore, HardSoftScore};
+
+    #[test]
+    fn test_solver_config_from_toml() {
+
+        let config_string = r#"
+            n_jobs = 4
+            score_precision = [0, 3]
+            logging_level = "Silent"
+            migration_topology = { RandomK = 3 }
+
+            [agent_builder.TS]
+            neighbours_count = 128
+            tabu_entity_rate = 0.2
+            compare_to_global = true
+            move_probas = [0.5, 0.5, 0.0, 0.0, 0.0, 0.0]
+            migration_frequency = 10
+
+            [agent_builder.TS.termination_strategy.SNI]
+            limit_milliseconds = 5000
+
+            [constraint_weight_overrides]
+            minimize_distance = 2.5
+        "#;
+
+        let config = SolverConfig::<Vec<f64>, HardSoftScore>::from_toml_str(config_string).unwrap();
+        assert_eq!(config.n_jobs, 4);
+        assert_eq!(config.score_precision, Some(vec![0, 3]));
+        assert_eq!(matches!(config.logging_level, SolverLoggingLevels::Silent), true);
+        assert_eq!(matches!(config.agent_builder, AgentBuildersVariants::TS(_)), true);
+        assert_eq!(config.constraint_weight_overrides["minimize_distance"], 2.5);
+        assert_eq!(matches!(config.migration_topology, MigrationTopologies::RandomK(3)), true);
+    }
+
+    #[test]
+    fn test_solver_config_validation() {
+
+        let config_string = r#"
+            score_precision = [0, 0, 3]
+
+            [agent_builder.LA]
+            late_acceptance_size = 10
+            tabu_entity_rate = 0.2
+            migration_frequency = 10
+
+            [agent_builder.LA.termination_strategy.StL]
+            steps_limit = 100
+        "#;
+        let config_error = SolverConfig::<Vec<f64>, HardSoftScore>::from_toml_str(config_string).err().unwrap();
+        assert_eq!(config_error.to_string().contains("score_precision"), true);
+        let config_error = SolverConfig::<Vec<f64>, BendableScore>::from_toml_str(config_string).err().unwrap();
+        assert_eq!(config_error.to_string().contains("BendableScore"), true);
+        let config_string = config_string.replace("[0, 0, 3]", "[0, 3]");
+        assert_eq!(SolverConfig::<Vec<f64>, BendableScore>::from_toml_str(&config_string).is_ok(), true);
+
+        let config_string = r#"{
+            "n_jobs": 2,
+            "agent_builder": {"LA": {
+                "late_acceptance_size": 10, "tabu_entity_rate": 0.2, "move_probas": [0.5, 0.5],
+                "migration_frequency": 10, "termination_strategy": {"StL": {"steps_limit": 100}}
+            }}
+        }"#;
+        let config_error = SolverConfig::<Vec<f64>, HardSoftScore>::from_json_str(config_string).err().unwrap();
+        assert_eq!(config_error.to_string().contains("move_probas"), true);
+    }
+
+    #[test]
+    fn test_solver_config_phases() {
+
+        let config_string = r#"
+            n_jobs = 2
+
+            [agent_builder.TS]
+            neighbours_count = 128
+            tabu_entity_rate = 0.2
+            compare_to_global = true
+            migration_frequency = 10
+            [agent_builder.TS.termination_strategy.TSL]
+            limit_milliseconds = 30000
+
+            [[phases]]
+            n_jobs = 4
+            [phases.agent_builder.LA]
+            late_acceptance_size = 10
+            tabu_entity_rate = 0.2
+            migration_frequency = 10
+            [phases.agent_builder.LA.termination_strategy.StL]
+            steps_limit = 1000
+            [phases.termination_strategy.SNI]
+            limit_milliseconds = 10000
+
+            [[phases]]
+            [phases.agent_builder.LA]
+            late_acceptance_size = 100
+            tabu_entity_rate = 0.0
+            migration_frequency = 10
+            [phases.agent_builder.LA.termination_strategy.StL]
+            steps_limit = 1000
+        "#;
+
+        let config = SolverConfig::<Vec<f64>, HardSoftScore>::from_toml_str(config_string).unwrap();
+        assert_eq!(config.phases.len(), 2);
+        assert_eq!(config.phases[0].n_jobs, 4);
+        assert_eq!(config.phases[0].termination_strategy.is_some(), true);
+        assert_eq!(config.phases[1].n_jobs, 1);
+        assert_eq!(config.phases[1].termination_strategy.is_none(), true);
+
+        let config_string = config_string.replace("n_jobs = 4", "n_jobs = 0");
+        let config_error = SolverConfig::<Vec<f64>, HardSoftScore>::from_toml_str(&config_string).err().unwrap();
+        assert_eq!(config_error.to_string().contains("Phase"), true);
+    }
+
+    #[test]
+    fn test_solver_config_agent_teams() {
+
+        let config_string = r#"
+            n_jobs = 2
+
+            [agent_builder.TS]
+            neighbours_count = 128
+            tabu_entity_rate = 0.2
+            compare_to_global = true
+            migration_frequency = 10
+            [agent_builder.TS.termination_strategy.TSL]
+            limit_milliseconds = 30000
+
+            [[agent_teams]]
+            n_jobs = 3
+            [agent_teams.agent_builder.LA]
+            late_acceptance_size = 10
+            tabu_entity_rate = 0.2
+            migration_frequency = 100
+            [agent_teams.agent_builder.LA.termination_strategy.StL]
+            steps_limit = 1000
+        "#;
+
+        let config = SolverConfig::<Vec<f64>, HardSoftScore>::from_toml_str(config_string).unwrap();
+        assert_eq!(config.agent_teams.len(), 1);
+        let phase = Phase {agent_builder: config.agent_builder, n_jobs: config.n_jobs, agent_teams: config.agent_teams, termination_strategy: None};
+        let agent_builders = phase.get_agent_builders();
+        assert_eq!(agent_builders.len(), 5);
+        assert_eq!(matches!(agent_builders[1], AgentBuildersVariants::TS(_)), true);
+        assert_eq!(matches!(agent_builders[2], AgentBuildersVariants::LA(_)), true);
+
+        let config_string = config_string.replace("n_jobs = 3", "n_jobs = 0");
+        let config_error = SolverConfig::<Vec<f64>, HardSoftScore>::from_toml_str(&config_string).err().unwrap();
+        assert_eq!(config_error.to_string().contains("AgentTeam"), true);
+    }
+}
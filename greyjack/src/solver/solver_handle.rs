@@ -0,0 +1,58 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use serde_json::Value;
+use std::ops::AddAssign;
+use std::fmt::Debug;
+use crate::score_calculation::scores::ScoreTrait;
+use super::SolverResult;
+use crate::errors::GreyjackError;
+
+/*
+    Returned by Solver::start(). Solving runs in a background thread, so a caller (HTTP handler, UI, etc.)
+    can ask for the current best solution at any moment and stop solving gracefully by terminate_early().
+    Agents finish their current step, publish their best individuals and stop all together.
+*/
+pub struct SolverHandle<ScoreType>
+where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {
+    termination_flag: Arc<AtomicBool>,
+    global_top_json: Arc<Mutex<Value>>,
+    solving_thread: JoinHandle<Result<SolverResult<ScoreType>, GreyjackError>>,
+}
+
+impl<ScoreType> SolverHandle<ScoreType>
+where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {
+
+    pub fn new(
+        termination_flag: Arc<AtomicBool>,
+        global_top_json: Arc<Mutex<Value>>,
+        solving_thread: JoinHandle<Result<SolverResult<ScoreType>, GreyjackError>>
+    ) -> Self {
+        Self {
+            termination_flag: termination_flag,
+            global_top_json: global_top_json,
+            solving_thread: solving_thread,
+        }
+    }
+
+    pub fn terminate_early(&self) {
+        self.termination_flag.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_solving(&self) -> bool {
+        return !self.solving_thread.is_finished();
+    }
+
+    // Snapshot of the current global best solution in the raw format (SolverResult::get_raw_solution()).
+    // Value::Null until the first agent publishes its best individual.
+    pub fn best_solution(&self) -> Value {
+        return self.global_top_json.lock().unwrap().clone();
+    }
+
+    pub fn join(self) -> Result<SolverResult<ScoreType>, GreyjackError> {
+        match self.solving_thread.join() {
+            Ok(solution) => return solution,
+            Err(panic_payload) => std::panic::resume_unwind(panic_payload),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 
 
-#[derive(Clone)]
+use serde::{Serialize, Deserialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum SolverLoggingLevels {
     Info,
     FreshOnly,
@@ -0,0 +1,179 @@
+
+use crate::agents::base::Individual;
+use crate::score_calculation::scores::ScoreTrait;
+use crate::score_calculation::score_calculators::ConstraintTimingStats;
+use super::InitialSolutionVariants;
+use super::TerminationReason;
+use crate::errors::GreyjackError;
+use crate::domain::SolutionView;
+
+use std::ops::AddAssign;
+use std::fmt::Debug;
+use polars::prelude::*;
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use serde_json::Value;
+
+/*
+    Returned by Solver::solve() and SolverHandle::join().
+    variable_values keeps the same (name, value) pairs as the raw solution, so get_raw_solution()
+    can be passed to DomainBuilderTrait::build_from_solution() as before.
+    pareto_front - non-dominated solutions (in the same format as variable_values and score),
+    filled only with SolverConfig::with_pareto_archive().
+    constraint_weights - effective weights of constraints (after SolverConfig::constraint_weight_overrides).
+    constraint_timing_stats - running time of constraints and prescoring functions summed over all agents.
+*/
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SolverResult<ScoreType>
+where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {
+    pub best_individual: Individual<ScoreType>,
+    pub variable_values: Vec<(String, AnyValue<'static>)>,
+    pub score: ScoreType,
+    pub agents_steps_counts: Vec<u64>,
+    pub solving_time_milliseconds: i64,
+    pub termination_reason: TerminationReason,
+    #[serde(default)]
+    pub constraint_timing_stats: Vec<ConstraintTimingStats>,
+    #[serde(default)]
+    pub constraint_weights: Vec<(String, f64)>,
+    #[serde(default = "Vec::new")]
+    pub pareto_front: Vec<(Vec<(String, AnyValue<'static>)>, ScoreType)>,
+}
+
+impl<ScoreType> SolverResult<ScoreType>
+where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send + Serialize {
+
+    pub fn new(
+        best_individual: Individual<ScoreType>,
+        variable_values: Vec<(String, AnyValue<'static>)>,
+        agents_steps_counts: Vec<u64>,
+        solving_time_milliseconds: i64,
+        termination_reason: TerminationReason,
+    ) -> Self {
+        Self {
+            score: best_individual.score.clone(),
+            best_individual: best_individual,
+            variable_values: variable_values,
+            agents_steps_counts: agents_steps_counts,
+            solving_time_milliseconds: solving_time_milliseconds,
+            termination_reason: termination_reason,
+            constraint_timing_stats: Vec::new(),
+            constraint_weights: Vec::new(),
+            pareto_front: Vec::new(),
+        }
+    }
+
+    pub fn with_constraint_timing_stats(mut self, constraint_timing_stats: Vec<ConstraintTimingStats>) -> Self {
+        self.constraint_timing_stats = constraint_timing_stats;
+        return self;
+    }
+
+    pub fn with_constraint_weights(mut self, constraint_weights: Vec<(String, f64)>) -> Self {
+        self.constraint_weights = constraint_weights;
+        return self;
+    }
+
+    pub fn with_pareto_front(mut self, pareto_front: Vec<(Vec<(String, AnyValue<'static>)>, ScoreType)>) -> Self {
+        self.pareto_front = pareto_front;
+        return self;
+    }
+
+    // raw solutions of the front, each one can be passed to DomainBuilderTrait::build_from_solution()
+    pub fn get_pareto_front_raw_solutions(&self) -> Vec<Value> {
+        return self.pareto_front.iter().map(|(variable_values, score)| json!((variable_values, score))).collect();
+    }
+
+    // json string to send via http, can be restored by from_json_str()
+    pub fn to_json(&self) -> Result<String, GreyjackError> {
+        let solver_result_json = serde_json::to_string(self)?;
+        return Ok(solver_result_json);
+    }
+
+    // (Vec<(String, AnyValue)>, ScoreType) tuple, the format of solutions published to observers
+    pub fn get_raw_solution(&self) -> Value {
+        return json!((&self.variable_values, &self.score));
+    }
+
+    pub fn get_solution_view(&self) -> Result<SolutionView, GreyjackError> {
+        return SolutionView::new(self.variable_values.clone(), serde_json::to_value(&self.score)?);
+    }
+
+    pub fn get_total_steps_count(&self) -> u64 {
+        return self.agents_steps_counts.iter().sum();
+    }
+
+    /*
+        InitialSolutionVariants::CotwinValuesVector accepts both a raw solution
+        and a serialized SolverResult. Domain builders always receive the raw one.
+    */
+    pub fn normalize_raw_solution(solution: &Value) -> Value {
+        match solution {
+            Value::Object(fields) if fields.contains_key("variable_values") && fields.contains_key("score") => {
+                return json!((&fields["variable_values"], &fields["score"]));
+            }
+            _ => return solution.clone(),
+        }
+    }
+}
+
+impl<ScoreType> SolverResult<ScoreType>
+where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send + Serialize + DeserializeOwned {
+
+    pub fn from_json(solution: &Value) -> Result<Self, GreyjackError> {
+        let solver_result = serde_json::from_value(solution.clone())?;
+        return Ok(solver_result);
+    }
+
+    pub fn from_json_str(solution_string: &str) -> Result<Self, GreyjackError> {
+        let solver_result = serde_json::from_str(solution_string)?;
+        return Ok(solver_result);
+    }
+}
+
+impl<ScoreType, DomainType> From<SolverResult<ScoreType>> for InitialSolutionVariants<DomainType>
+where 
+ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send + Serialize,
+DomainType: Clone + Send {
+    fn from(solver_result: SolverResult<ScoreType>) -> Self {
+        return InitialSolutionVariants::CotwinValuesVector(solver_result.get_raw_solution());
+    }
+}
+
+unsafe impl<ScoreType> Send for SolverResult<ScoreType>
+where ScoreType: ScoreTrait + Clone + AddAssign + PartialEq + PartialOrd + Ord + Debug + Send {
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::score_calculation::scores::HardSoftScore;
+
+    #[test]
+    fn test_solver_result_json_round_trip() {
+
+        let best_individual = Individual::new(vec![0.1 + 0.2, 3.0], HardSoftScore::new(-1.0, -123.456789012345));
+        let variable_values = vec![
+            ("group: 0-->x".to_string(), AnyValue::Float64(0.1 + 0.2)),
+            ("group: 1-->y".to_string(), AnyValue::Int64(3)),
+        ];
+        let solver_result = SolverResult::new(best_individual, variable_values, vec![10, 20], 1500, TerminationReason::TerminatedEarly);
+
+        let solver_result_json = solver_result.to_json().unwrap();
+        let restored_result = SolverResult::<HardSoftScore>::from_json_str(&solver_result_json).unwrap();
+        assert_eq!(restored_result.best_individual.variable_values, solver_result.best_individual.variable_values);
+        assert_eq!(restored_result.variable_values, solver_result.variable_values);
+        assert_eq!(restored_result.score, solver_result.score);
+        assert_eq!(restored_result.agents_steps_counts, vec![10, 20]);
+        assert_eq!(restored_result.get_total_steps_count(), 30);
+        assert_eq!(restored_result.solving_time_milliseconds, 1500);
+        assert_eq!(restored_result.termination_reason, TerminationReason::TerminatedEarly);
+
+        let solver_result_value: Value = serde_json::from_str(&solver_result_json).unwrap();
+        assert_eq!(SolverResult::<HardSoftScore>::from_json(&solver_result_value).unwrap().score, solver_result.score);
+        let raw_solution = SolverResult::<HardSoftScore>::normalize_raw_solution(&solver_result_value);
+        assert_eq!(raw_solution, solver_result.get_raw_solution());
+        assert_eq!(SolverResult::<HardSoftScore>::normalize_raw_solution(&raw_solution), raw_solution);
+    }
+}
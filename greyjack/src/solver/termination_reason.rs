@@ -0,0 +1,12 @@
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TerminationReason {
+    // every agent accomplished its own termination strategy
+    AgentsTerminationStrategies,
+    // solving was stopped by SolverHandle::terminate_early()
+    TerminatedEarly,
+    // solver-wide termination strategy (SolverConfig::with_termination_strategy()) was accomplished
+    SolverTerminationStrategy,
+}
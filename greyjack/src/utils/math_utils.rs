@@ -1,7 +1,8 @@
 
-use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use rand::{rngs::StdRng, seq::SliceRandom};
 use rand_distr::{Distribution, Uniform};
 use std::{collections::HashSet, hash::Hash};
+use crate::errors::GreyjackError;
 
 pub fn rint(x: f64) -> f64 {
     if (x - x.floor()).abs() < (x.ceil() - x).abs() {x.floor()} else {x.ceil()}
@@ -12,39 +13,42 @@ pub fn round(value: f64, precision: u64) -> f64 {
     value.floor() + ((value - value.floor()) * multiplier).floor() / multiplier
 }
 
-pub fn get_random_id(start_id: usize, end_exclusive: usize) -> usize {
-    Uniform::new(start_id, end_exclusive).sample(&mut StdRng::from_entropy())
+pub fn get_random_id(start_id: usize, end_exclusive: usize, random_generator: &mut StdRng) -> usize {
+    Uniform::new(start_id, end_exclusive).sample(random_generator)
 }
 
-pub fn choice<T>(objects: &Vec<T>, n: usize, replace: bool) -> Vec<T>
+pub fn choice<T>(objects: &Vec<T>, n: usize, replace: bool, random_generator: &mut StdRng) -> Result<Vec<T>, GreyjackError>
 where T: Clone {
     if replace == true {
-        choice_with_replacement(objects, n)
+        choice_with_replacement(objects, n, random_generator)
     } else {
-        choice_without_replacement(objects, n)
+        choice_without_replacement(objects, n, random_generator)
     }
 }
 
-fn choice_with_replacement<T>(objects: &Vec<T>, n: usize) -> Vec<T>
+fn choice_with_replacement<T>(objects: &Vec<T>, n: usize, random_generator: &mut StdRng) -> Result<Vec<T>, GreyjackError>
 where T: Clone {
     
     let objects_count = objects.len();
-    let chosen_objects: Vec<T> = (0..n).into_iter().map(|i| objects[get_random_id(0, objects_count)].clone()).collect();
-    return chosen_objects;
+    if objects_count == 0 && n > 0 {
+        return Err(GreyjackError::NotEnoughObjects { requested: n, available: 0 });
+    }
+    let chosen_objects: Vec<T> = (0..n).into_iter().map(|i| objects[get_random_id(0, objects_count, random_generator)].clone()).collect();
+    return Ok(chosen_objects);
 }
 
-fn choice_without_replacement<T>(objects: &Vec<T>, n: usize) -> Vec<T>
+fn choice_without_replacement<T>(objects: &Vec<T>, n: usize, random_generator: &mut StdRng) -> Result<Vec<T>, GreyjackError>
 where T: Clone {
 
     if n > objects.len() {
-        panic!("There are less objects tnan can be chosen from collection without replacement");
+        return Err(GreyjackError::NotEnoughObjects { requested: n, available: objects.len() });
     }
     
     let mut random_ids:Vec<usize> = (0..objects.len()).collect();
-    random_ids.shuffle(&mut StdRng::from_entropy());
+    random_ids.shuffle(random_generator);
     let chosen_objects: Vec<T> = (0..n).into_iter().map(|i| objects[random_ids[i]].clone()).collect();
 
-    return chosen_objects;
+    return Ok(chosen_objects);
 }
 
 /*pub fn select_non_tabu_ids<T>(objects: &Vec<T>, n: usize, group: bool) -> Vec<T>
@@ -0,0 +1,117 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand_distr::{Distribution, Uniform};
+use crate::utils::math_utils;
+
+#[derive(Debug, Clone)]
+pub struct GJBool {
+    pub name: String,
+    pub initial_value: Option<f64>,
+    pub lower_bound: f64,
+    pub upper_bound: f64,
+    pub frozen: bool,
+    pub random_generator: StdRng,
+    pub semantic_groups: Vec<String>
+}
+
+impl GJBool {
+    pub fn new(initial_value: Option<bool>, frozen: bool, semantic_groups: Option<Vec<String>>) -> Self {
+
+            let casted_initial_value;
+            match initial_value {
+                Some(x) => casted_initial_value = Some(if x {1.0} else {0.0}),
+                None => casted_initial_value = None
+            }
+
+            let mut current_semantic_groups: Vec<String> = Vec::new();
+            match semantic_groups {
+                None => current_semantic_groups.push("common".to_string()),
+                Some(groups) => {
+                    for group in groups {
+                        current_semantic_groups.push(group);
+                    }
+                },
+            }
+
+            GJBool {
+                name: "".to_string(),
+                initial_value: casted_initial_value,
+                lower_bound: 0.0,
+                upper_bound: 1.0,
+                frozen: frozen,
+                random_generator: StdRng::from_entropy(),
+                semantic_groups: current_semantic_groups
+            }
+        }
+}
+
+impl GJBool {
+
+    pub fn set_name(&mut self, new_name: String) {
+        self.name = new_name;
+    }
+
+    pub fn inverse_transform(&self, value: f64) -> bool {
+        return self.fix(value) == 1.0;
+    }
+
+    pub fn fix(&self, value: f64) -> f64 {
+
+        if self.frozen {
+            match self.initial_value {
+                Some(x) => return x,
+                None => panic!("Frozen value must be initialized")
+            }
+        }
+
+        if value >= 0.5 {
+            return 1.0;
+        } else {
+            return 0.0;
+        }
+    }
+
+    pub fn sample(&mut self) -> f64 {
+
+        if self.frozen {
+            match self.initial_value {
+                Some(x) => return x,
+                None => panic!("Frozen value must be initialized")
+            }
+        }
+
+        return Uniform::new_inclusive(0, 1).sample(&mut self.random_generator) as f64;
+    }
+
+    // the opposite value, mutation of a bool makes sense only this way
+    pub fn get_changed_value(&self, value: f64) -> f64 {
+        return 1.0 - math_utils::rint(value);
+    }
+
+    pub fn get_initial_value(&mut self) -> f64 {
+
+        match self.initial_value {
+            None => return self.sample(),
+            Some(x) => return x,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gj_bool_fix_and_inverse_transform() {
+        let x = GJBool::new(None, false, None);
+
+        assert_eq!(x.fix(0.7), 1.0);
+        assert_eq!(x.fix(-3.0), 0.0);
+        assert_eq!(x.inverse_transform(0.9), true);
+        assert_eq!(x.get_changed_value(1.0), 0.0);
+
+        let mut x = GJBool::new(Some(true), true, None);
+        assert_eq!(x.get_initial_value(), 1.0);
+        assert_eq!(x.fix(0.0), 1.0);
+    }
+}
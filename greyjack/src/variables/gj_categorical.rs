@@ -0,0 +1,144 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand_distr::{Distribution, Uniform};
+use polars::datatypes::AnyValue;
+use crate::utils::math_utils;
+
+/*
+    Choice from an explicit set of values (shift codes, colors, machine names, etc).
+    Inside a sample the variable is the index of the chosen value in categories,
+    inverse transform gives the value itself, so domain builders get native polars values.
+    Values must be owned (AnyValue::StringOwned for strings), because variables live as long as the solver.
+*/
+#[derive(Debug, Clone)]
+pub struct GJCategorical {
+    pub name: String,
+    pub initial_category: Option<AnyValue<'static>>,
+    pub initial_value: Option<f64>,
+    pub categories: Vec<AnyValue<'static>>,
+    pub lower_bound: f64,
+    pub upper_bound: f64,
+    pub frozen: bool,
+    pub random_generator: StdRng,
+    pub semantic_groups: Vec<String>
+}
+
+impl GJCategorical {
+    pub fn new(initial_value: Option<AnyValue<'static>>, categories: Vec<AnyValue<'static>>, frozen: bool, semantic_groups: Option<Vec<String>>) -> Self {
+
+            // None for unknown initial category too, VariablesManager reports it by initial_category
+            let initial_id;
+            match &initial_value {
+                Some(x) => initial_id = categories.iter().position(|category| category == x).map(|i| i as f64),
+                None => initial_id = None
+            }
+
+            let mut current_semantic_groups: Vec<String> = Vec::new();
+            match semantic_groups {
+                None => current_semantic_groups.push("common".to_string()),
+                Some(groups) => {
+                    for group in groups {
+                        current_semantic_groups.push(group);
+                    }
+                },
+            }
+
+            GJCategorical {
+                name: "".to_string(),
+                initial_category: initial_value,
+                initial_value: initial_id,
+                lower_bound: 0.0,
+                upper_bound: categories.len() as f64 - 1.0,
+                categories: categories,
+                frozen: frozen,
+                random_generator: StdRng::from_entropy(),
+                semantic_groups: current_semantic_groups
+            }
+        }
+}
+
+impl GJCategorical {
+
+    pub fn set_name(&mut self, new_name: String) {
+        self.name = new_name;
+    }
+
+    pub fn inverse_transform(&self, value: f64) -> AnyValue<'static> {
+        return self.categories[self.fix(value) as usize].clone();
+    }
+
+    pub fn fix(&self, value: f64) -> f64 {
+
+        if self.frozen {
+            match self.initial_value {
+                Some(x) => return x,
+                None => panic!("Frozen value must be initialized")
+            }
+        }
+
+        let mut fixed_value = math_utils::rint(value);
+        if fixed_value < self.lower_bound {
+            fixed_value = self.lower_bound;
+        } else if fixed_value > self.upper_bound {
+            fixed_value = self.upper_bound;
+        }
+
+        return fixed_value;
+    }
+
+    pub fn sample(&mut self) -> f64 {
+
+        if self.frozen {
+            match self.initial_value {
+                Some(x) => return x,
+                None => panic!("Frozen value must be initialized")
+            }
+        }
+
+        return Uniform::new(0, self.categories.len()).sample(&mut self.random_generator) as f64;
+    }
+
+    // a random category other than the current one
+    pub fn get_changed_value(&self, value: f64, random_generator: &mut StdRng) -> f64 {
+
+        if self.categories.len() <= 1 {
+            return self.fix(value);
+        }
+
+        let current_id = self.fix(value) as usize;
+        let mut changed_id = Uniform::new(0, self.categories.len() - 1).sample(random_generator);
+        if changed_id >= current_id {
+            changed_id += 1;
+        }
+
+        return changed_id as f64;
+    }
+
+    pub fn get_initial_value(&mut self) -> f64 {
+
+        match self.initial_value {
+            None => return self.sample(),
+            Some(x) => return x,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gj_categorical_inverse_transform() {
+        let shifts = vec![AnyValue::StringOwned("D".into()), AnyValue::StringOwned("N".into()), AnyValue::StringOwned("OFF".into())];
+        let x = GJCategorical::new(Some(AnyValue::StringOwned("N".into())), shifts, false, None);
+
+        assert_eq!(x.initial_value, Some(1.0));
+        assert_eq!(x.inverse_transform(2.2), AnyValue::StringOwned("OFF".into()));
+        assert_eq!(x.fix(10.0), 2.0);
+
+        let mut random_generator = StdRng::seed_from_u64(42);
+        for _ in 0..20 {
+            assert_ne!(x.get_changed_value(1.0, &mut random_generator), 1.0);
+        }
+    }
+}
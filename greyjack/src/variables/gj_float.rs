@@ -12,7 +12,6 @@ pub struct GJFloat {
     pub upper_bound: f64,
     pub frozen: bool,
     pub random_generator: StdRng,
-    pub uniform_distribution: Uniform<f64>,
     pub normal_distribution: Option<Normal<f64>>,
     pub semantic_groups: Vec<String>
 }
@@ -44,7 +43,6 @@ impl GJFloat {
                 upper_bound: upper_bound,
                 frozen: frozen,
                 random_generator: StdRng::from_entropy(),
-                uniform_distribution: Uniform::new_inclusive(lower_bound, upper_bound),
                 normal_distribution: normal_distribution,
                 semantic_groups: current_semantic_groups
             }
@@ -84,7 +82,7 @@ impl GJFloat {
             }
         }
 
-        let sampled_value: f64 = self.uniform_distribution.sample( &mut self.random_generator);
+        let sampled_value: f64 = Uniform::new_inclusive(self.lower_bound, self.upper_bound).sample(&mut self.random_generator);
         return sampled_value;
     }
 
@@ -13,9 +13,11 @@ pub struct GJInteger {
     pub upper_bound: f64,
     pub frozen: bool,
     pub random_generator: StdRng,
-    pub uniform_distribution: Uniform<i64>,
     pub normal_distribution: Option<Normal<f64>>,
-    pub semantic_groups: Vec<String>
+    pub semantic_groups: Vec<String>,
+    pub allowed_values: Option<Vec<f64>>,
+    pub allow_unassigned: bool,
+    pub unassigned_probability: f64,
 }
 
 impl GJInteger {
@@ -50,11 +52,40 @@ impl GJInteger {
                 upper_bound: upper_bound as f64,
                 frozen: frozen,
                 random_generator: StdRng::from_entropy(),
-                uniform_distribution: Uniform::new_inclusive(lower_bound, upper_bound),
                 normal_distribution: normal_distribution,
-                semantic_groups: current_semantic_groups
+                semantic_groups: current_semantic_groups,
+                allowed_values: None,
+                allow_unassigned: false,
+                unassigned_probability: 0.0,
             }
         }
+
+    /*
+        Restricts the variable to an explicit set of values inside its bounds
+        (rooms that fit a lesson, vehicles with the skills a customer needs, etc).
+        Sampling and change moves take only allowed values, values after swaps,
+        crossovers and mutations are fixed to the nearest allowed one.
+        Values outside bounds are kept to be reported by VariablesManager::new() as InvalidVariable.
+    */
+    pub fn with_allowed_values(mut self, allowed_values: Vec<i64>) -> Self {
+        let mut current_allowed_values: Vec<f64> = allowed_values.iter().map(|x| *x as f64).collect();
+        current_allowed_values.sort_by(|a, b| a.total_cmp(b));
+        current_allowed_values.dedup();
+        self.allowed_values = Some(current_allowed_values);
+        return self;
+    }
+
+    /*
+        Lets the variable stay unassigned (AnyValue::Null in planning dfs) for overconstrained problems.
+        Inside a sample unassigned is exactly lower_bound - 1.0. Only initial sampling and change moves
+        pick it (with unassigned_probability), so calculators can penalize nulls softly.
+        Other values below lower_bound (after continuous crossovers and mutations) are clamped as usual.
+    */
+    pub fn with_allow_unassigned(mut self, unassigned_probability: f64) -> Self {
+        self.allow_unassigned = true;
+        self.unassigned_probability = unassigned_probability;
+        return self;
+    }
 }
 
 impl GJInteger {
@@ -67,6 +98,14 @@ impl GJInteger {
         return self.fix(value) as i64;
     }
 
+    pub fn get_unassigned_value(&self) -> f64 {
+        return self.lower_bound - 1.0;
+    }
+
+    pub fn is_unassigned(&self, value: f64) -> bool {
+        return self.allow_unassigned && self.fix(value) == self.get_unassigned_value();
+    }
+
     pub fn fix(&self, value: f64) -> f64 {
 
         if self.frozen {
@@ -76,12 +115,62 @@ impl GJInteger {
             }
         }
 
+        if self.allow_unassigned && value == self.get_unassigned_value() {
+            return self.get_unassigned_value();
+        }
+
         let mut fixed_value = Self::min(Self::max(value, self.lower_bound), self.upper_bound);
         fixed_value = math_utils::rint(fixed_value);
+        if let Some(allowed_values) = &self.allowed_values {
+            fixed_value = Self::get_nearest_allowed_value(allowed_values, fixed_value);
+        }
 
         return fixed_value;
     }
 
+    fn get_nearest_allowed_value(allowed_values: &Vec<f64>, value: f64) -> f64 {
+
+        let insert_id = allowed_values.partition_point(|x| *x < value);
+        if insert_id == 0 {
+            return allowed_values[0];
+        }
+        if insert_id == allowed_values.len() {
+            return allowed_values[allowed_values.len() - 1];
+        }
+
+        let (left_value, right_value) = (allowed_values[insert_id - 1], allowed_values[insert_id]);
+        if value - left_value <= right_value - value {
+            return left_value;
+        } else {
+            return right_value;
+        }
+    }
+
+    pub fn get_out_of_bounds_allowed_values(&self) -> Vec<f64> {
+        match &self.allowed_values {
+            None => return Vec::new(),
+            Some(allowed_values) => return allowed_values.iter().filter(|x| **x < self.lower_bound || **x > self.upper_bound).cloned().collect(),
+        }
+    }
+
+    pub fn is_allowed_value(&self, value: f64) -> bool {
+        match &self.allowed_values {
+            None => return value >= self.lower_bound && value <= self.upper_bound,
+            Some(allowed_values) => return allowed_values.contains(&value),
+        }
+    }
+
+    // random value for moves, the variable's own generator is used only for initial sampling
+    pub fn get_random_value(&self, random_generator: &mut StdRng) -> Option<f64> {
+        if self.allow_unassigned && Uniform::new(0.0, 1.0).sample(random_generator) < self.unassigned_probability {
+            return Some(self.get_unassigned_value());
+        }
+        match &self.allowed_values {
+            None => return None,
+            Some(allowed_values) => return Some(allowed_values[Uniform::new(0, allowed_values.len()).sample(random_generator)]),
+        }
+    }
+
     pub fn sample(&mut self) -> f64 {
 
         if self.frozen {
@@ -91,7 +180,16 @@ impl GJInteger {
             }
         }
 
-        let sampled_value: f64 = self.uniform_distribution.sample( &mut self.random_generator) as f64;
+        if self.allow_unassigned && Uniform::new(0.0, 1.0).sample(&mut self.random_generator) < self.unassigned_probability {
+            return self.get_unassigned_value();
+        }
+
+        let sampled_value: f64;
+        match &self.allowed_values {
+            // distribution is built here, because bounds are checked only by VariablesManager::new()
+            None => sampled_value = Uniform::new_inclusive(self.lower_bound as i64, self.upper_bound as i64).sample(&mut self.random_generator) as f64,
+            Some(allowed_values) => sampled_value = allowed_values[Uniform::new(0, allowed_values.len()).sample(&mut self.random_generator)],
+        }
         return sampled_value;
     }
 
@@ -137,6 +235,8 @@ impl GJInteger {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::variables::PlanningVariablesVariants;
+    use crate::score_calculation::score_requesters::VariablesManager;
 
     #[test]
     fn test_gp_float_var_frozen() {
@@ -179,4 +279,64 @@ mod tests {
         let x_to_ceil = x.inverse_transform(x_to_ceil);
         assert_eq!(x_to_ceil, 5);
     }
+
+    #[test]
+    fn test_gp_integer_var_allowed_values() {
+        let mut x = GJInteger::new( None, 0, 10, false, None).with_allowed_values(vec![8, 2, 5, 2]);
+
+        assert_eq!(x.allowed_values, Some(vec![2.0, 5.0, 8.0]));
+        assert_eq!(x.fix(3.4), 2.0);
+        assert_eq!(x.fix(4.0), 5.0);
+        assert_eq!(x.fix(100.0), 8.0);
+        for _ in 0..20 {
+            let sampled_value = x.sample();
+            assert_eq!(x.is_allowed_value(sampled_value), true);
+        }
+    }
+
+    #[test]
+    fn test_gp_integer_var_allowed_values_out_of_bounds() {
+        let mut x = GJInteger::new( None, 0, 10, false, None).with_allowed_values(vec![20, 5, -3]);
+        x.set_name("vehicle_id".to_string());
+        assert_eq!(x.get_out_of_bounds_allowed_values(), vec![-3.0, 20.0]);
+
+        let variables_error = VariablesManager::new(vec![PlanningVariablesVariants::GJI(x)]).err().unwrap();
+        assert_eq!(variables_error.to_string().contains("[-3.0, 20.0]"), true);
+    }
+
+    #[test]
+    fn test_gp_integer_var_inverted_bounds() {
+        let x = GJInteger::new( None, 10, 0, false, None);
+        assert_eq!(VariablesManager::new(vec![PlanningVariablesVariants::GJI(x)]).is_err(), true);
+
+        let y = crate::variables::GJFloat::new( None, 10.0, 0.0, false, None);
+        assert_eq!(VariablesManager::new(vec![PlanningVariablesVariants::GJF(y)]).is_err(), true);
+    }
+
+    #[test]
+    fn test_gp_integer_var_allow_unassigned() {
+        let mut x = GJInteger::new( None, 0, 10, false, None).with_allow_unassigned(1.0);
+
+        assert_eq!(x.sample(), -1.0);
+        assert_eq!(x.is_unassigned(-1.0), true);
+        assert_eq!(x.is_unassigned(-0.7), false);
+        assert_eq!(x.fix(-0.3), 0.0);
+
+        let y = GJInteger::new( None, 0, 10, false, None);
+        assert_eq!(y.is_unassigned(-5.0), false);
+    }
+
+    #[test]
+    fn test_gp_integer_var_fix_out_of_range_with_unassigned() {
+        let x = GJInteger::new( None, 0, 10, false, None);
+        assert_eq!(x.fix(-3.7), 0.0);
+        assert_eq!(x.fix(-1.0), 0.0);
+
+        let y = GJInteger::new( None, 0, 10, false, None).with_allow_unassigned(0.0);
+        assert_eq!(y.fix(-3.7), 0.0);
+        assert_eq!(y.is_unassigned(-3.7), false);
+        assert_eq!(y.fix(-1.2), 0.0);
+        assert_eq!(y.fix(-1.0), -1.0);
+        assert_eq!(y.is_unassigned(y.get_unassigned_value()), true);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,109 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use crate::utils::math_utils;
+
+/*
+    List (permutation) planning variable.
+    All lists of the same list_group share one set of elements: every element is planned
+    into exactly one list of the group (vehicle routes, machine queues, etc).
+    Inside a sample a list occupies max_length consecutive columns (slots).
+    A slot holds an element index (position in elements) or -1.0 for an empty slot,
+    elements of a list are always placed at its first slots.
+*/
+#[derive(Debug, Clone)]
+pub struct GJList {
+    pub name: String,
+    pub initial_value: Option<Vec<i64>>,
+    pub elements: Vec<i64>,
+    pub list_group: String,
+    pub max_length: usize,
+    pub frozen: bool,
+    pub random_generator: StdRng,
+}
+
+impl GJList {
+    pub fn new(initial_value: Option<Vec<i64>>, elements: Vec<i64>, list_group: &str, frozen: bool, max_length: Option<usize>) -> Self {
+
+            let current_max_length;
+            match max_length {
+                Some(x) => current_max_length = x,
+                None => current_max_length = elements.len(),
+            }
+
+            GJList {
+                name: "".to_string(),
+                initial_value: initial_value,
+                elements: elements,
+                list_group: list_group.to_string(),
+                max_length: current_max_length,
+                frozen: frozen,
+                random_generator: StdRng::from_entropy(),
+            }
+        }
+}
+
+impl GJList {
+
+    pub fn set_name(&mut self, new_name: String) {
+        self.name = new_name;
+    }
+
+    pub fn get_element_id(&self, element: i64) -> Option<usize> {
+        return self.elements.iter().position(|x| *x == element);
+    }
+
+    pub fn get_initial_element_ids(&self) -> Vec<usize> {
+        match &self.initial_value {
+            None => return Vec::new(),
+            Some(initial_list) => return initial_list.iter().filter_map(|x| self.get_element_id(*x)).collect(),
+        }
+    }
+
+    pub fn fix(&self, value: f64) -> f64 {
+
+        let upper_bound = self.elements.len() as f64 - 1.0;
+        let mut fixed_value = math_utils::rint(value);
+        if fixed_value < -1.0 {
+            fixed_value = -1.0;
+        } else if fixed_value > upper_bound {
+            fixed_value = upper_bound;
+        }
+
+        return fixed_value;
+    }
+
+    // slots -> planned elements ids, empty slots are skipped
+    pub fn get_element_ids(&self, slot_values: &[f64]) -> Vec<usize> {
+        return slot_values.iter()
+            .map(|x| self.fix(*x))
+            .filter(|x| *x >= 0.0)
+            .map(|x| x as usize)
+            .collect();
+    }
+
+    pub fn inverse_transform(&self, slot_values: &[f64]) -> Vec<i64> {
+        return self.get_element_ids(slot_values).iter().map(|i| self.elements[*i]).collect();
+    }
+
+    pub fn to_slot_values(&self, element_ids: &Vec<usize>) -> Vec<f64> {
+        let mut slot_values = vec![-1.0; self.max_length];
+        element_ids.iter().enumerate().for_each(|(i, element_id)| slot_values[i] = *element_id as f64);
+        return slot_values;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gj_list_inverse_transform() {
+        let x = GJList::new(None, vec![10, 20, 30, 40], "routes", false, None);
+
+        let slot_values = x.to_slot_values(&vec![2, 0, 3]);
+        assert_eq!(slot_values, vec![2.0, 0.0, 3.0, -1.0]);
+        assert_eq!(x.inverse_transform(&slot_values), vec![30, 10, 40]);
+        assert_eq!(x.fix(7.2), 3.0);
+        assert_eq!(x.fix(-3.0), -1.0);
+    }
+}
@@ -1,9 +1,13 @@
-
-
 pub mod gj_integer;
 pub mod gj_float;
+pub mod gj_bool;
+pub mod gj_categorical;
+pub mod gj_list;
 pub mod planning_variables_variants;
 
 pub use gj_float::GJFloat;
 pub use gj_integer::GJInteger;
-pub use planning_variables_variants::PlanningVariablesVariants;
\ No newline at end of file
+pub use gj_bool::GJBool;
+pub use gj_categorical::GJCategorical;
+pub use gj_list::GJList;
+pub use planning_variables_variants::PlanningVariablesVariants;
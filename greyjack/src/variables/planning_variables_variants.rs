@@ -1,9 +1,14 @@
-
 use super::GJFloat;
 use super::GJInteger;
+use super::GJBool;
+use super::GJCategorical;
+use super::GJList;
 
 #[derive(Debug, Clone)]
 pub enum PlanningVariablesVariants {
     GJF(GJFloat),
     GJI(GJInteger),
-}
\ No newline at end of file
+    GJB(GJBool),
+    GJC(GJCategorical),
+    GJL(GJList),
+}